@@ -0,0 +1,217 @@
+/*
+ * @file ambient.rs
+ * @brief Ambient light auto brightness
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: ambient.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Ambient Light Auto Brightness.
+//!
+//! BRIEF:
+//! Smooths ambient light readings and maps them to an LED duty.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{ADC_MAX, AMBIENT_ALPHA_PERCENT, AUTO_DIM_MIN_DUTY};
+
+/// Maps an ambient light reading to an LED duty.
+///
+/// # Details
+/// Linear from AUTO_DIM_MIN_DUTY in darkness to 100% at full scale,
+/// so the LED dims in the dark and brightens in daylight.
+///
+/// # Arguments
+/// * `light` - Ambient light ADC reading (0-ADC_MAX)
+///
+/// # Returns
+/// * `u8` - Duty in percent
+#[allow(dead_code)]
+pub fn duty_for_light(light: u16) -> u8 {
+    let light = u32::from(light.min(ADC_MAX));
+    let span = u32::from(100 - AUTO_DIM_MIN_DUTY);
+    (u32::from(AUTO_DIM_MIN_DUTY) + light * span / u32::from(ADC_MAX)) as u8
+}
+
+/// Exponential moving average filter for ambient readings.
+///
+/// # Details
+/// Smooths noisy ADC samples before they drive brightness.
+/// The first sample seeds the average directly.
+///
+/// # Fields
+/// * `alpha` - Weight of each new sample in percent (1-100)
+/// * `value` - Current smoothed value, if seeded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct AmbientFilter {
+    alpha: u8,
+    value: Option<u16>,
+}
+
+impl Default for AmbientFilter {
+    /// Returns default AmbientFilter instance.
+    ///
+    /// # Details
+    /// Uses the configured smoothing factor.
+    ///
+    /// # Returns
+    /// * `Self` - New AmbientFilter
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(AMBIENT_ALPHA_PERCENT)
+    }
+}
+
+impl AmbientFilter {
+    /// Creates new unseeded filter.
+    ///
+    /// # Details
+    /// Alpha is clamped to 1..=100.
+    ///
+    /// # Arguments
+    /// * `alpha` - Weight of each new sample in percent
+    ///
+    /// # Returns
+    /// * `Self` - New AmbientFilter instance
+    #[allow(dead_code)]
+    pub fn new(alpha: u8) -> Self {
+        Self {
+            alpha: alpha.clamp(1, 100),
+            value: None,
+        }
+    }
+
+    /// Feeds a raw reading and returns the smoothed value.
+    ///
+    /// # Details
+    /// Moves at least one count toward the input each update so the
+    /// output always converges despite integer rounding.
+    ///
+    /// # Arguments
+    /// * `raw` - Raw ADC reading
+    ///
+    /// # Returns
+    /// * `u16` - Smoothed reading
+    #[allow(dead_code)]
+    pub fn update(&mut self, raw: u16) -> u16 {
+        let smoothed = match self.value {
+            None => raw,
+            Some(prev) => {
+                let diff = i32::from(raw) - i32::from(prev);
+                let mut step = diff * i32::from(self.alpha) / 100;
+                if step == 0 {
+                    step = diff.signum();
+                }
+                (i32::from(prev) + step) as u16
+            }
+        };
+        self.value = Some(smoothed);
+        smoothed
+    }
+
+    /// Feeds a raw reading and returns the resulting duty.
+    ///
+    /// # Arguments
+    /// * `raw` - Raw ADC reading
+    ///
+    /// # Returns
+    /// * `u8` - Duty in percent from the smoothed reading
+    #[allow(dead_code)]
+    pub fn duty(&mut self, raw: u16) -> u8 {
+        duty_for_light(self.update(raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Duty Mapping Tests ====================
+
+    #[test]
+    fn test_duty_for_light_endpoints() {
+        assert_eq!(duty_for_light(0), AUTO_DIM_MIN_DUTY);
+        assert_eq!(duty_for_light(ADC_MAX), 100);
+        assert_eq!(duty_for_light(u16::MAX), 100);
+    }
+
+    // ==================== AmbientFilter Tests ====================
+
+    #[test]
+    fn test_first_sample_seeds() {
+        let mut filter = AmbientFilter::new(25);
+        assert_eq!(filter.update(1000), 1000);
+    }
+
+    #[test]
+    fn test_step_converges_gradually() {
+        let mut filter = AmbientFilter::new(25);
+        filter.update(0);
+        let first = filter.update(4000);
+        assert_eq!(first, 1000);
+        let mut last = first;
+        for _ in 0..100 {
+            let value = filter.update(4000);
+            assert!(value >= last);
+            assert!(value <= 4000);
+            last = value;
+        }
+        assert_eq!(last, 4000);
+    }
+
+    #[test]
+    fn test_step_down_converges() {
+        let mut filter = AmbientFilter::new(50);
+        filter.update(4000);
+        assert_eq!(filter.update(0), 2000);
+        assert_eq!(filter.update(0), 1000);
+        for _ in 0..50 {
+            filter.update(0);
+        }
+        assert_eq!(filter.update(0), 0);
+    }
+
+    #[test]
+    fn test_spike_is_damped() {
+        let mut filter = AmbientFilter::new(10);
+        filter.update(1000);
+        assert_eq!(filter.update(4000), 1300);
+        assert_eq!(filter.update(1000), 1270);
+    }
+
+    #[test]
+    fn test_duty_uses_smoothed_value() {
+        let mut filter = AmbientFilter::new(50);
+        filter.duty(0);
+        let duty = filter.duty(ADC_MAX);
+        assert_eq!(duty, duty_for_light(2047));
+        assert!(duty < 100);
+    }
+}
@@ -0,0 +1,280 @@
+/*
+ * @file backend.rs
+ * @brief Timing backend selection
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: backend.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Timing Backend Selection.
+//!
+//! BRIEF:
+//! Selects the blink task delay provider at build time through the
+//! backend-embassy-time or backend-hal-delay feature.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+#[cfg(feature = "backend-hal-delay")]
+use crate::config::CPU_HZ;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Converts nanoseconds to CPU cycles.
+///
+/// # Details
+/// Rounds up so a delay is never shorter than requested and
+/// saturates at u32::MAX.
+///
+/// # Arguments
+/// * `ns` - Delay in nanoseconds
+/// * `cpu_hz` - CPU clock frequency in hertz
+///
+/// # Returns
+/// * `u32` - Cycle count
+#[allow(dead_code)]
+pub fn ns_to_cycles(ns: u32, cpu_hz: u32) -> u32 {
+    let cycles = (u64::from(ns) * u64::from(cpu_hz)).div_ceil(1_000_000_000);
+    u32::try_from(cycles).unwrap_or(u32::MAX)
+}
+
+/// Advances a waited total from a wrapping microsecond counter.
+///
+/// # Details
+/// Adds the counter ticks since the previous reading using wrapping
+/// subtraction, so a 32-bit counter rollover between readings is
+/// counted correctly. Readings must be less than one counter period
+/// (about 71 minutes) apart.
+///
+/// # Arguments
+/// * `waited_us` - Time waited so far in microseconds
+/// * `last_us` - Previous counter reading
+/// * `now_us` - Current counter reading
+///
+/// # Returns
+/// * `u64` - Updated time waited in microseconds
+#[allow(dead_code)]
+pub fn advance_waited_us(waited_us: u64, last_us: u32, now_us: u32) -> u64 {
+    waited_us.saturating_add(u64::from(now_us.wrapping_sub(last_us)))
+}
+
+/// Future that yields to the executor once.
+///
+/// # Details
+/// Wakes itself and returns Pending on the first poll, so every
+/// other ready task runs before the caller resumes.
+///
+/// # Fields
+/// * `yielded` - true once the first poll has returned Pending
+#[allow(dead_code)]
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    /// Polls the yield.
+    ///
+    /// # Arguments
+    /// * `cx` - Task context providing the waker
+    ///
+    /// # Returns
+    /// * `Poll<()>` - Pending on the first poll, then Ready
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// Returns a future that yields to the executor once.
+///
+/// # Returns
+/// * `YieldNow` - Yield future
+#[allow(dead_code)]
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+/// Delay used by the blink task with the embassy-time backend.
+#[cfg(feature = "backend-embassy-time")]
+#[allow(dead_code)]
+pub type BackendDelay = embassy_time::Delay;
+
+/// Delay used by the blink task with the HAL delay backend.
+#[cfg(feature = "backend-hal-delay")]
+#[allow(dead_code)]
+pub type BackendDelay = HalDelay;
+
+/// Returns the delay for the selected backend.
+///
+/// # Returns
+/// * `BackendDelay` - Delay provider for the blink task
+#[cfg(feature = "backend-embassy-time")]
+#[allow(dead_code)]
+pub fn backend_delay() -> BackendDelay {
+    embassy_time::Delay
+}
+
+/// Returns the delay for the selected backend.
+///
+/// # Returns
+/// * `BackendDelay` - Delay provider for the blink task
+#[cfg(feature = "backend-hal-delay")]
+#[allow(dead_code)]
+pub fn backend_delay() -> BackendDelay {
+    HalDelay
+}
+
+/// Free-running counter HAL delay.
+///
+/// # Details
+/// Polls the TIMER0 microsecond counter against a deadline and yields
+/// to the executor between polls, so other tasks keep running without
+/// an alarm-driven time driver and the time they take counts toward
+/// the delay.
+#[cfg(feature = "backend-hal-delay")]
+#[allow(dead_code)]
+pub struct HalDelay;
+
+/// Reads the free-running microsecond counter.
+///
+/// # Returns
+/// * `u32` - Low word of TIMER0
+#[cfg(feature = "backend-hal-delay")]
+fn now_us() -> u32 {
+    embassy_rp::pac::TIMER0.timerawl().read()
+}
+
+/// Yields until the given time has passed on the microsecond counter.
+///
+/// # Arguments
+/// * `duration_us` - Time to wait in microseconds
+#[cfg(feature = "backend-hal-delay")]
+async fn wait_us(duration_us: u64) {
+    let mut last_us = now_us();
+    let mut waited_us = 0;
+    while waited_us < duration_us {
+        yield_now().await;
+        let now = now_us();
+        waited_us = advance_waited_us(waited_us, last_us, now);
+        last_us = now;
+    }
+}
+
+#[cfg(feature = "backend-hal-delay")]
+impl embedded_hal_async::delay::DelayNs for HalDelay {
+    /// Busy-waits for the given nanoseconds.
+    ///
+    /// # Arguments
+    /// * `ns` - Delay in nanoseconds
+    async fn delay_ns(&mut self, ns: u32) {
+        cortex_m::asm::delay(ns_to_cycles(ns, CPU_HZ));
+    }
+
+    /// Waits for the given microseconds.
+    ///
+    /// # Arguments
+    /// * `us` - Delay in microseconds
+    async fn delay_us(&mut self, us: u32) {
+        wait_us(u64::from(us)).await;
+    }
+
+    /// Waits for the given milliseconds.
+    ///
+    /// # Arguments
+    /// * `ms` - Delay in milliseconds
+    async fn delay_ms(&mut self, ms: u32) {
+        wait_us(u64::from(ms) * 1_000).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Cycle Conversion Tests ====================
+
+    #[test]
+    fn test_ns_to_cycles_exact() {
+        assert_eq!(ns_to_cycles(1_000_000, 150_000_000), 150_000);
+        assert_eq!(ns_to_cycles(0, 150_000_000), 0);
+    }
+
+    #[test]
+    fn test_ns_to_cycles_rounds_up() {
+        assert_eq!(ns_to_cycles(1, 150_000_000), 1);
+        assert_eq!(ns_to_cycles(10, 150_000_000), 2);
+    }
+
+    #[test]
+    fn test_ns_to_cycles_saturates() {
+        assert_eq!(ns_to_cycles(u32::MAX, u32::MAX), u32::MAX);
+    }
+
+    // ==================== Deadline Tests ====================
+
+    #[test]
+    fn test_advance_waited_us_counts_ticks() {
+        assert_eq!(advance_waited_us(0, 1_000, 1_250), 250);
+        assert_eq!(advance_waited_us(250, 1_250, 1_250), 250);
+    }
+
+    #[test]
+    fn test_advance_waited_us_handles_rollover() {
+        assert_eq!(advance_waited_us(100, u32::MAX - 9, 20), 130);
+    }
+
+    #[test]
+    fn test_advance_waited_us_reaches_long_deadline() {
+        let deadline_us = u64::from(u32::MAX) * 1_000;
+        let mut waited_us = 0;
+        let mut last_us = 0u32;
+        while waited_us < deadline_us {
+            let now = last_us.wrapping_add(u32::MAX / 2);
+            waited_us = advance_waited_us(waited_us, last_us, now);
+            last_us = now;
+        }
+        assert!(waited_us - deadline_us < u64::from(u32::MAX / 2));
+    }
+
+    // ==================== Yield Tests ====================
+
+    #[test]
+    fn test_yield_now_pends_once() {
+        let mut future = core::pin::pin!(yield_now());
+        let mut cx = Context::from_waker(core::task::Waker::noop());
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(()));
+    }
+}
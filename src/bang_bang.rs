@@ -0,0 +1,128 @@
+/*
+ * @file bang_bang.rs
+ * @brief Two-point setpoint indicator
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: bang_bang.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Two-Point Setpoint Indicator.
+//!
+//! BRIEF:
+//! Classifies a sensor value against a setpoint and deadband to
+//! select a blinking or solid LED.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::LedMode;
+
+/// Two-point setpoint indicator.
+///
+/// # Details
+/// Blinks while a sensor value is within the deadband of the
+/// setpoint and goes solid when it is farther off.
+/// Independent of the sensor so it can be tested on host.
+///
+/// # Fields
+/// * `setpoint` - Target value
+/// * `deadband` - Allowed distance from the setpoint, inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct BangBang {
+    setpoint: i32,
+    deadband: u32,
+}
+
+impl BangBang {
+    /// Creates new setpoint indicator.
+    ///
+    /// # Arguments
+    /// * `setpoint` - Target value
+    /// * `deadband` - Allowed distance from the setpoint, inclusive
+    ///
+    /// # Returns
+    /// * `Self` - New BangBang instance
+    #[allow(dead_code)]
+    pub fn new(setpoint: i32, deadband: u32) -> Self {
+        Self { setpoint, deadband }
+    }
+
+    /// Returns LED mode for a sensor value.
+    ///
+    /// # Arguments
+    /// * `value` - Current sensor value
+    ///
+    /// # Returns
+    /// * `LedMode` - Blink near the setpoint, Solid far from it
+    #[allow(dead_code)]
+    pub fn mode_for(&self, value: i32) -> LedMode {
+        if value.abs_diff(self.setpoint) <= self.deadband {
+            LedMode::Blink
+        } else {
+            LedMode::Solid
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== BangBang Tests ====================
+
+    #[test]
+    fn test_inside_deadband_blinks() {
+        let control = BangBang::new(100, 5);
+        assert_eq!(control.mode_for(100), LedMode::Blink);
+        assert_eq!(control.mode_for(103), LedMode::Blink);
+        assert_eq!(control.mode_for(97), LedMode::Blink);
+    }
+
+    #[test]
+    fn test_deadband_edges_inclusive() {
+        let control = BangBang::new(100, 5);
+        assert_eq!(control.mode_for(105), LedMode::Blink);
+        assert_eq!(control.mode_for(95), LedMode::Blink);
+    }
+
+    #[test]
+    fn test_outside_deadband_solid() {
+        let control = BangBang::new(100, 5);
+        assert_eq!(control.mode_for(106), LedMode::Solid);
+        assert_eq!(control.mode_for(94), LedMode::Solid);
+        assert_eq!(control.mode_for(i32::MIN), LedMode::Solid);
+    }
+
+    #[test]
+    fn test_zero_deadband_only_exact() {
+        let control = BangBang::new(-20, 0);
+        assert_eq!(control.mode_for(-20), LedMode::Blink);
+        assert_eq!(control.mode_for(-21), LedMode::Solid);
+    }
+}
@@ -0,0 +1,188 @@
+/*
+ * @file bank.rs
+ * @brief Indexed multi-LED GPIO bank
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: bank.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Multi-LED GPIO Bank.
+//!
+//! BRIEF:
+//! Owns a fixed set of LED pins and drives them by index with
+//! bounds-checked access.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::pin::LedPin;
+
+/// LED bank error enumeration.
+///
+/// # Variants
+/// * `IndexOutOfRange` - Index is not below the bank size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum BankError {
+    IndexOutOfRange,
+}
+
+/// Bank of LED pins addressed by index.
+///
+/// # Details
+/// Owns N pins, typically Embassy `Output`s, so multi-LED setups are
+/// configured once instead of juggling individual PIN_n handles.
+///
+/// # Fields
+/// * `pins` - Owned LED pins
+#[allow(dead_code)]
+pub struct LedBank<P: LedPin, const N: usize> {
+    pins: [P; N],
+}
+
+impl<P: LedPin, const N: usize> LedBank<P, N> {
+    /// Creates new bank owning the given pins.
+    ///
+    /// # Arguments
+    /// * `pins` - LED pins in index order
+    ///
+    /// # Returns
+    /// * `Self` - New LedBank instance
+    #[allow(dead_code)]
+    pub fn new(pins: [P; N]) -> Self {
+        Self { pins }
+    }
+
+    /// Returns the number of LEDs in the bank.
+    ///
+    /// # Returns
+    /// * `usize` - Bank size N
+    #[allow(dead_code)]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns whether the bank has no LEDs.
+    ///
+    /// # Returns
+    /// * `bool` - true when N is zero
+    #[allow(dead_code)]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Drives one LED pin.
+    ///
+    /// # Arguments
+    /// * `index` - LED index
+    /// * `level` - true for high, false for low
+    ///
+    /// # Returns
+    /// * `Result<(), BankError>` - Ok, or IndexOutOfRange leaving all pins untouched
+    #[allow(dead_code)]
+    pub fn set(&mut self, index: usize, level: bool) -> Result<(), BankError> {
+        let pin = self.pins.get_mut(index).ok_or(BankError::IndexOutOfRange)?;
+        pin.set_level(level);
+        Ok(())
+    }
+
+    /// Drives every LED pin.
+    ///
+    /// # Arguments
+    /// * `levels` - Level for each pin in index order
+    #[allow(dead_code)]
+    pub fn set_all(&mut self, levels: &[bool; N]) {
+        for (pin, &level) in self.pins.iter_mut().zip(levels) {
+            pin.set_level(level);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Shared record of levels written to one pin.
+    type Log = Rc<RefCell<Vec<bool>>>;
+
+    /// Mock pin recording every level written.
+    struct MockPin {
+        levels: Log,
+    }
+
+    impl LedPin for MockPin {
+        fn set_level(&mut self, high: bool) {
+            self.levels.borrow_mut().push(high);
+        }
+    }
+
+    fn bank() -> (LedBank<MockPin, 3>, [Log; 3]) {
+        let logs: [Log; 3] = Default::default();
+        let pins = [0, 1, 2].map(|i| MockPin {
+            levels: logs[i].clone(),
+        });
+        (LedBank::new(pins), logs)
+    }
+
+    // ==================== LedBank Tests ====================
+
+    #[test]
+    fn test_set_all_applies_each_level() {
+        let (mut bank, logs) = bank();
+        bank.set_all(&[true, false, true]);
+        assert_eq!(*logs[0].borrow(), vec![true]);
+        assert_eq!(*logs[1].borrow(), vec![false]);
+        assert_eq!(*logs[2].borrow(), vec![true]);
+    }
+
+    #[test]
+    fn test_set_drives_only_indexed_pin() {
+        let (mut bank, logs) = bank();
+        assert_eq!(bank.set(1, true), Ok(()));
+        assert!(logs[0].borrow().is_empty());
+        assert_eq!(*logs[1].borrow(), vec![true]);
+        assert!(logs[2].borrow().is_empty());
+    }
+
+    #[test]
+    fn test_out_of_range_index_rejected() {
+        let (mut bank, logs) = bank();
+        assert_eq!(bank.set(3, true), Err(BankError::IndexOutOfRange));
+        assert_eq!(bank.set(usize::MAX, false), Err(BankError::IndexOutOfRange));
+        assert!(logs.iter().all(|log| log.borrow().is_empty()));
+    }
+
+    #[test]
+    fn test_len_matches_pin_count() {
+        let (bank, _) = bank();
+        assert_eq!(bank.len(), 3);
+        assert!(!bank.is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+/*
+ * @file blink_code.rs
+ * @brief Numeric blink code encoding
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: blink_code.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Numeric Blink Code Encoding.
+//!
+//! BRIEF:
+//! Converts a numeric code into a sequence of timed flashes.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{BLINK_CODE_OFF_MS, BLINK_CODE_ON_MS, TIME_CODE_GAP_MS, VERSION};
+use crate::led::LedState;
+
+/// Iterator over the steps of a numeric blink code.
+///
+/// # Details
+/// Yields one ON/OFF pulse pair per unit of the code.
+/// A code of 0 yields no steps.
+///
+/// # Fields
+/// * `remaining` - Number of steps left to yield
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct BlinkCode {
+    remaining: u16,
+}
+
+impl Iterator for BlinkCode {
+    type Item = (LedState, u64);
+
+    /// Returns the next blink code step.
+    ///
+    /// # Details
+    /// Alternates ON and OFF, starting with ON.
+    ///
+    /// # Returns
+    /// * `Option<(LedState, u64)>` - Next step or None when finished
+    #[allow(dead_code)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let step = if self.remaining.is_multiple_of(2) {
+            (LedState::On, BLINK_CODE_ON_MS)
+        } else {
+            (LedState::Off, BLINK_CODE_OFF_MS)
+        };
+        self.remaining -= 1;
+        Some(step)
+    }
+}
+
+/// Encodes a numeric code as a sequence of flashes.
+///
+/// # Details
+/// Produces `code` flashes, each followed by an OFF interval.
+/// Shared by the early-boot raw blink and async code blinks.
+///
+/// # Arguments
+/// * `code` - Number of flashes to emit
+///
+/// # Returns
+/// * `BlinkCode` - Iterator over `(LedState, u64)` steps
+#[allow(dead_code)]
+pub fn encode_blink_code(code: u8) -> BlinkCode {
+    BlinkCode {
+        remaining: u16::from(code) * 2,
+    }
+}
+
+/// Encodes a time of day as two blink codes.
+///
+/// # Details
+/// Flashes the hour, holds a long gap, then flashes the minute.
+/// A zero hour or minute produces no flashes for that part.
+///
+/// # Arguments
+/// * `hour` - Hour to flash
+/// * `minute` - Minute to flash
+///
+/// # Returns
+/// * `impl Iterator<Item = (LedState, u64)>` - Readout steps
+#[allow(dead_code)]
+pub fn encode_time(hour: u8, minute: u8) -> impl Iterator<Item = (LedState, u64)> {
+    encode_blink_code(hour)
+        .chain(core::iter::once((LedState::Off, TIME_CODE_GAP_MS)))
+        .chain(encode_blink_code(minute))
+}
+
+/// Encodes a major.minor version as two blink codes.
+///
+/// # Details
+/// Flashes the major number, holds the time readout gap, then
+/// flashes the minor number. A zero part produces no flashes.
+///
+/// # Arguments
+/// * `major` - Major version
+/// * `minor` - Minor version
+///
+/// # Returns
+/// * `impl Iterator<Item = (LedState, u64)>` - Version readout steps
+#[allow(dead_code)]
+pub fn encode_version(major: u8, minor: u8) -> impl Iterator<Item = (LedState, u64)> {
+    encode_time(major, minor)
+}
+
+/// Encodes the firmware VERSION as blink codes.
+///
+/// # Returns
+/// * `impl Iterator<Item = (LedState, u64)>` - Version readout steps
+#[allow(dead_code)]
+pub fn version_blink() -> impl Iterator<Item = (LedState, u64)> {
+    encode_version(VERSION.0, VERSION.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Blink Code Tests ====================
+
+    #[test]
+    fn test_zero_code_is_empty() {
+        assert_eq!(encode_blink_code(0).count(), 0);
+    }
+
+    #[test]
+    fn test_code_step_count() {
+        assert_eq!(encode_blink_code(3).count(), 6);
+        assert_eq!(encode_blink_code(255).count(), 510);
+    }
+
+    #[test]
+    fn test_code_flash_count() {
+        let flashes = encode_blink_code(5)
+            .filter(|(state, _)| *state == LedState::On)
+            .count();
+        assert_eq!(flashes, 5);
+    }
+
+    #[test]
+    fn test_code_step_order() {
+        let mut code = encode_blink_code(1);
+        assert_eq!(code.next(), Some((LedState::On, BLINK_CODE_ON_MS)));
+        assert_eq!(code.next(), Some((LedState::Off, BLINK_CODE_OFF_MS)));
+        assert_eq!(code.next(), None);
+    }
+
+    // ==================== Time Readout Tests ====================
+
+    fn flash_counts(steps: impl Iterator<Item = (LedState, u64)>) -> (usize, usize) {
+        let steps: Vec<_> = steps.collect();
+        let gap = steps
+            .iter()
+            .position(|&step| step == (LedState::Off, TIME_CODE_GAP_MS))
+            .unwrap();
+        let count = |part: &[(LedState, u64)]| part.iter().filter(|s| s.0 == LedState::On).count();
+        (count(&steps[..gap]), count(&steps[gap + 1..]))
+    }
+
+    #[test]
+    fn test_encode_time_3_07() {
+        assert_eq!(flash_counts(encode_time(3, 7)), (3, 7));
+        assert_eq!(encode_time(3, 7).count(), 6 + 1 + 14);
+    }
+
+    #[test]
+    fn test_encode_time_12_00() {
+        assert_eq!(flash_counts(encode_time(12, 0)), (12, 0));
+        assert_eq!(
+            encode_time(12, 0).last(),
+            Some((LedState::Off, TIME_CODE_GAP_MS))
+        );
+    }
+
+    // ==================== Version Readout Tests ====================
+
+    #[test]
+    fn test_encode_version_1_4() {
+        assert_eq!(flash_counts(encode_version(1, 4)), (1, 4));
+    }
+
+    #[test]
+    fn test_version_blink_matches_config() {
+        assert_eq!(
+            flash_counts(version_blink()),
+            (usize::from(VERSION.0), usize::from(VERSION.1))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * @file boot.rs
+ * @brief Reset-reason-aware boot blink
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: boot.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Reset-Reason-Aware Boot Blink.
+//!
+//! BRIEF:
+//! Detects the reset cause and selects a distinctive boot blink pattern.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::pattern::Pattern;
+
+/// Reset cause enumeration.
+///
+/// # Details
+/// Classifies why the chip last came out of reset.
+///
+/// # Variants
+/// * `PowerOn` - Normal power-on or external reset
+/// * `Watchdog` - Watchdog timeout or forced watchdog reset
+/// * `Brownout` - Supply dropped below the brownout threshold
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum BootReason {
+    PowerOn,
+    Watchdog,
+    Brownout,
+}
+
+impl BootReason {
+    /// Classifies reset cause from reset reason flags.
+    ///
+    /// # Details
+    /// Brownout takes precedence, since a sagging supply can also
+    /// starve the watchdog.
+    ///
+    /// # Arguments
+    /// * `watchdog` - true if the watchdog caused the reset
+    /// * `brownout` - true if the brownout detector caused the reset
+    ///
+    /// # Returns
+    /// * `Self` - Classified reset cause
+    #[allow(dead_code)]
+    pub fn from_flags(watchdog: bool, brownout: bool) -> Self {
+        if brownout {
+            BootReason::Brownout
+        } else if watchdog {
+            BootReason::Watchdog
+        } else {
+            BootReason::PowerOn
+        }
+    }
+}
+
+/// Reads the reset cause from the RP2350 reset reason registers.
+///
+/// # Details
+/// Checks the TIMER and FORCE bits of WATCHDOG.REASON and the
+/// HAD_BOR bit of POWMAN.CHIP_RESET.
+///
+/// # Returns
+/// * `BootReason` - Cause of the last reset
+#[cfg(feature = "embassy-rp")]
+#[allow(dead_code)]
+pub fn read_boot_reason() -> BootReason {
+    let reason = embassy_rp::pac::WATCHDOG.reason().read();
+    let brownout = embassy_rp::pac::POWMAN.chip_reset().read().had_bor();
+    BootReason::from_flags(reason.timer() || reason.force(), brownout)
+}
+
+/// Selects the boot blink pattern for a reset cause.
+///
+/// # Details
+/// Watchdog resets flash a distinctive SOS and brownout resets the
+/// slow recovery blink so they stand out from a normal power-on.
+///
+/// # Arguments
+/// * `reason` - Cause of the last reset
+///
+/// # Returns
+/// * `Pattern` - Pattern to play once at boot
+#[allow(dead_code)]
+pub fn boot_blink_pattern(reason: BootReason) -> Pattern {
+    match reason {
+        BootReason::PowerOn => Pattern::Steady,
+        BootReason::Watchdog => Pattern::Sos,
+        BootReason::Brownout => Pattern::Recovery,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Boot Reason Tests ====================
+
+    #[test]
+    fn test_from_flags() {
+        assert_eq!(BootReason::from_flags(false, false), BootReason::PowerOn);
+        assert_eq!(BootReason::from_flags(true, false), BootReason::Watchdog);
+        assert_eq!(BootReason::from_flags(false, true), BootReason::Brownout);
+        assert_eq!(BootReason::from_flags(true, true), BootReason::Brownout);
+    }
+
+    // ==================== Boot Pattern Tests ====================
+
+    #[test]
+    fn test_power_on_pattern() {
+        assert_eq!(boot_blink_pattern(BootReason::PowerOn), Pattern::Steady);
+    }
+
+    #[test]
+    fn test_watchdog_pattern() {
+        assert_eq!(boot_blink_pattern(BootReason::Watchdog), Pattern::Sos);
+    }
+
+    #[test]
+    fn test_brownout_pattern() {
+        assert_eq!(boot_blink_pattern(BootReason::Brownout), Pattern::Recovery);
+    }
+
+    #[test]
+    fn test_reasons_have_distinct_patterns() {
+        assert_ne!(
+            boot_blink_pattern(BootReason::PowerOn),
+            boot_blink_pattern(BootReason::Watchdog)
+        );
+        assert_ne!(
+            boot_blink_pattern(BootReason::Brownout),
+            boot_blink_pattern(BootReason::Watchdog)
+        );
+        assert_ne!(
+            boot_blink_pattern(BootReason::Brownout),
+            boot_blink_pattern(BootReason::PowerOn)
+        );
+    }
+}
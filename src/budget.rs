@@ -0,0 +1,173 @@
+/*
+ * @file budget.rs
+ * @brief Sliding-window toggle budget
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: budget.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Sliding-Window Toggle Budget.
+//!
+//! BRIEF:
+//! Caps the number of LED toggles in any time window to protect
+//! relays and reduce wear.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+/// Toggle budget over a sliding time window.
+///
+/// # Details
+/// Allows at most MAX toggles in any window of `window_ms`. Keeps
+/// the timestamps of recent allowed toggles, so the budget refreshes
+/// gradually as old toggles slide out. Callers hold the LED state
+/// when a toggle is denied, protecting relays from wear.
+///
+/// # Fields
+/// * `window_ms` - Sliding window length in milliseconds
+/// * `stamps` - Ring of allowed toggle timestamps, oldest at head
+/// * `head` - Index of the oldest timestamp
+/// * `len` - Number of timestamps inside the window
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ToggleBudget<const MAX: usize> {
+    window_ms: u64,
+    stamps: [u64; MAX],
+    head: usize,
+    len: usize,
+}
+
+impl<const MAX: usize> ToggleBudget<MAX> {
+    /// Creates new budget with a full allowance.
+    ///
+    /// # Arguments
+    /// * `window_ms` - Sliding window length in milliseconds
+    ///
+    /// # Returns
+    /// * `Self` - New ToggleBudget instance
+    #[allow(dead_code)]
+    pub fn new(window_ms: u64) -> Self {
+        Self {
+            window_ms,
+            stamps: [0; MAX],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the toggle cap per window.
+    ///
+    /// # Returns
+    /// * `usize` - Maximum toggles per window
+    #[allow(dead_code)]
+    pub const fn max_per_window(&self) -> usize {
+        MAX
+    }
+
+    /// Decides whether a toggle now fits the budget.
+    ///
+    /// # Details
+    /// Expires toggles at least window_ms old, then records and allows
+    /// the toggle if fewer than MAX remain. Denied toggles are not
+    /// recorded.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `bool` - true if the toggle may happen
+    #[allow(dead_code)]
+    pub fn allow(&mut self, now_ms: u64) -> bool {
+        while self.len > 0 && now_ms.saturating_sub(self.stamps[self.head]) >= self.window_ms {
+            self.head = (self.head + 1) % MAX;
+            self.len -= 1;
+        }
+        if self.len == MAX {
+            return false;
+        }
+        self.stamps[(self.head + self.len) % MAX] = now_ms;
+        self.len += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Toggle Budget Tests ====================
+
+    #[test]
+    fn test_toggles_within_budget_allowed() {
+        let mut budget = ToggleBudget::<3>::new(1000);
+        assert!(budget.allow(0));
+        assert!(budget.allow(10));
+        assert!(budget.allow(20));
+        assert_eq!(budget.max_per_window(), 3);
+    }
+
+    #[test]
+    fn test_toggles_beyond_budget_denied() {
+        let mut budget = ToggleBudget::<3>::new(1000);
+        for now in [0, 10, 20] {
+            assert!(budget.allow(now));
+        }
+        assert!(!budget.allow(30));
+        assert!(!budget.allow(999));
+    }
+
+    #[test]
+    fn test_budget_refreshes_after_window() {
+        let mut budget = ToggleBudget::<3>::new(1000);
+        for now in [0, 10, 20] {
+            assert!(budget.allow(now));
+        }
+        assert!(budget.allow(1000));
+        assert!(!budget.allow(1005));
+        assert!(budget.allow(1010));
+        assert!(budget.allow(1020));
+        assert!(!budget.allow(1500));
+    }
+
+    #[test]
+    fn test_window_slides_with_ring_wrap() {
+        let mut budget = ToggleBudget::<2>::new(100);
+        for step in 0..10u64 {
+            let start = step * 150;
+            assert!(budget.allow(start));
+            assert!(budget.allow(start + 1));
+            assert!(!budget.allow(start + 2));
+        }
+    }
+
+    #[test]
+    fn test_zero_budget_denies_all() {
+        let mut budget = ToggleBudget::<0>::new(1000);
+        assert!(!budget.allow(0));
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * @file bus_activity.rs
+ * @brief CAN bus activity monitor
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: bus_activity.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Bus Activity Monitor.
+//!
+//! BRIEF:
+//! Classifies bus traffic as active or quiet for LED mode selection.
+//! Timeout logic is independent of the CAN peripheral.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::BUS_QUIET_TIMEOUT_MS;
+use crate::led::LedMode;
+
+/// Bus activity monitor with quiet timeout.
+///
+/// # Details
+/// Records the timestamp of the most recent bus message.
+/// Classifies the bus as active or quiet for LED mode selection.
+/// Independent of the CAN peripheral so it can be tested on host.
+///
+/// # Fields
+/// * `timeout_ms` - Quiet timeout in milliseconds
+/// * `last_seen_ms` - Timestamp of the last message, if any
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct BusMonitor {
+    timeout_ms: u64,
+    last_seen_ms: Option<u64>,
+}
+
+impl Default for BusMonitor {
+    /// Returns default BusMonitor instance.
+    ///
+    /// # Details
+    /// Delegates to new() with the configured quiet timeout.
+    ///
+    /// # Returns
+    /// * `Self` - New BusMonitor with default timeout
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(BUS_QUIET_TIMEOUT_MS)
+    }
+}
+
+impl BusMonitor {
+    /// Creates new bus monitor with the given quiet timeout.
+    ///
+    /// # Details
+    /// Starts with no message seen so the bus is quiet.
+    ///
+    /// # Arguments
+    /// * `timeout_ms` - Quiet timeout in milliseconds
+    ///
+    /// # Returns
+    /// * `Self` - New BusMonitor instance
+    #[allow(dead_code)]
+    pub fn new(timeout_ms: u64) -> Self {
+        Self {
+            timeout_ms,
+            last_seen_ms: None,
+        }
+    }
+
+    /// Records that a bus message was received.
+    ///
+    /// # Details
+    /// Called from the CAN receive path for every message.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time in milliseconds
+    #[allow(dead_code)]
+    pub fn message_seen(&mut self, now_ms: u64) {
+        self.last_seen_ms = Some(now_ms);
+    }
+
+    /// Returns LED mode for the current bus activity.
+    ///
+    /// # Details
+    /// Blinks while a message was seen within the timeout.
+    /// Goes solid once the bus has been quiet for the timeout.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `LedMode` - Blink when active, Solid when quiet
+    #[allow(dead_code)]
+    pub fn state(&self, now_ms: u64) -> LedMode {
+        match self.last_seen_ms {
+            Some(seen) if now_ms.saturating_sub(seen) < self.timeout_ms => LedMode::Blink,
+            _ => LedMode::Solid,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== BusMonitor Tests ====================
+
+    #[test]
+    fn test_new_monitor_is_quiet() {
+        let monitor = BusMonitor::new(100);
+        assert_eq!(monitor.state(0), LedMode::Solid);
+    }
+
+    #[test]
+    fn test_default_uses_config_timeout() {
+        assert_eq!(BusMonitor::default(), BusMonitor::new(BUS_QUIET_TIMEOUT_MS));
+    }
+
+    #[test]
+    fn test_recent_message_is_active() {
+        let mut monitor = BusMonitor::new(100);
+        monitor.message_seen(1000);
+        assert_eq!(monitor.state(1000), LedMode::Blink);
+        assert_eq!(monitor.state(1099), LedMode::Blink);
+    }
+
+    #[test]
+    fn test_timeout_elapsed_is_quiet() {
+        let mut monitor = BusMonitor::new(100);
+        monitor.message_seen(1000);
+        assert_eq!(monitor.state(1100), LedMode::Solid);
+        assert_eq!(monitor.state(5000), LedMode::Solid);
+    }
+
+    #[test]
+    fn test_new_message_reactivates() {
+        let mut monitor = BusMonitor::new(100);
+        monitor.message_seen(1000);
+        assert_eq!(monitor.state(2000), LedMode::Solid);
+        monitor.message_seen(2000);
+        assert_eq!(monitor.state(2050), LedMode::Blink);
+    }
+}
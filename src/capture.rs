@@ -0,0 +1,134 @@
+/*
+ * @file capture.rs
+ * @brief Input frequency to blink rate mapping
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: capture.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Input Frequency Blink Rate.
+//!
+//! BRIEF:
+//! Maps a measured input-capture frequency, such as a tachometer
+//! signal, onto a clamped blink delay.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::MAX_BLINK_DELAY_MS;
+use crate::led::clamp_delay;
+
+/// Converts an input-capture period to a frequency.
+///
+/// # Details
+/// Glue for a capture reading giving the counter ticks between two
+/// rising edges of the input signal.
+///
+/// # Arguments
+/// * `period_ticks` - Ticks between consecutive edges
+/// * `tick_hz` - Capture counter clock in Hz
+///
+/// # Returns
+/// * `f32` - Measured frequency in Hz, 0.0 for a zero period
+#[allow(dead_code)]
+pub fn hz_from_capture(period_ticks: u32, tick_hz: u32) -> f32 {
+    if period_ticks == 0 {
+        return 0.0;
+    }
+    tick_hz as f32 / period_ticks as f32
+}
+
+/// Maps a measured input frequency to a blink delay.
+///
+/// # Details
+/// One full blink per input cycle, so each phase lasts half the
+/// input period. The result is clamped to the allowed delay range.
+/// Zero, negative and NaN frequencies give the slowest blink.
+///
+/// # Arguments
+/// * `measured_hz` - Measured input frequency in Hz
+///
+/// # Returns
+/// * `u64` - Blink delay in milliseconds
+#[allow(dead_code)]
+pub fn delay_from_input_hz(measured_hz: f32) -> u64 {
+    if measured_hz.is_nan() || measured_hz <= 0.0 {
+        return MAX_BLINK_DELAY_MS;
+    }
+    clamp_delay((500.0 / measured_hz) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MIN_BLINK_DELAY_MS;
+
+    // ==================== Frequency Mapping Tests ====================
+
+    #[test]
+    fn test_one_hz_is_half_second_phase() {
+        assert_eq!(delay_from_input_hz(1.0), 500);
+    }
+
+    #[test]
+    fn test_higher_frequency_is_faster() {
+        assert_eq!(delay_from_input_hz(5.0), 100);
+        assert_eq!(delay_from_input_hz(25.0), 20);
+    }
+
+    #[test]
+    fn test_very_high_frequency_clamped_to_min() {
+        assert_eq!(delay_from_input_hz(10_000.0), MIN_BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_very_low_frequency_clamped_to_max() {
+        assert_eq!(delay_from_input_hz(0.001), MAX_BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_zero_and_invalid_frequency_slowest() {
+        assert_eq!(delay_from_input_hz(0.0), MAX_BLINK_DELAY_MS);
+        assert_eq!(delay_from_input_hz(-3.0), MAX_BLINK_DELAY_MS);
+        assert_eq!(delay_from_input_hz(f32::NAN), MAX_BLINK_DELAY_MS);
+    }
+
+    // ==================== Capture Glue Tests ====================
+
+    #[test]
+    fn test_hz_from_capture() {
+        assert_eq!(hz_from_capture(1_000_000, 1_000_000), 1.0);
+        assert_eq!(hz_from_capture(200_000, 1_000_000), 5.0);
+        assert_eq!(hz_from_capture(0, 1_000_000), 0.0);
+    }
+
+    #[test]
+    fn test_capture_to_delay() {
+        assert_eq!(delay_from_input_hz(hz_from_capture(40_000, 1_000_000)), 20);
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ * @file color.rs
+ * @brief Named NeoPixel colors
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: color.rs
+//!
+//! DESCRIPTION:
+//! RP2350 NeoPixel Color Table.
+//!
+//! BRIEF:
+//! Defines GRB colors with named constants and brightness scaling.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+/// NeoPixel color in GRB order.
+///
+/// # Details
+/// Fields are declared in wire order for WS2812-style pixels.
+///
+/// # Fields
+/// * `g` - Green channel
+/// * `r` - Red channel
+/// * `b` - Blue channel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Color {
+    pub g: u8,
+    pub r: u8,
+    pub b: u8,
+}
+
+impl Color {
+    /// Pure red.
+    #[allow(dead_code)]
+    pub const RED: Color = Color::rgb(255, 0, 0);
+
+    /// Pure green.
+    #[allow(dead_code)]
+    pub const GREEN: Color = Color::rgb(0, 255, 0);
+
+    /// Pure blue.
+    #[allow(dead_code)]
+    pub const BLUE: Color = Color::rgb(0, 0, 255);
+
+    /// Full white.
+    #[allow(dead_code)]
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    /// Pixel off.
+    #[allow(dead_code)]
+    pub const OFF: Color = Color::rgb(0, 0, 0);
+
+    /// Creates color from red, green and blue components.
+    ///
+    /// # Arguments
+    /// * `r` - Red channel
+    /// * `g` - Green channel
+    /// * `b` - Blue channel
+    ///
+    /// # Returns
+    /// * `Self` - New Color instance
+    #[allow(dead_code)]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { g, r, b }
+    }
+
+    /// Returns color scaled to a brightness percentage.
+    ///
+    /// # Details
+    /// Percentages above 100 are treated as 100.
+    ///
+    /// # Arguments
+    /// * `pct` - Brightness in percent (0-100)
+    ///
+    /// # Returns
+    /// * `Self` - Scaled color
+    #[allow(dead_code)]
+    pub const fn with_brightness(self, pct: u8) -> Self {
+        let pct = if pct > 100 { 100 } else { pct };
+        Self {
+            g: scale(self.g, pct),
+            r: scale(self.r, pct),
+            b: scale(self.b, pct),
+        }
+    }
+
+    /// Returns color as wire-order bytes.
+    ///
+    /// # Returns
+    /// * `[u8; 3]` - Green, red, blue bytes
+    #[allow(dead_code)]
+    pub const fn to_grb(self) -> [u8; 3] {
+        [self.g, self.r, self.b]
+    }
+}
+
+/// Scales one channel by a percentage.
+///
+/// # Arguments
+/// * `channel` - Channel value
+/// * `pct` - Brightness in percent (0-100)
+///
+/// # Returns
+/// * `u8` - Scaled channel value
+const fn scale(channel: u8, pct: u8) -> u8 {
+    (channel as u16 * pct as u16 / 100) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Named Color Tests ====================
+
+    #[test]
+    fn test_named_colors_grb_order() {
+        assert_eq!(Color::RED.to_grb(), [0, 255, 0]);
+        assert_eq!(Color::GREEN.to_grb(), [255, 0, 0]);
+        assert_eq!(Color::BLUE.to_grb(), [0, 0, 255]);
+        assert_eq!(Color::WHITE.to_grb(), [255, 255, 255]);
+        assert_eq!(Color::OFF.to_grb(), [0, 0, 0]);
+    }
+
+    // ==================== Brightness Scaling Tests ====================
+
+    #[test]
+    fn test_brightness_zero() {
+        assert_eq!(Color::WHITE.with_brightness(0), Color::OFF);
+    }
+
+    #[test]
+    fn test_brightness_half() {
+        assert_eq!(Color::WHITE.with_brightness(50).to_grb(), [127, 127, 127]);
+        assert_eq!(Color::RED.with_brightness(50), Color::rgb(127, 0, 0));
+    }
+
+    #[test]
+    fn test_brightness_full() {
+        assert_eq!(Color::BLUE.with_brightness(100), Color::BLUE);
+    }
+
+    #[test]
+    fn test_brightness_above_full_clamped() {
+        assert_eq!(Color::GREEN.with_brightness(200), Color::GREEN);
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * @file color_temp.rs
+ * @brief Dual-white color temperature
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: color_temp.rs
+//!
+//! DESCRIPTION:
+//! Dual-White Color Temperature Mixing.
+//!
+//! BRIEF:
+//! Splits blink brightness between warm and cool white PWM channels
+//! to reach a requested color temperature.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{COOL_WHITE_K, PWM_TOP, WARM_WHITE_K};
+use crate::led::LedState;
+
+/// Dual-white color temperature mixer.
+///
+/// # Details
+/// Splits a brightness between a warm and a cool white PWM channel.
+/// Temperatures at or below the warm LED's rating drive only the warm
+/// channel, at or above the cool rating only the cool channel, and
+/// anything between is mixed linearly. Brightness 255 puts the two
+/// channels' sum at the PWM top.
+///
+/// # Fields
+/// * `warm_k` - Warm white LED color temperature in kelvin
+/// * `cool_k` - Cool white LED color temperature in kelvin
+/// * `top` - PWM counter top value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ColorTemp {
+    warm_k: u16,
+    cool_k: u16,
+    top: u16,
+}
+
+impl Default for ColorTemp {
+    /// Returns default ColorTemp instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New ColorTemp with the configured LEDs
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColorTemp {
+    /// Creates new mixer for the configured LEDs.
+    ///
+    /// # Returns
+    /// * `Self` - Mixer for WARM_WHITE_K and COOL_WHITE_K at PWM_TOP
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::with_range(WARM_WHITE_K, COOL_WHITE_K, PWM_TOP)
+    }
+
+    /// Creates new mixer for a custom LED pair.
+    ///
+    /// # Details
+    /// A cool rating below the warm rating is raised to match it.
+    ///
+    /// # Arguments
+    /// * `warm_k` - Warm white LED color temperature in kelvin
+    /// * `cool_k` - Cool white LED color temperature in kelvin
+    /// * `top` - PWM counter top value
+    ///
+    /// # Returns
+    /// * `Self` - New ColorTemp instance
+    #[allow(dead_code)]
+    pub fn with_range(warm_k: u16, cool_k: u16, top: u16) -> Self {
+        Self {
+            warm_k,
+            cool_k: cool_k.max(warm_k),
+            top,
+        }
+    }
+
+    /// Returns warm and cool duties for a temperature.
+    ///
+    /// # Arguments
+    /// * `temp_k` - Requested color temperature in kelvin
+    /// * `brightness` - Overall brightness (0-255)
+    ///
+    /// # Returns
+    /// * `(u16, u16)` - Warm and cool compare values
+    #[allow(dead_code)]
+    pub fn channels_for(&self, temp_k: u16, brightness: u8) -> (u16, u16) {
+        let total = u32::from(self.top) * u32::from(brightness) / 255;
+        let span = u32::from(self.cool_k - self.warm_k);
+        if span == 0 {
+            return if temp_k < self.cool_k {
+                (total as u16, 0)
+            } else {
+                (0, total as u16)
+            };
+        }
+        let cool_part = u32::from(temp_k.clamp(self.warm_k, self.cool_k) - self.warm_k);
+        let cool = total * cool_part / span;
+        ((total - cool) as u16, cool as u16)
+    }
+
+    /// Returns warm and cool duties for an LED state.
+    ///
+    /// # Details
+    /// The temperature mix is only applied during the on phase; the
+    /// off phase leaves both channels dark.
+    ///
+    /// # Arguments
+    /// * `state` - Current LED state
+    /// * `temp_k` - Requested color temperature in kelvin
+    /// * `brightness` - Overall brightness (0-255)
+    ///
+    /// # Returns
+    /// * `(u16, u16)` - Warm and cool compare values
+    #[allow(dead_code)]
+    pub fn channels_for_state(&self, state: LedState, temp_k: u16, brightness: u8) -> (u16, u16) {
+        match state {
+            LedState::On => self.channels_for(temp_k, brightness),
+            LedState::Off => (0, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Mix Tests ====================
+
+    #[test]
+    fn test_extreme_warm_uses_warm_channel() {
+        let mixer = ColorTemp::with_range(2700, 6500, 1000);
+        assert_eq!(mixer.channels_for(2700, 255), (1000, 0));
+        assert_eq!(mixer.channels_for(1000, 255), (1000, 0));
+    }
+
+    #[test]
+    fn test_extreme_cool_uses_cool_channel() {
+        let mixer = ColorTemp::with_range(2700, 6500, 1000);
+        assert_eq!(mixer.channels_for(6500, 255), (0, 1000));
+        assert_eq!(mixer.channels_for(u16::MAX, 255), (0, 1000));
+    }
+
+    #[test]
+    fn test_mid_temp_splits_channels() {
+        let mixer = ColorTemp::with_range(2700, 6500, 1000);
+        assert_eq!(mixer.channels_for(4600, 255), (500, 500));
+        let (warm, cool) = mixer.channels_for(3650, 255);
+        assert_eq!((warm, cool), (750, 250));
+    }
+
+    #[test]
+    fn test_brightness_scales_total() {
+        let mixer = ColorTemp::with_range(2700, 6500, 1000);
+        let (warm, cool) = mixer.channels_for(4600, 51);
+        assert_eq!(warm + cool, 200);
+        assert_eq!(mixer.channels_for(4600, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_off_phase_is_dark() {
+        let mixer = ColorTemp::new();
+        assert_eq!(mixer.channels_for_state(LedState::Off, 4000, 255), (0, 0));
+        assert_eq!(
+            mixer.channels_for_state(LedState::On, WARM_WHITE_K, 255),
+            (PWM_TOP, 0)
+        );
+    }
+}
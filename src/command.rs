@@ -0,0 +1,316 @@
+/*
+ * @file command.rs
+ * @brief Console command parser
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: command.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Console Command Parser.
+//!
+//! BRIEF:
+//! Parses console lines into blink commands without allocation.
+//! Numeric arguments accept decimal, hex and binary.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::pattern::Pattern;
+
+/// Blink command enumeration.
+///
+/// # Details
+/// Commands parsed from a console line.
+///
+/// # Variants
+/// * `Delay` - Set the blink delay in milliseconds
+/// * `Help` - Print the command list
+/// * `Stats` - Print accumulated blink statistics
+/// * `Identify` - Run the identify blink for the given seconds
+/// * `Lock` - Lock the configuration against commands
+/// * `Unlock` - Unlock the configuration with a code
+/// * `Pattern` - Select the blink pattern
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum BlinkCommand {
+    Delay(u64),
+    Help,
+    Stats,
+    Identify(u16),
+    Lock,
+    Unlock(u32),
+    Pattern(Pattern),
+}
+
+/// Console help text.
+const HELP_TEXT: &str = "commands:\r\n  delay <ms>      set blink delay (decimal, 0x hex, 0b binary)\r\n  stats           show on/off time and duty\r\n  identify <s>    flash identify blink for s seconds\r\n  lock            lock configuration\r\n  unlock <code>   unlock configuration\r\n  pattern <name>  select blink pattern\r\n  help | ?        show this list\r\n";
+
+/// Returns the console help text.
+///
+/// # Details
+/// Lists every command verb for the console to print.
+///
+/// # Returns
+/// * `&'static str` - Help text
+#[allow(dead_code)]
+pub fn help_text() -> &'static str {
+    HELP_TEXT
+}
+
+/// Command parse error enumeration.
+///
+/// # Variants
+/// * `Empty` - Line contained no command
+/// * `UnknownCommand` - Command verb not recognized
+/// * `MissingArgument` - Required argument absent
+/// * `BadArgument` - Argument could not be parsed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand,
+    MissingArgument,
+    BadArgument,
+}
+
+/// Parses an unsigned integer in decimal, hex or binary.
+///
+/// # Details
+/// Accepts `0x`/`0X` for hex and `0b`/`0B` for binary prefixes;
+/// anything else is decimal. Signs, empty digits, invalid digits
+/// and overflow are rejected.
+///
+/// # Arguments
+/// * `text` - Number text
+///
+/// # Returns
+/// * `Result<u64, ParseError>` - Parsed value or BadArgument
+#[allow(dead_code)]
+pub fn parse_number(text: &str) -> Result<u64, ParseError> {
+    let (digits, radix) = match text.get(..2) {
+        Some("0x" | "0X") => (&text[2..], 16),
+        Some("0b" | "0B") => (&text[2..], 2),
+        _ => (text, 10),
+    };
+    if digits.is_empty() || digits.starts_with('+') {
+        return Err(ParseError::BadArgument);
+    }
+    u64::from_str_radix(digits, radix).map_err(|_| ParseError::BadArgument)
+}
+
+/// Parses a console line into a command.
+///
+/// # Details
+/// Splits on whitespace; the first word is the command verb.
+/// Supported commands: `delay <ms>`, `identify <s>`, `lock`,
+/// `unlock <code>`, `pattern <name>`, `stats`, `help` and `?`.
+/// Identify durations must fit in u16 and unlock codes in u32.
+/// Pattern names are those returned by Pattern::name().
+///
+/// # Arguments
+/// * `line` - Input line
+///
+/// # Returns
+/// * `Result<BlinkCommand, ParseError>` - Parsed command or error
+#[allow(dead_code)]
+pub fn parse_command(line: &str) -> Result<BlinkCommand, ParseError> {
+    let mut words = line.split_whitespace();
+    let verb = words.next().ok_or(ParseError::Empty)?;
+    match verb {
+        "delay" => {
+            let arg = words.next().ok_or(ParseError::MissingArgument)?;
+            Ok(BlinkCommand::Delay(parse_number(arg)?))
+        }
+        "identify" => {
+            let arg = words.next().ok_or(ParseError::MissingArgument)?;
+            let secs = u16::try_from(parse_number(arg)?).map_err(|_| ParseError::BadArgument)?;
+            Ok(BlinkCommand::Identify(secs))
+        }
+        "lock" => Ok(BlinkCommand::Lock),
+        "unlock" => {
+            let arg = words.next().ok_or(ParseError::MissingArgument)?;
+            let code = u32::try_from(parse_number(arg)?).map_err(|_| ParseError::BadArgument)?;
+            Ok(BlinkCommand::Unlock(code))
+        }
+        "pattern" => {
+            let arg = words.next().ok_or(ParseError::MissingArgument)?;
+            let pattern = Pattern::from_name(arg).ok_or(ParseError::BadArgument)?;
+            Ok(BlinkCommand::Pattern(pattern))
+        }
+        "stats" => Ok(BlinkCommand::Stats),
+        "help" | "?" => Ok(BlinkCommand::Help),
+        _ => Err(ParseError::UnknownCommand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Number Parsing Tests ====================
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_number("500"), Ok(500));
+        assert_eq!(parse_number("0"), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_number("0x1F4"), Ok(500));
+        assert_eq!(parse_number("0X1f4"), Ok(500));
+    }
+
+    #[test]
+    fn test_parse_binary() {
+        assert_eq!(parse_number("0b111110100"), Ok(500));
+        assert_eq!(parse_number("0B1"), Ok(1));
+    }
+
+    #[test]
+    fn test_parse_malformed_hex() {
+        assert_eq!(parse_number("0x1G4"), Err(ParseError::BadArgument));
+        assert_eq!(parse_number("0x"), Err(ParseError::BadArgument));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_digits() {
+        assert_eq!(parse_number("0b102"), Err(ParseError::BadArgument));
+        assert_eq!(parse_number("12a"), Err(ParseError::BadArgument));
+        assert_eq!(parse_number("+5"), Err(ParseError::BadArgument));
+        assert_eq!(parse_number("0x+5"), Err(ParseError::BadArgument));
+    }
+
+    #[test]
+    fn test_parse_overflow() {
+        assert_eq!(
+            parse_number("0x10000000000000000"),
+            Err(ParseError::BadArgument)
+        );
+        assert_eq!(
+            parse_number("18446744073709551616"),
+            Err(ParseError::BadArgument)
+        );
+    }
+
+    // ==================== Command Parsing Tests ====================
+
+    #[test]
+    fn test_parse_delay_command_radixes() {
+        assert_eq!(parse_command("delay 500"), Ok(BlinkCommand::Delay(500)));
+        assert_eq!(parse_command("delay 0x1F4"), Ok(BlinkCommand::Delay(500)));
+        assert_eq!(
+            parse_command("delay 0b111110100"),
+            Ok(BlinkCommand::Delay(500))
+        );
+    }
+
+    #[test]
+    fn test_parse_delay_malformed_hex() {
+        assert_eq!(parse_command("delay 0xZZ"), Err(ParseError::BadArgument));
+    }
+
+    #[test]
+    fn test_parse_help() {
+        assert_eq!(parse_command("help"), Ok(BlinkCommand::Help));
+        assert_eq!(parse_command("?"), Ok(BlinkCommand::Help));
+        assert_eq!(parse_command("  ? "), Ok(BlinkCommand::Help));
+    }
+
+    #[test]
+    fn test_parse_lock_unlock() {
+        assert_eq!(parse_command("lock"), Ok(BlinkCommand::Lock));
+        assert_eq!(parse_command("unlock 2350"), Ok(BlinkCommand::Unlock(2350)));
+        assert_eq!(parse_command("unlock"), Err(ParseError::MissingArgument));
+        assert_eq!(
+            parse_command("unlock 0x100000000"),
+            Err(ParseError::BadArgument)
+        );
+    }
+
+    #[test]
+    fn test_parse_stats() {
+        assert_eq!(parse_command("stats"), Ok(BlinkCommand::Stats));
+    }
+
+    #[test]
+    fn test_parse_identify() {
+        assert_eq!(parse_command("identify 30"), Ok(BlinkCommand::Identify(30)));
+        assert_eq!(parse_command("identify"), Err(ParseError::MissingArgument));
+        assert_eq!(
+            parse_command("identify 65536"),
+            Err(ParseError::BadArgument)
+        );
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        assert_eq!(
+            parse_command("pattern sos"),
+            Ok(BlinkCommand::Pattern(Pattern::Sos))
+        );
+        assert_eq!(parse_command("pattern"), Err(ParseError::MissingArgument));
+        assert_eq!(
+            parse_command("pattern strobe"),
+            Err(ParseError::BadArgument)
+        );
+    }
+
+    #[test]
+    fn test_help_text_mentions_verbs() {
+        for verb in [
+            "delay ",
+            "identify ",
+            "lock ",
+            "unlock ",
+            "pattern ",
+            "stats ",
+            "help | ? ",
+        ] {
+            assert!(help_text().contains(&format!("\r\n  {verb}")));
+        }
+    }
+
+    #[test]
+    fn test_help_text_columns_align() {
+        let mut rows = help_text().lines().skip(1);
+        let column = |row: &str| row.trim_end().rfind("  ").map(|i| i + 2);
+        let first = column(rows.next().unwrap());
+        assert!(first.is_some());
+        for row in rows {
+            assert_eq!(column(row), first, "{row}");
+        }
+    }
+
+    #[test]
+    fn test_parse_command_errors() {
+        assert_eq!(parse_command("   "), Err(ParseError::Empty));
+        assert_eq!(parse_command("delay"), Err(ParseError::MissingArgument));
+        assert_eq!(parse_command("blink 5"), Err(ParseError::UnknownCommand));
+    }
+}
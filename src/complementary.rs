@@ -0,0 +1,200 @@
+/*
+ * @file complementary.rs
+ * @brief Complementary LED pair with dead-time
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: complementary.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Complementary LED Pair.
+//!
+//! BRIEF:
+//! Drives a main/inverse LED pair with an all-off dead-time between phases.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{BLINK_DELAY_MS, DEAD_TIME_MS};
+use crate::led::{led_state_to_level, LedState};
+
+/// Output of one complementary pair step.
+///
+/// # Fields
+/// * `main` - Main LED level
+/// * `inverse` - Inverse LED level
+/// * `hold_ms` - Time to hold these levels in milliseconds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct PairStep {
+    pub main: bool,
+    pub inverse: bool,
+    pub hold_ms: u64,
+}
+
+/// Main/inverse LED pair with dead-time between phases.
+///
+/// # Details
+/// Alternates the main and inverse LEDs, inserting an all-off
+/// interval before every phase change when dead-time is non-zero.
+///
+/// # Fields
+/// * `state` - Current main LED state
+/// * `delay_ms` - Phase duration in milliseconds
+/// * `dead_time_ms` - All-off interval between phases
+/// * `pending` - Main state to assert after the dead-time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ComplementaryPair {
+    state: LedState,
+    delay_ms: u64,
+    dead_time_ms: u64,
+    pending: Option<LedState>,
+}
+
+impl Default for ComplementaryPair {
+    /// Returns default ComplementaryPair instance.
+    ///
+    /// # Details
+    /// Uses the default blink delay and configured dead-time.
+    ///
+    /// # Returns
+    /// * `Self` - New ComplementaryPair with default timing
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(BLINK_DELAY_MS, DEAD_TIME_MS)
+    }
+}
+
+impl ComplementaryPair {
+    /// Creates new pair with the main LED off.
+    ///
+    /// # Arguments
+    /// * `delay_ms` - Phase duration in milliseconds
+    /// * `dead_time_ms` - All-off interval between phases
+    ///
+    /// # Returns
+    /// * `Self` - New ComplementaryPair instance
+    #[allow(dead_code)]
+    pub fn new(delay_ms: u64, dead_time_ms: u64) -> Self {
+        Self {
+            state: LedState::Off,
+            delay_ms,
+            dead_time_ms,
+            pending: None,
+        }
+    }
+
+    /// Returns the next pair step.
+    ///
+    /// # Details
+    /// Every phase change first yields both LEDs off for the
+    /// dead-time, then the new main/inverse levels for the phase.
+    ///
+    /// # Returns
+    /// * `PairStep` - Levels to apply and how long to hold them
+    #[allow(dead_code)]
+    pub fn transition_step(&mut self) -> PairStep {
+        let next = match self.pending.take() {
+            Some(next) => next,
+            None => {
+                let next = match self.state {
+                    LedState::On => LedState::Off,
+                    LedState::Off => LedState::On,
+                };
+                if self.dead_time_ms > 0 {
+                    self.pending = Some(next);
+                    return PairStep {
+                        main: false,
+                        inverse: false,
+                        hold_ms: self.dead_time_ms,
+                    };
+                }
+                next
+            }
+        };
+        self.state = next;
+        let main = led_state_to_level(next);
+        PairStep {
+            main,
+            inverse: !main,
+            hold_ms: self.delay_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Dead-Time Tests ====================
+
+    #[test]
+    fn test_dead_time_before_each_phase() {
+        let mut pair = ComplementaryPair::new(100, 5);
+        let off = PairStep {
+            main: false,
+            inverse: false,
+            hold_ms: 5,
+        };
+        assert_eq!(pair.transition_step(), off);
+        assert_eq!(
+            pair.transition_step(),
+            PairStep {
+                main: true,
+                inverse: false,
+                hold_ms: 100,
+            }
+        );
+        assert_eq!(pair.transition_step(), off);
+        assert_eq!(
+            pair.transition_step(),
+            PairStep {
+                main: false,
+                inverse: true,
+                hold_ms: 100,
+            }
+        );
+    }
+
+    #[test]
+    fn test_zero_dead_time_skips_gap() {
+        let mut pair = ComplementaryPair::new(100, 0);
+        assert!(pair.transition_step().main);
+        assert!(pair.transition_step().inverse);
+        assert!(pair.transition_step().main);
+    }
+
+    #[test]
+    fn test_levels_never_overlap() {
+        let mut pair = ComplementaryPair::default();
+        for _ in 0..20 {
+            let step = pair.transition_step();
+            assert!(!(step.main && step.inverse));
+        }
+    }
+}
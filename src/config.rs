@@ -38,7 +38,9 @@
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: November 28, 2025
-//! UPDATE DATE: December 4, 2025
+//! UPDATE DATE: October 14, 2026
+
+use crate::pin::{OutputBackend, OutputMode};
 
 /// Default LED blink delay in milliseconds.
 ///
@@ -51,6 +53,16 @@
 #[allow(dead_code)]
 pub const BLINK_DELAY_MS: u64 = 500;
 
+/// CPU core clock frequency in hertz.
+///
+/// # Details
+/// Converts delays to busy-wait cycles for the HAL delay backend.
+///
+/// # Value
+/// 150000000 Hz (RP2350 default system clock)
+#[allow(dead_code)]
+pub const CPU_HZ: u32 = 150_000_000;
+
 /// Minimum allowed blink delay in milliseconds.
 ///
 /// # Details
@@ -61,6 +73,48 @@ pub const BLINK_DELAY_MS: u64 = 500;
 #[allow(dead_code)]
 pub const MIN_BLINK_DELAY_MS: u64 = 10;
 
+/// Shortest blink phase the eye can follow in milliseconds.
+///
+/// # Details
+/// Delays below this floor are handled by the sub-perceptual mode.
+///
+/// # Value
+/// 20 milliseconds
+#[allow(dead_code)]
+pub const PERCEPTIBLE_FLOOR_MS: u64 = 20;
+
+/// Watchdog timeout in milliseconds.
+///
+/// # Details
+/// Timeout of the hardware watchdog when it is in use. Blink delays
+/// are checked against a safe fraction of it. 0 means no watchdog.
+///
+/// # Value
+/// 0 milliseconds (no watchdog)
+#[allow(dead_code)]
+pub const WATCHDOG_TIMEOUT_MS: u64 = 0;
+
+/// Safe share of the watchdog timeout for one blink phase in percent.
+///
+/// # Details
+/// Leaves headroom for loop overhead so the watchdog is fed before
+/// it fires.
+///
+/// # Value
+/// 50 percent
+#[allow(dead_code)]
+pub const WATCHDOG_SAFE_PERCENT: u64 = 50;
+
+/// Fast blink phase time in milliseconds.
+///
+/// # Details
+/// ON and OFF time of Pattern::Fast, shown while connecting.
+///
+/// # Value
+/// 100 milliseconds
+#[allow(dead_code)]
+pub const FAST_BLINK_MS: u64 = 100;
+
 /// Maximum allowed blink delay in milliseconds.
 ///
 /// # Details
@@ -71,6 +125,603 @@ pub const MIN_BLINK_DELAY_MS: u64 = 10;
 #[allow(dead_code)]
 pub const MAX_BLINK_DELAY_MS: u64 = 10000;
 
+/// Default PWM duty cycle in percent.
+///
+/// # Details
+/// Duty applied while the LED is lit in PWM modes.
+///
+/// # Value
+/// 100 percent (full on)
+#[allow(dead_code)]
+pub const DEFAULT_DUTY_PERCENT: u8 = 100;
+
+/// Default off-phase PWM duty cycle in percent.
+///
+/// # Details
+/// Glow level applied during the off phase in PWM modes, giving a
+/// two-level bright/dim blink instead of on/dark.
+///
+/// # Value
+/// 0 percent (fully dark)
+#[allow(dead_code)]
+pub const DEFAULT_OFF_DUTY_PERCENT: u8 = 0;
+
+/// PWM duty inversion for common-anode LEDs.
+///
+/// # Details
+/// When set, higher brightness yields a lower PWM compare value.
+/// Complements active-low polarity for PWM outputs.
+///
+/// # Value
+/// false (common-cathode)
+#[allow(dead_code)]
+pub const DUTY_INVERT: bool = false;
+
+/// Default LED intensity level.
+///
+/// # Details
+/// Logical brightness (0-255) fed through the brightness curve.
+///
+/// # Value
+/// 255 (full brightness)
+#[allow(dead_code)]
+pub const DEFAULT_INTENSITY: u8 = 255;
+
+/// Default timing speed factor in percent.
+///
+/// # Details
+/// Scales every blink delay; 200 is half speed, 50 is double speed.
+///
+/// # Value
+/// 100 percent (normal speed)
+#[allow(dead_code)]
+pub const DEFAULT_SPEED_PERCENT: u16 = 100;
+
+/// PWM soft start ramp time in milliseconds.
+///
+/// # Details
+/// Duty ramps from 0 to target over this time on first enable to
+/// limit inrush current.
+///
+/// # Value
+/// 100 milliseconds
+#[allow(dead_code)]
+pub const SOFT_START_RAMP_MS: u64 = 100;
+
+/// PWM disable ramp-down time in milliseconds.
+///
+/// # Details
+/// Duty ramps from its setting to 0 over this time when blinking is
+/// disabled. 0 cuts the LED off immediately.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const DISABLE_RAMP_MS: u64 = 0;
+
+/// PWM disable ramp step in milliseconds.
+///
+/// # Details
+/// Hold time reported by each tick while a disable ramp runs, so the
+/// PWM compare follows the falling duty in steps of this size.
+///
+/// # Value
+/// 10 milliseconds
+#[allow(dead_code)]
+pub const DISABLE_RAMP_STEP_MS: u64 = 10;
+
+/// Complementary LED dead-time in milliseconds.
+///
+/// # Details
+/// Interval with both LEDs of a complementary pair off during a
+/// phase change, so the two outputs never overlap.
+///
+/// # Value
+/// 5 milliseconds
+#[allow(dead_code)]
+pub const DEAD_TIME_MS: u64 = 5;
+
+/// Default post-toggle settle time in microseconds.
+///
+/// # Details
+/// Extra wait after each pin write for LED drivers that need
+/// time to settle. Added on top of the blink delay; 0 disables it.
+///
+/// # Value
+/// 0 microseconds (disabled)
+#[allow(dead_code)]
+pub const SETTLE_US: u64 = 0;
+
+/// Phase lock correction gain in percent.
+///
+/// # Details
+/// Fraction of the measured phase error corrected per toggle.
+/// 100 snaps to the reference immediately; lower values nudge gently.
+///
+/// # Value
+/// 50 percent
+#[allow(dead_code)]
+pub const PHASE_LOCK_GAIN_PERCENT: u64 = 50;
+
+/// Full-scale ADC reading.
+///
+/// # Details
+/// Maximum value of the 12-bit RP2350 ADC.
+///
+/// # Value
+/// 4095
+#[allow(dead_code)]
+pub const ADC_MAX: u16 = 4095;
+
+/// Ambient light smoothing factor in percent.
+///
+/// # Details
+/// Weight of each new reading in the exponential moving average.
+///
+/// # Value
+/// 25 percent
+#[allow(dead_code)]
+pub const AMBIENT_ALPHA_PERCENT: u8 = 25;
+
+/// Minimum auto-dim duty in percent.
+///
+/// # Details
+/// Duty used in complete darkness so the LED stays visible.
+///
+/// # Value
+/// 5 percent
+#[allow(dead_code)]
+pub const AUTO_DIM_MIN_DUTY: u8 = 5;
+
+/// Number of warmup blinks before the steady pattern.
+///
+/// # Details
+/// Fast full-brightness blinks run once at start for LEDs that
+/// behave oddly cold; 0 disables warmup.
+///
+/// # Value
+/// 0 blinks (disabled)
+#[allow(dead_code)]
+pub const WARMUP_BLINKS: u8 = 0;
+
+/// Warmup blink phase delay in milliseconds.
+///
+/// # Details
+/// ON and OFF duration of each warmup blink.
+///
+/// # Value
+/// 50 milliseconds
+#[allow(dead_code)]
+pub const WARMUP_DELAY_MS: u64 = 50;
+
+/// Maximum continuous LED on-time in milliseconds.
+///
+/// # Details
+/// Failsafe for high-power LEDs: an on-phase longer than this is
+/// cut short and forced off. A value of 0 disables the failsafe.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const MAX_ON_MS: u64 = 0;
+
+/// Blink delay resolution of the status word in milliseconds.
+///
+/// # Details
+/// The status word stores the delay as a count of these units.
+///
+/// # Value
+/// 10 milliseconds
+#[allow(dead_code)]
+pub const STATUS_DELAY_UNIT_MS: u64 = 10;
+
+/// Blink delay change per rotary encoder detent in milliseconds.
+///
+/// # Details
+/// Clockwise detents lengthen the delay; counter-clockwise shorten it.
+///
+/// # Value
+/// 10 milliseconds
+#[allow(dead_code)]
+pub const ENCODER_STEP_MS: u64 = 10;
+
+/// Progress blink delay at 0 percent in milliseconds.
+///
+/// # Details
+/// Slowest blink rate of the progress indicator.
+///
+/// # Value
+/// 1000 milliseconds
+#[allow(dead_code)]
+pub const PROGRESS_SLOW_MS: u64 = 1000;
+
+/// Progress blink delay at 100 percent in milliseconds.
+///
+/// # Details
+/// Fastest blink rate of the progress indicator.
+///
+/// # Value
+/// 50 milliseconds
+#[allow(dead_code)]
+pub const PROGRESS_FAST_MS: u64 = 50;
+
+/// Thermal throttle warning threshold in degrees Celsius.
+///
+/// # Details
+/// Die temperatures above this switch the LED to the warning pattern.
+///
+/// # Value
+/// 70.0 degrees Celsius
+#[allow(dead_code)]
+pub const THERMAL_WARN_C: f32 = 70.0;
+
+/// Skip the first toggle of a fresh controller.
+///
+/// # Details
+/// When set the first toggle leaves the LED off without counting,
+/// adding one off phase before blinking starts.
+///
+/// # Value
+/// false
+#[allow(dead_code)]
+pub const SKIP_FIRST_TOGGLE: bool = false;
+
+/// Startup delay before the first blink in milliseconds.
+///
+/// # Details
+/// Holds the LED off after init to avoid inrush conflicts.
+/// A value of 0 starts blinking immediately.
+///
+/// # Value
+/// 0 milliseconds (disabled)
+#[allow(dead_code)]
+pub const STARTUP_DELAY_MS: u64 = 0;
+
+/// Maximum allowed startup delay in milliseconds.
+///
+/// # Details
+/// Upper bound applied to the startup delay.
+///
+/// # Value
+/// 5000 milliseconds (5 seconds)
+#[allow(dead_code)]
+pub const MAX_STARTUP_DELAY_MS: u64 = 5000;
+
+/// Blink code pulse ON time in milliseconds.
+///
+/// # Details
+/// Duration of each flash when emitting a numeric blink code.
+///
+/// # Value
+/// 200 milliseconds
+#[allow(dead_code)]
+pub const BLINK_CODE_ON_MS: u64 = 200;
+
+/// Blink code pulse OFF time in milliseconds.
+///
+/// # Details
+/// Dark interval following each flash of a numeric blink code.
+///
+/// # Value
+/// 300 milliseconds
+#[allow(dead_code)]
+pub const BLINK_CODE_OFF_MS: u64 = 300;
+
+/// Blink inversion switch GPIO pin.
+///
+/// # Details
+/// Input read by the invert task; while high the blink output is
+/// inverted. main.rs claims PIN_15 and fails to build if this differs.
+///
+/// # Value
+/// GPIO 15
+#[allow(dead_code)]
+pub const INVERT_INPUT_PIN: u8 = 15;
+
+/// Alive heartbeat LED GPIO pin.
+///
+/// # Details
+/// Dedicated status LED blinked by the heartbeat task, independent of
+/// the main indicator on GPIO 16. main.rs claims PIN_17 and fails to
+/// build if this differs.
+///
+/// # Value
+/// GPIO 17
+#[allow(dead_code)]
+pub const HEARTBEAT_LED_PIN: u8 = 17;
+
+/// Passthrough input GPIO pin.
+///
+/// # Details
+/// Input mirrored onto the main LED in passthrough mode. main.rs
+/// claims PIN_14 and fails to build if this differs.
+///
+/// # Value
+/// GPIO 14
+#[allow(dead_code)]
+pub const PASSTHROUGH_INPUT_PIN: u8 = 14;
+
+/// Alive heartbeat LED phase delay in milliseconds.
+///
+/// # Details
+/// Equal ON and OFF phases give a slow 1 Hz blink.
+///
+/// # Value
+/// 500 milliseconds
+#[allow(dead_code)]
+pub const HEARTBEAT_LED_DELAY_MS: u64 = 500;
+
+/// Firmware version as (major, minor).
+///
+/// # Details
+/// Flashed at boot as two blink codes. Keep in step with the
+/// Cargo.toml package version.
+///
+/// # Value
+/// 0.1
+#[allow(dead_code)]
+pub const VERSION: (u8, u8) = (0, 1);
+
+/// Gap between the hour and minute codes of a time readout in milliseconds.
+///
+/// # Details
+/// Long dark interval so the two numbers are not read as one.
+///
+/// # Value
+/// 1500 milliseconds
+#[allow(dead_code)]
+pub const TIME_CODE_GAP_MS: u64 = 1500;
+
+/// Input debounce stable time in milliseconds.
+///
+/// # Details
+/// An input must hold a new level this long before it is reported.
+///
+/// # Value
+/// 20 milliseconds
+#[allow(dead_code)]
+pub const DEBOUNCE_MS: u64 = 20;
+
+/// Group sync flash duration in milliseconds.
+///
+/// # Details
+/// Time all LEDs are held on together by a group flash.
+///
+/// # Value
+/// 150 milliseconds
+#[allow(dead_code)]
+pub const GROUP_FLASH_MS: u64 = 150;
+
+/// Command frame start byte.
+///
+/// # Details
+/// First byte of every CRC-protected UART command frame.
+///
+/// # Value
+/// 0x7E
+#[allow(dead_code)]
+pub const FRAME_START: u8 = 0x7E;
+
+/// Demo mode pattern switch interval in milliseconds.
+///
+/// # Details
+/// Time each randomly picked pattern plays in demo mode.
+///
+/// # Value
+/// 10000 milliseconds
+#[allow(dead_code)]
+pub const DEMO_INTERVAL_MS: u64 = 10_000;
+
+/// Configuration unlock code.
+///
+/// # Details
+/// Code the `unlock` command must give to lift a configuration lock.
+///
+/// # Value
+/// 2350
+#[allow(dead_code)]
+pub const UNLOCK_CODE: u32 = 2350;
+
+/// Minimum interval between accepted commands in milliseconds.
+///
+/// # Details
+/// Commands arriving faster than this are dropped by the rate limiter.
+///
+/// # Value
+/// 100 milliseconds
+#[allow(dead_code)]
+pub const COMMAND_MIN_INTERVAL_MS: u64 = 100;
+
+/// Blink script step capacity.
+///
+/// # Details
+/// Maximum number of on/off steps in an uploaded blink script.
+///
+/// # Value
+/// 16 steps
+#[allow(dead_code)]
+pub const SCRIPT_CAPACITY: usize = 16;
+
+/// Blink deadline tolerance in milliseconds.
+///
+/// # Details
+/// Lateness up to this value is not counted as a missed deadline.
+///
+/// # Value
+/// 2 milliseconds
+#[allow(dead_code)]
+pub const DEADLINE_TOLERANCE_MS: u64 = 2;
+
+/// Blink event channel capacity.
+///
+/// # Details
+/// Number of transition events buffered for the flash-logging task.
+///
+/// # Value
+/// 16 events
+#[allow(dead_code)]
+pub const EVENT_CHANNEL_CAPACITY: usize = 16;
+
+/// Command queue capacity.
+///
+/// # Details
+/// Number of pending commands buffered between input and the blink task.
+///
+/// # Value
+/// 8 commands
+#[allow(dead_code)]
+pub const COMMAND_QUEUE_CAPACITY: usize = 8;
+
+/// Warm white LED color temperature in kelvin.
+///
+/// # Details
+/// Rating of the warm channel of a dual-white LED.
+///
+/// # Value
+/// 2700 K
+#[allow(dead_code)]
+pub const WARM_WHITE_K: u16 = 2700;
+
+/// Cool white LED color temperature in kelvin.
+///
+/// # Details
+/// Rating of the cool channel of a dual-white LED.
+///
+/// # Value
+/// 6500 K
+#[allow(dead_code)]
+pub const COOL_WHITE_K: u16 = 6500;
+
+/// LED output driver backend.
+///
+/// # Details
+/// PWM dims the LED at the controller duty on PWM slice 0 channel A.
+/// The timer-isr build always drives digital.
+///
+/// # Value
+/// OutputBackend::Digital
+#[allow(dead_code)]
+pub const OUTPUT_BACKEND: OutputBackend = OutputBackend::Digital;
+
+/// PWM counter top value.
+///
+/// # Details
+/// Compare values run from 0 to this value for 0 to 100 percent.
+///
+/// # Value
+/// 1000
+#[allow(dead_code)]
+pub const PWM_TOP: u16 = 1000;
+
+/// LED output driver mode.
+///
+/// # Details
+/// Open-drain suits LEDs wired to an external supply through the pin.
+/// The timer-isr build always drives push-pull.
+///
+/// # Value
+/// OutputMode::PushPull
+#[allow(dead_code)]
+pub const OUTPUT_MODE: OutputMode = OutputMode::PushPull;
+
+/// Input passthrough mode.
+///
+/// # Details
+/// When true the main LED mirrors the passthrough input instead of
+/// blinking. Ignored by the timer-isr build.
+///
+/// # Value
+/// false
+#[allow(dead_code)]
+pub const PASSTHROUGH_MODE: bool = false;
+
+/// Maximum stored length of a console history line in bytes.
+///
+/// # Details
+/// Longer commands are truncated when pushed into the history.
+///
+/// # Value
+/// 32 bytes
+#[allow(dead_code)]
+pub const HISTORY_LINE_LEN: usize = 32;
+
+/// LED polarity sense threshold.
+///
+/// # Details
+/// Idle pin readings above this indicate an LED pulled to the supply
+/// (active-low wiring); readings at or below indicate active-high.
+///
+/// # Value
+/// 2048 (mid-scale of the 12-bit ADC)
+#[allow(dead_code)]
+pub const POLARITY_SENSE_THRESHOLD: u16 = 2048;
+
+/// Morse code time unit in milliseconds.
+///
+/// # Details
+/// Length of a dot; dashes and gaps are multiples of this unit.
+///
+/// # Value
+/// 200 milliseconds
+#[allow(dead_code)]
+pub const MORSE_UNIT_MS: u64 = 200;
+
+/// Total flash size in bytes.
+///
+/// # Details
+/// Matches the FLASH region length in memory.x.
+///
+/// # Value
+/// 4194304 bytes (4 MiB)
+#[allow(dead_code)]
+pub const FLASH_SIZE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Flash offset of the persisted blink configuration.
+///
+/// # Details
+/// Uses the last 4 KiB erase sector so firmware growth cannot reach it.
+///
+/// # Value
+/// FLASH_SIZE_BYTES - 4096
+#[allow(dead_code)]
+pub const CONFIG_FLASH_OFFSET: u32 = (FLASH_SIZE_BYTES - 4096) as u32;
+
+/// UART RX activity flash length in milliseconds.
+///
+/// # Details
+/// On-time of the brief flash for a received byte.
+///
+/// # Value
+/// 20 milliseconds
+#[allow(dead_code)]
+pub const RX_FLASH_MS: u64 = 20;
+
+/// Minimum UART RX flash spacing in milliseconds.
+///
+/// # Details
+/// Start-to-start spacing between activity flashes. Bytes arriving
+/// sooner coalesce into the previous flash, leaving a visible dark gap
+/// so bursts never merge into solid on.
+///
+/// # Value
+/// 60 milliseconds
+#[allow(dead_code)]
+pub const RX_FLASH_INTERVAL_MS: u64 = 60;
+
+/// Compile-time check that an RX flash ends before the next window.
+const _: () = assert!(RX_FLASH_INTERVAL_MS > RX_FLASH_MS);
+
+/// Bus quiet timeout in milliseconds.
+///
+/// # Details
+/// Time without a received bus message after which the bus is quiet.
+/// Selects between blinking (active) and solid (quiet) LED modes.
+///
+/// # Value
+/// 1000 milliseconds (1 second)
+#[allow(dead_code)]
+pub const BUS_QUIET_TIMEOUT_MS: u64 = 1000;
+
 #[cfg(test)]
 mod tests {
     use super::*;
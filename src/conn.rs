@@ -0,0 +1,115 @@
+/*
+ * @file conn.rs
+ * @brief Connection state indicator
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: conn.rs
+//!
+//! DESCRIPTION:
+//! Connection State Indicator.
+//!
+//! BRIEF:
+//! Maps connecting, connected and error link states to distinct
+//! blink patterns.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::pattern::Pattern;
+
+/// Connection state enumeration.
+///
+/// # Details
+/// Link status shown by a connectivity indicator.
+///
+/// # Variants
+/// * `Connecting` - Link is being established
+/// * `Connected` - Link is up
+/// * `Error` - Link failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum ConnState {
+    Connecting,
+    Connected,
+    Error,
+}
+
+/// Returns the blink pattern for a connection state.
+///
+/// # Details
+/// Connecting blinks fast, connected shows a calm heartbeat and an
+/// error flashes SOS, so each state reads differently at a glance.
+///
+/// # Arguments
+/// * `state` - Current connection state
+///
+/// # Returns
+/// * `Pattern` - Pattern to show for the state
+#[allow(dead_code)]
+pub fn pattern_for_conn(state: ConnState) -> Pattern {
+    match state {
+        ConnState::Connecting => Pattern::Fast,
+        ConnState::Connected => Pattern::Heartbeat,
+        ConnState::Error => Pattern::Sos,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Pattern Mapping Tests ====================
+
+    #[test]
+    fn test_connecting_is_fast() {
+        assert_eq!(pattern_for_conn(ConnState::Connecting), Pattern::Fast);
+    }
+
+    #[test]
+    fn test_connected_is_heartbeat() {
+        assert_eq!(pattern_for_conn(ConnState::Connected), Pattern::Heartbeat);
+    }
+
+    #[test]
+    fn test_error_is_sos() {
+        assert_eq!(pattern_for_conn(ConnState::Error), Pattern::Sos);
+    }
+
+    #[test]
+    fn test_states_have_distinct_patterns() {
+        let patterns = [
+            ConnState::Connecting,
+            ConnState::Connected,
+            ConnState::Error,
+        ]
+        .map(pattern_for_conn);
+        assert_ne!(patterns[0], patterns[1]);
+        assert_ne!(patterns[1], patterns[2]);
+        assert_ne!(patterns[0], patterns[2]);
+    }
+}
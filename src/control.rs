@@ -0,0 +1,677 @@
+/*
+ * @file control.rs
+ * @brief Blink control layer helpers
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: control.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Blink Control Layer.
+//!
+//! BRIEF:
+//! Provides command flow helpers sitting between input and the blink task.
+//! Includes the command queue, rate limiting against command flooding
+//! and toggle count waits.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::command::BlinkCommand;
+use crate::config::COMMAND_MIN_INTERVAL_MS;
+#[cfg(feature = "embassy-sync")]
+use crate::config::COMMAND_QUEUE_CAPACITY;
+use crate::led::{LedController, LockError, WatchdogError};
+use crate::store::{ConfigError, ConfigStore};
+#[cfg(feature = "embassy-sync")]
+use core::cell::RefCell;
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "embassy-sync")]
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+#[cfg(feature = "embassy-sync")]
+use embassy_sync::blocking_mutex::Mutex;
+#[cfg(feature = "embassy-sync")]
+use embassy_sync::signal::Signal;
+
+/// Latest toggle count published by the blink task.
+///
+/// # Details
+/// Signalled with the controller's toggle count after every toggle.
+/// Holds a single waiter; concurrent waiters steal each other's wakeups.
+#[cfg(feature = "embassy-sync")]
+#[allow(dead_code)]
+pub static TOGGLE_COUNT: Signal<CriticalSectionRawMutex, u64> = Signal::new();
+
+/// Live blink inversion flag.
+///
+/// # Details
+/// Written by the invert input task and read by the blink task
+/// before every pin write.
+#[allow(dead_code)]
+pub static INVERT: AtomicBool = AtomicBool::new(false);
+
+/// One-shot completion signal.
+///
+/// # Details
+/// Raised by play_steps when a burst, alert or Morse message has
+/// finished playing.
+#[cfg(feature = "embassy-sync")]
+#[allow(dead_code)]
+pub static ONE_SHOT_DONE: Signal<CriticalSectionRawMutex, ()> = Signal::new();
+
+/// Pending commands shared between input handlers and the blink task.
+///
+/// # Details
+/// Input handlers push; the blink task drains once per cycle.
+#[cfg(feature = "embassy-sync")]
+#[allow(dead_code)]
+pub static COMMAND_QUEUE: Mutex<
+    CriticalSectionRawMutex,
+    RefCell<CommandQueue<COMMAND_QUEUE_CAPACITY>>,
+> = Mutex::new(RefCell::new(CommandQueue::new()));
+
+/// Command queue error enumeration.
+///
+/// # Variants
+/// * `Full` - Queue is at capacity; the command was dropped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum QueueError {
+    Full,
+}
+
+/// Command application error enumeration.
+///
+/// # Variants
+/// * `Lock` - Rejected by the configuration lock, or a bad unlock code
+/// * `Watchdog` - Delay is unsafe for the watchdog and was not applied
+/// * `Store` - Applied, but persisting the change failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum CommandError {
+    Lock(LockError),
+    Watchdog(WatchdogError),
+    Store(ConfigError),
+}
+
+/// Fixed-capacity FIFO of blink commands.
+///
+/// # Details
+/// Ring buffer decoupling command input from the blink task.
+/// Never allocates; pushes beyond capacity are rejected.
+///
+/// # Fields
+/// * `slots` - Ring buffer storage
+/// * `head` - Index of the oldest command
+/// * `len` - Number of queued commands
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct CommandQueue<const N: usize> {
+    slots: [Option<BlinkCommand>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for CommandQueue<N> {
+    /// Returns default CommandQueue instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty CommandQueue
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> CommandQueue<N> {
+    /// Creates new empty command queue.
+    ///
+    /// # Returns
+    /// * `Self` - New CommandQueue instance
+    #[allow(dead_code)]
+    pub const fn new() -> Self {
+        Self {
+            slots: [None; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Appends a command to the back of the queue.
+    ///
+    /// # Arguments
+    /// * `command` - Command to enqueue
+    ///
+    /// # Returns
+    /// * `Result<(), QueueError>` - Full if the queue is at capacity
+    #[allow(dead_code)]
+    pub fn push(&mut self, command: BlinkCommand) -> Result<(), QueueError> {
+        if self.len == N {
+            return Err(QueueError::Full);
+        }
+        self.slots[(self.head + self.len) % N] = Some(command);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the oldest command.
+    ///
+    /// # Returns
+    /// * `Option<BlinkCommand>` - Oldest command, or None if empty
+    #[allow(dead_code)]
+    pub fn pop(&mut self) -> Option<BlinkCommand> {
+        if self.len == 0 {
+            return None;
+        }
+        let command = self.slots[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        command
+    }
+
+    /// Returns number of queued commands.
+    ///
+    /// # Returns
+    /// * `usize` - Queue length
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the queue is empty.
+    ///
+    /// # Returns
+    /// * `bool` - true if no commands are queued
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Applies a command to the controller.
+///
+/// # Details
+/// Console-only commands such as Help and Stats leave the controller
+/// unchanged. Delays unsafe for the watchdog are rejected. While the
+/// configuration is locked, Delay, Identify and Pattern are rejected.
+///
+/// # Arguments
+/// * `controller` - Controller to update
+/// * `command` - Command to apply
+///
+/// # Returns
+/// * `Result<(), CommandError>` - Ok, a Lock error, or a Watchdog error
+#[allow(dead_code)]
+pub fn apply_command(
+    controller: &mut LedController,
+    command: BlinkCommand,
+) -> Result<(), CommandError> {
+    match command {
+        BlinkCommand::Delay(ms) => {
+            controller.check_unlocked().map_err(CommandError::Lock)?;
+            controller
+                .set_delay_checked(ms)
+                .map_err(CommandError::Watchdog)?;
+        }
+        BlinkCommand::Identify(secs) => {
+            controller.check_unlocked().map_err(CommandError::Lock)?;
+            controller.identify(secs);
+        }
+        BlinkCommand::Pattern(pattern) => {
+            controller.check_unlocked().map_err(CommandError::Lock)?;
+            controller.queue_pattern(pattern);
+        }
+        BlinkCommand::Lock => controller.lock(),
+        BlinkCommand::Unlock(code) => controller.unlock(code).map_err(CommandError::Lock)?,
+        BlinkCommand::Help | BlinkCommand::Stats => {}
+    }
+    Ok(())
+}
+
+/// Applies a command and persists a new pattern selection.
+///
+/// # Details
+/// When the command changes the selected pattern, it is written to
+/// the store over the stored configuration so it is restored at the
+/// next boot. An identify blink does not change the selection.
+///
+/// # Arguments
+/// * `controller` - Controller to update
+/// * `store` - Configuration store
+/// * `command` - Command to apply
+///
+/// # Returns
+/// * `Result<(), CommandError>` - As apply_command, or a Store error
+#[allow(dead_code)]
+pub fn apply_and_persist<S: ConfigStore>(
+    controller: &mut LedController,
+    store: &mut S,
+    command: BlinkCommand,
+) -> Result<(), CommandError> {
+    let selected = controller.selected_pattern();
+    apply_command(controller, command)?;
+    if controller.selected_pattern() == selected {
+        return Ok(());
+    }
+    let mut config = store.load().unwrap_or_default();
+    config.pattern = controller.selected_pattern();
+    store.save(&config).map_err(CommandError::Store)
+}
+
+/// Drains the shared command queue into the controller.
+///
+/// # Details
+/// Called by the blink task once per cycle. Commands rejected by
+/// the configuration lock or the watchdog check are dropped. A
+/// changed pattern selection is persisted to the store.
+///
+/// # Arguments
+/// * `controller` - Controller to update
+/// * `store` - Configuration store
+#[cfg(feature = "embassy-sync")]
+#[allow(dead_code)]
+pub fn drain_commands<S: ConfigStore>(controller: &mut LedController, store: &mut S) {
+    COMMAND_QUEUE.lock(|queue| {
+        while let Some(command) = queue.borrow_mut().pop() {
+            let _ = apply_and_persist(controller, store, command);
+        }
+    });
+}
+
+/// Command rate limiter.
+///
+/// # Details
+/// Permits at most one command per configured interval.
+/// Commands arriving inside the interval are dropped.
+///
+/// # Fields
+/// * `min_interval_ms` - Minimum spacing between accepted commands
+/// * `last_allowed_ms` - Timestamp of the last accepted command, if any
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct RateLimiter {
+    min_interval_ms: u64,
+    last_allowed_ms: Option<u64>,
+}
+
+impl Default for RateLimiter {
+    /// Returns default RateLimiter instance.
+    ///
+    /// # Details
+    /// Delegates to new() with the configured command interval.
+    ///
+    /// # Returns
+    /// * `Self` - New RateLimiter with default interval
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(COMMAND_MIN_INTERVAL_MS)
+    }
+}
+
+impl RateLimiter {
+    /// Creates new rate limiter with the given interval.
+    ///
+    /// # Details
+    /// The first command is always allowed.
+    ///
+    /// # Arguments
+    /// * `min_interval_ms` - Minimum spacing between accepted commands
+    ///
+    /// # Returns
+    /// * `Self` - New RateLimiter instance
+    #[allow(dead_code)]
+    pub fn new(min_interval_ms: u64) -> Self {
+        Self {
+            min_interval_ms,
+            last_allowed_ms: None,
+        }
+    }
+
+    /// Decides whether a command arriving now is accepted.
+    ///
+    /// # Details
+    /// Accepted commands restart the interval; dropped ones do not.
+    ///
+    /// # Arguments
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `bool` - true if the command should be processed
+    #[allow(dead_code)]
+    pub fn allow(&mut self, now_ms: u64) -> bool {
+        let allowed = match self.last_allowed_ms {
+            Some(last) => now_ms.saturating_sub(last) >= self.min_interval_ms,
+            None => true,
+        };
+        if allowed {
+            self.last_allowed_ms = Some(now_ms);
+        }
+        allowed
+    }
+}
+
+/// Checks whether a toggle count has reached a target.
+///
+/// # Details
+/// Shared predicate used by wait_for_count.
+///
+/// # Arguments
+/// * `count` - Current toggle count
+/// * `target` - Toggle count being waited for
+///
+/// # Returns
+/// * `bool` - true once count is at or above target
+#[allow(dead_code)]
+pub fn count_reached(count: u64, target: u64) -> bool {
+    count >= target
+}
+
+/// Waits until the blink task reports a toggle count at or above target.
+///
+/// # Details
+/// Resolves on the first published count satisfying count_reached.
+/// To wait for N more toggles pass the current count plus N.
+///
+/// # Arguments
+/// * `target` - Toggle count to wait for
+///
+/// # Returns
+/// * `u64` - Toggle count that satisfied the target
+#[cfg(feature = "embassy-sync")]
+#[allow(dead_code)]
+pub async fn wait_for_count(target: u64) -> u64 {
+    loop {
+        let count = TOGGLE_COUNT.wait().await;
+        if count_reached(count, target) {
+            return count;
+        }
+    }
+}
+
+/// Waits until the current one-shot sequence completes.
+///
+/// # Details
+/// Resolves on the next ONE_SHOT_DONE, or immediately if one finished
+/// since the last wait.
+#[cfg(feature = "embassy-sync")]
+#[allow(dead_code)]
+pub async fn wait_for_idle() {
+    ONE_SHOT_DONE.wait().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{BLINK_DELAY_MS, UNLOCK_CODE};
+    use crate::led::LedState;
+    use crate::pattern::Pattern;
+    use crate::store::{BlinkConfig, MemoryStore};
+
+    // ==================== RateLimiter Tests ====================
+
+    #[test]
+    fn test_first_command_allowed() {
+        let mut limiter = RateLimiter::new(100);
+        assert!(limiter.allow(0));
+    }
+
+    #[test]
+    fn test_commands_within_interval_dropped() {
+        let mut limiter = RateLimiter::new(100);
+        assert!(limiter.allow(1000));
+        assert!(!limiter.allow(1001));
+        assert!(!limiter.allow(1050));
+        assert!(!limiter.allow(1099));
+    }
+
+    #[test]
+    fn test_spaced_commands_pass() {
+        let mut limiter = RateLimiter::new(100);
+        assert!(limiter.allow(1000));
+        assert!(limiter.allow(1100));
+        assert!(limiter.allow(1250));
+    }
+
+    #[test]
+    fn test_dropped_command_does_not_extend_interval() {
+        let mut limiter = RateLimiter::new(100);
+        assert!(limiter.allow(1000));
+        assert!(!limiter.allow(1090));
+        assert!(limiter.allow(1100));
+    }
+
+    #[test]
+    fn test_default_uses_config_interval() {
+        assert_eq!(
+            RateLimiter::default(),
+            RateLimiter::new(COMMAND_MIN_INTERVAL_MS)
+        );
+    }
+
+    // ==================== CommandQueue Tests ====================
+
+    #[test]
+    fn test_queue_fill_to_capacity() {
+        let mut queue = CommandQueue::<3>::new();
+        assert!(queue.is_empty());
+        for ms in 1..=3 {
+            assert_eq!(queue.push(BlinkCommand::Delay(ms)), Ok(()));
+        }
+        assert_eq!(queue.len(), 3);
+    }
+
+    #[test]
+    fn test_queue_overflow_rejected() {
+        let mut queue = CommandQueue::<2>::new();
+        queue.push(BlinkCommand::Delay(1)).unwrap();
+        queue.push(BlinkCommand::Delay(2)).unwrap();
+        assert_eq!(queue.push(BlinkCommand::Delay(3)), Err(QueueError::Full));
+        assert_eq!(queue.pop(), Some(BlinkCommand::Delay(1)));
+        assert_eq!(queue.pop(), Some(BlinkCommand::Delay(2)));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_queue_fifo_order_with_wrap() {
+        let mut queue = CommandQueue::<3>::new();
+        queue.push(BlinkCommand::Delay(1)).unwrap();
+        queue.push(BlinkCommand::Delay(2)).unwrap();
+        assert_eq!(queue.pop(), Some(BlinkCommand::Delay(1)));
+        queue.push(BlinkCommand::Delay(3)).unwrap();
+        queue.push(BlinkCommand::Delay(4)).unwrap();
+        assert_eq!(queue.pop(), Some(BlinkCommand::Delay(2)));
+        assert_eq!(queue.pop(), Some(BlinkCommand::Delay(3)));
+        assert_eq!(queue.pop(), Some(BlinkCommand::Delay(4)));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_apply_delay_command() {
+        let mut ctrl = LedController::new();
+        assert_eq!(apply_command(&mut ctrl, BlinkCommand::Delay(250)), Ok(()));
+        assert_eq!(ctrl.delay_ms(), 250);
+    }
+
+    #[test]
+    fn test_apply_identify_command() {
+        let mut ctrl = LedController::new();
+        assert_eq!(apply_command(&mut ctrl, BlinkCommand::Identify(5)), Ok(()));
+        assert!(ctrl.is_identifying());
+    }
+
+    #[test]
+    fn test_apply_unsafe_delay_reports_watchdog() {
+        let mut ctrl = LedController::new();
+        ctrl.set_watchdog_timeout_ms(1000);
+        assert_eq!(
+            apply_command(&mut ctrl, BlinkCommand::Delay(600)),
+            Err(CommandError::Watchdog(WatchdogError::ExceedsWatchdog))
+        );
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS);
+        assert_eq!(apply_command(&mut ctrl, BlinkCommand::Delay(400)), Ok(()));
+        assert_eq!(ctrl.delay_ms(), 400);
+    }
+
+    // ==================== Persistence Tests ====================
+
+    #[test]
+    fn test_pattern_change_is_persisted() {
+        let mut ctrl = LedController::new();
+        let mut store = MemoryStore::new();
+        let command = BlinkCommand::Pattern(Pattern::Heartbeat);
+        assert_eq!(apply_and_persist(&mut ctrl, &mut store, command), Ok(()));
+        assert_eq!(ctrl.selected_pattern(), Pattern::Heartbeat);
+        assert_eq!(store.load().unwrap().pattern, Pattern::Heartbeat);
+    }
+
+    #[test]
+    fn test_pattern_command_waits_for_phase_end() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.tick().state, LedState::On);
+        let command = BlinkCommand::Pattern(Pattern::Sos);
+        assert_eq!(apply_command(&mut ctrl, command), Ok(()));
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+        let tick = ctrl.tick();
+        assert_eq!((tick.state, tick.delay_ms), Pattern::Sos.steps()[0]);
+    }
+
+    #[test]
+    fn test_persist_keeps_other_stored_settings() {
+        let mut ctrl = LedController::new();
+        let mut store = MemoryStore::new();
+        let stored = BlinkConfig {
+            delay_ms: 250,
+            ..BlinkConfig::default()
+        };
+        store.save(&stored).unwrap();
+        let command = BlinkCommand::Pattern(Pattern::Sos);
+        assert_eq!(apply_and_persist(&mut ctrl, &mut store, command), Ok(()));
+        let config = store.load().unwrap();
+        assert_eq!(config.delay_ms, 250);
+        assert_eq!(config.pattern, Pattern::Sos);
+    }
+
+    #[test]
+    fn test_unchanged_selection_is_not_written() {
+        let mut ctrl = LedController::new();
+        let mut store = MemoryStore::new();
+        let commands = [
+            BlinkCommand::Identify(5),
+            BlinkCommand::Delay(250),
+            BlinkCommand::Pattern(Pattern::Steady),
+        ];
+        for command in commands {
+            assert_eq!(apply_and_persist(&mut ctrl, &mut store, command), Ok(()));
+        }
+        assert_eq!(store, MemoryStore::new());
+    }
+
+    // ==================== Lock Tests ====================
+
+    #[test]
+    fn test_locked_rejects_delay() {
+        let mut ctrl = LedController::new();
+        assert_eq!(apply_command(&mut ctrl, BlinkCommand::Lock), Ok(()));
+        assert_eq!(
+            apply_command(&mut ctrl, BlinkCommand::Delay(250)),
+            Err(CommandError::Lock(LockError::Locked))
+        );
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS);
+        assert_eq!(
+            apply_command(&mut ctrl, BlinkCommand::Identify(5)),
+            Err(CommandError::Lock(LockError::Locked))
+        );
+        assert!(!ctrl.is_identifying());
+    }
+
+    #[test]
+    fn test_wrong_code_keeps_lock() {
+        let mut ctrl = LedController::new();
+        ctrl.lock();
+        assert_eq!(
+            apply_command(&mut ctrl, BlinkCommand::Unlock(UNLOCK_CODE + 1)),
+            Err(CommandError::Lock(LockError::BadCode))
+        );
+        assert!(ctrl.is_locked());
+    }
+
+    #[test]
+    fn test_unlock_restores_mutability() {
+        let mut ctrl = LedController::new();
+        ctrl.lock();
+        assert_eq!(
+            apply_command(&mut ctrl, BlinkCommand::Unlock(UNLOCK_CODE)),
+            Ok(())
+        );
+        assert!(!ctrl.is_locked());
+        assert_eq!(apply_command(&mut ctrl, BlinkCommand::Delay(250)), Ok(()));
+        assert_eq!(ctrl.delay_ms(), 250);
+    }
+
+    #[test]
+    fn test_locked_rejects_pattern() {
+        let mut ctrl = LedController::new();
+        ctrl.lock();
+        assert_eq!(
+            apply_command(&mut ctrl, BlinkCommand::Pattern(Pattern::Sos)),
+            Err(CommandError::Lock(LockError::Locked))
+        );
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+    }
+
+    #[test]
+    fn test_console_commands_allowed_while_locked() {
+        let mut ctrl = LedController::new();
+        ctrl.lock();
+        assert_eq!(apply_command(&mut ctrl, BlinkCommand::Stats), Ok(()));
+        assert_eq!(apply_command(&mut ctrl, BlinkCommand::Help), Ok(()));
+    }
+
+    // ==================== Count Predicate Tests ====================
+
+    #[test]
+    fn test_count_below_target() {
+        assert!(!count_reached(4, 5));
+        assert!(!count_reached(0, 1));
+    }
+
+    #[test]
+    fn test_count_at_target() {
+        assert!(count_reached(5, 5));
+        assert!(count_reached(0, 0));
+    }
+
+    #[test]
+    fn test_count_above_target() {
+        assert!(count_reached(6, 5));
+        assert!(count_reached(u64::MAX, 5));
+    }
+}
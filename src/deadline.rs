@@ -0,0 +1,189 @@
+/*
+ * @file deadline.rs
+ * @brief Missed deadline monitor
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: deadline.rs
+//!
+//! DESCRIPTION:
+//! Missed Blink Deadline Monitor.
+//!
+//! BRIEF:
+//! Records how late blink steps run, keeping the worst overshoot
+//! and the first missed deadline for debugging.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::DEADLINE_TOLERANCE_MS;
+
+/// Missed blink deadline monitor.
+///
+/// # Details
+/// Compares when each blink step was due with when it actually ran.
+/// Tracks the worst overshoot seen and captures the first sample
+/// late by more than the tolerance, so the earliest failure is kept
+/// even after later misses. Early samples count as on time.
+///
+/// # Fields
+/// * `tolerance_ms` - Lateness allowed before a sample counts as missed
+/// * `worst_ms` - Largest overshoot recorded
+/// * `misses` - Number of missed deadlines
+/// * `first_miss` - First missed `(expected_ms, actual_ms)` sample
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct DeadlineMonitor {
+    tolerance_ms: u64,
+    worst_ms: u64,
+    misses: u32,
+    first_miss: Option<(u64, u64)>,
+}
+
+impl Default for DeadlineMonitor {
+    /// Returns default DeadlineMonitor instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New DeadlineMonitor with the configured tolerance
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeadlineMonitor {
+    /// Creates new monitor with DEADLINE_TOLERANCE_MS.
+    ///
+    /// # Returns
+    /// * `Self` - New DeadlineMonitor instance
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::with_tolerance(DEADLINE_TOLERANCE_MS)
+    }
+
+    /// Creates new monitor with a custom tolerance.
+    ///
+    /// # Arguments
+    /// * `tolerance_ms` - Lateness allowed before a sample counts as missed
+    ///
+    /// # Returns
+    /// * `Self` - New DeadlineMonitor instance
+    #[allow(dead_code)]
+    pub fn with_tolerance(tolerance_ms: u64) -> Self {
+        Self {
+            tolerance_ms,
+            worst_ms: 0,
+            misses: 0,
+            first_miss: None,
+        }
+    }
+
+    /// Records one deadline sample.
+    ///
+    /// # Arguments
+    /// * `expected_ms` - Time the step was due
+    /// * `actual_ms` - Time the step ran
+    #[allow(dead_code)]
+    pub fn record(&mut self, expected_ms: u64, actual_ms: u64) {
+        let late_ms = actual_ms.saturating_sub(expected_ms);
+        self.worst_ms = self.worst_ms.max(late_ms);
+        if late_ms > self.tolerance_ms {
+            self.misses = self.misses.saturating_add(1);
+            self.first_miss.get_or_insert((expected_ms, actual_ms));
+        }
+    }
+
+    /// Returns the worst overshoot recorded.
+    ///
+    /// # Returns
+    /// * `u64` - Largest lateness in milliseconds, 0 if never late
+    #[allow(dead_code)]
+    pub fn worst_overshoot_ms(&self) -> u64 {
+        self.worst_ms
+    }
+
+    /// Returns the number of missed deadlines.
+    ///
+    /// # Returns
+    /// * `u32` - Samples late by more than the tolerance
+    #[allow(dead_code)]
+    pub fn misses(&self) -> u32 {
+        self.misses
+    }
+
+    /// Returns the first missed deadline.
+    ///
+    /// # Returns
+    /// * `Option<(u64, u64)>` - `(expected_ms, actual_ms)` of the first miss
+    #[allow(dead_code)]
+    pub fn first_miss(&self) -> Option<(u64, u64)> {
+        self.first_miss
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Deadline Monitor Tests ====================
+
+    #[test]
+    fn test_on_time_samples_record_nothing() {
+        let mut monitor = DeadlineMonitor::with_tolerance(0);
+        monitor.record(500, 500);
+        monitor.record(1000, 990);
+        assert_eq!(monitor.worst_overshoot_ms(), 0);
+        assert_eq!(monitor.misses(), 0);
+        assert_eq!(monitor.first_miss(), None);
+    }
+
+    #[test]
+    fn test_worst_overshoot_is_captured() {
+        let mut monitor = DeadlineMonitor::with_tolerance(0);
+        monitor.record(500, 500);
+        monitor.record(1000, 1012);
+        monitor.record(1500, 1500);
+        monitor.record(2000, 2040);
+        monitor.record(2500, 2505);
+        assert_eq!(monitor.worst_overshoot_ms(), 40);
+        assert_eq!(monitor.misses(), 3);
+        assert_eq!(monitor.first_miss(), Some((1000, 1012)));
+    }
+
+    #[test]
+    fn test_tolerance_hides_small_lateness() {
+        let mut monitor = DeadlineMonitor::with_tolerance(5);
+        monitor.record(500, 505);
+        assert_eq!(monitor.worst_overshoot_ms(), 5);
+        assert_eq!(monitor.first_miss(), None);
+        monitor.record(1000, 1006);
+        assert_eq!(monitor.first_miss(), Some((1000, 1006)));
+    }
+}
@@ -0,0 +1,172 @@
+/*
+ * @file debounce.rs
+ * @brief Shared input debounce filter
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: debounce.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Shared Input Debounce Filter.
+//!
+//! BRIEF:
+//! Time-based debounce for buttons, edges and encoder inputs.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::DEBOUNCE_MS;
+
+/// Shared input debounce filter.
+///
+/// # Details
+/// Reports a level change only after the raw input has held the new
+/// level for the stable time. Used by every digital input handler.
+/// The input is assumed low at start.
+///
+/// # Fields
+/// * `stable_ms` - Time a level must hold before it is reported
+/// * `level` - Last reported level
+/// * `candidate` - Most recent raw level
+/// * `since_ms` - Time the candidate level was first seen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Debouncer {
+    stable_ms: u64,
+    level: bool,
+    candidate: bool,
+    since_ms: u64,
+}
+
+impl Default for Debouncer {
+    /// Returns default Debouncer instance.
+    ///
+    /// # Details
+    /// Delegates to new() with the configured stable time.
+    ///
+    /// # Returns
+    /// * `Self` - New Debouncer with default stable time
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(DEBOUNCE_MS)
+    }
+}
+
+impl Debouncer {
+    /// Creates new debouncer with the given stable time.
+    ///
+    /// # Arguments
+    /// * `stable_ms` - Time a level must hold before it is reported
+    ///
+    /// # Returns
+    /// * `Self` - New Debouncer instance
+    #[allow(dead_code)]
+    pub fn new(stable_ms: u64) -> Self {
+        Self {
+            stable_ms,
+            level: false,
+            candidate: false,
+            since_ms: 0,
+        }
+    }
+
+    /// Feeds a raw input sample.
+    ///
+    /// # Arguments
+    /// * `raw` - Raw input level
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `Option<bool>` - New debounced level when it changes stably
+    #[allow(dead_code)]
+    pub fn update(&mut self, raw: bool, now_ms: u64) -> Option<bool> {
+        if raw != self.candidate {
+            self.candidate = raw;
+            self.since_ms = now_ms;
+        }
+        let stable = now_ms.saturating_sub(self.since_ms) >= self.stable_ms;
+        if stable && self.candidate != self.level {
+            self.level = self.candidate;
+            return Some(self.level);
+        }
+        None
+    }
+
+    /// Returns the debounced level.
+    ///
+    /// # Returns
+    /// * `bool` - Last reported level
+    #[allow(dead_code)]
+    pub fn level(&self) -> bool {
+        self.level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Debouncer Tests ====================
+
+    #[test]
+    fn test_default_uses_config() {
+        assert_eq!(Debouncer::default(), Debouncer::new(DEBOUNCE_MS));
+    }
+
+    #[test]
+    fn test_bouncy_press_reported_once() {
+        let mut debouncer = Debouncer::new(20);
+        let samples = [(true, 0), (false, 3), (true, 5), (false, 8), (true, 10)];
+        for (raw, now) in samples {
+            assert_eq!(debouncer.update(raw, now), None);
+        }
+        assert_eq!(debouncer.update(true, 29), None);
+        assert_eq!(debouncer.update(true, 30), Some(true));
+        assert_eq!(debouncer.update(true, 50), None);
+        assert!(debouncer.level());
+    }
+
+    #[test]
+    fn test_glitch_not_reported() {
+        let mut debouncer = Debouncer::new(20);
+        assert_eq!(debouncer.update(true, 0), None);
+        assert_eq!(debouncer.update(false, 5), None);
+        assert_eq!(debouncer.update(false, 100), None);
+        assert!(!debouncer.level());
+    }
+
+    #[test]
+    fn test_release_reported_after_stable() {
+        let mut debouncer = Debouncer::new(10);
+        debouncer.update(true, 0);
+        assert_eq!(debouncer.update(true, 10), Some(true));
+        assert_eq!(debouncer.update(false, 15), None);
+        assert_eq!(debouncer.update(true, 17), None);
+        assert_eq!(debouncer.update(false, 18), None);
+        assert_eq!(debouncer.update(false, 28), Some(false));
+    }
+}
@@ -0,0 +1,285 @@
+/*
+ * @file demo.rs
+ * @brief Random pattern demo mode
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: demo.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Random Pattern Demo Mode.
+//!
+//! BRIEF:
+//! Cycles through randomly picked built-in patterns using a seeded
+//! LFSR so demo sequences are reproducible.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::DEMO_INTERVAL_MS;
+use crate::pattern::Pattern;
+
+/// Patterns demo mode chooses between.
+const DEMO_PATTERNS: [Pattern; 3] = [Pattern::Steady, Pattern::Heartbeat, Pattern::Sos];
+
+/// Length of the maximal LFSR sequence.
+const LFSR_PERIOD: u64 = 65535;
+
+/// 16-bit Galois linear feedback shift register.
+///
+/// # Details
+/// Taps 16, 14, 13 and 11 give the maximal 65535-state sequence.
+/// Cheap, deterministic pseudo-randomness; not for cryptography.
+///
+/// # Fields
+/// * `state` - Current register value, never zero
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Lfsr {
+    state: u16,
+}
+
+impl Lfsr {
+    /// Creates new LFSR from a seed.
+    ///
+    /// # Details
+    /// A zero seed would lock the register, so it is replaced by 1.
+    ///
+    /// # Arguments
+    /// * `seed` - Initial register value
+    ///
+    /// # Returns
+    /// * `Self` - New Lfsr instance
+    #[allow(dead_code)]
+    pub fn new(seed: u16) -> Self {
+        Self {
+            state: if seed == 0 { 1 } else { seed },
+        }
+    }
+
+    /// Steps the register and returns the new value.
+    ///
+    /// # Returns
+    /// * `u16` - Next pseudo-random value
+    #[allow(dead_code)]
+    pub fn next_u16(&mut self) -> u16 {
+        let lsb = self.state & 1;
+        self.state >>= 1;
+        if lsb != 0 {
+            self.state ^= 0xB400;
+        }
+        self.state
+    }
+}
+
+/// Picks a random built-in pattern.
+///
+/// # Arguments
+/// * `rng` - Random source
+///
+/// # Returns
+/// * `Pattern` - One of the demo patterns
+#[allow(dead_code)]
+pub fn pick_pattern(rng: &mut Lfsr) -> Pattern {
+    DEMO_PATTERNS[usize::from(rng.next_u16()) % DEMO_PATTERNS.len()]
+}
+
+/// Demo mode cycling through random patterns.
+///
+/// # Details
+/// Picks a new pattern each time the interval elapses. The same seed
+/// always gives the same sequence.
+///
+/// # Fields
+/// * `rng` - Random source
+/// * `interval_ms` - Time per pattern in milliseconds
+/// * `elapsed_ms` - Time spent on the current pattern
+/// * `current` - Pattern playing now
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct DemoMode {
+    rng: Lfsr,
+    interval_ms: u64,
+    elapsed_ms: u64,
+    current: Pattern,
+}
+
+impl DemoMode {
+    /// Creates new demo mode with the configured interval.
+    ///
+    /// # Arguments
+    /// * `seed` - Random seed
+    ///
+    /// # Returns
+    /// * `Self` - New DemoMode with its first pattern picked
+    #[allow(dead_code)]
+    pub fn new(seed: u16) -> Self {
+        Self::with_interval(seed, DEMO_INTERVAL_MS)
+    }
+
+    /// Creates new demo mode with an explicit interval.
+    ///
+    /// # Arguments
+    /// * `seed` - Random seed
+    /// * `interval_ms` - Time per pattern in milliseconds
+    ///
+    /// # Returns
+    /// * `Self` - New DemoMode with its first pattern picked
+    #[allow(dead_code)]
+    pub fn with_interval(seed: u16, interval_ms: u64) -> Self {
+        let mut rng = Lfsr::new(seed);
+        let current = pick_pattern(&mut rng);
+        Self {
+            rng,
+            interval_ms,
+            elapsed_ms: 0,
+            current,
+        }
+    }
+
+    /// Returns the pattern playing now.
+    ///
+    /// # Returns
+    /// * `Pattern` - Current demo pattern
+    #[allow(dead_code)]
+    pub fn current(&self) -> Pattern {
+        self.current
+    }
+
+    /// Advances demo time.
+    ///
+    /// # Details
+    /// Picks one new pattern for each full interval elapsed; the
+    /// remainder carries into the next interval. Whole LFSR periods
+    /// are skipped, so a large step lands on the same pattern as
+    /// stepping one interval at a time. A zero interval never switches.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance
+    ///
+    /// # Returns
+    /// * `Option<Pattern>` - New pattern if a switch happened
+    #[allow(dead_code)]
+    pub fn advance(&mut self, elapsed_ms: u64) -> Option<Pattern> {
+        if self.interval_ms == 0 {
+            return None;
+        }
+        let total_ms = u128::from(self.elapsed_ms) + u128::from(elapsed_ms);
+        let picks = total_ms / u128::from(self.interval_ms);
+        self.elapsed_ms = (total_ms % u128::from(self.interval_ms)) as u64;
+        if picks == 0 {
+            return None;
+        }
+        for _ in 0..picks % u128::from(LFSR_PERIOD) {
+            self.current = pick_pattern(&mut self.rng);
+        }
+        Some(self.current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn choices(seed: u16) -> [Pattern; 16] {
+        let mut demo = DemoMode::with_interval(seed, 100);
+        let mut out = [demo.current(); 16];
+        for slot in out.iter_mut().skip(1) {
+            *slot = demo.advance(100).unwrap();
+        }
+        out
+    }
+
+    // ==================== LFSR Tests ====================
+
+    #[test]
+    fn test_lfsr_known_sequence() {
+        let mut rng = Lfsr::new(0xACE1);
+        assert_eq!(rng.next_u16(), 0xE270);
+        assert_eq!(rng.next_u16(), 0x7138);
+    }
+
+    #[test]
+    fn test_lfsr_zero_seed_does_not_lock() {
+        let mut rng = Lfsr::new(0);
+        assert_ne!(rng.next_u16(), 0);
+    }
+
+    // ==================== Demo Mode Tests ====================
+
+    #[test]
+    fn test_fixed_seed_is_reproducible() {
+        assert_eq!(choices(0xACE1), choices(0xACE1));
+    }
+
+    #[test]
+    fn test_sequence_uses_several_patterns() {
+        let picks = choices(0xACE1);
+        for pattern in DEMO_PATTERNS {
+            assert!(picks.contains(&pattern));
+        }
+    }
+
+    #[test]
+    fn test_switches_only_on_interval() {
+        let mut demo = DemoMode::with_interval(7, 100);
+        assert_eq!(demo.advance(99), None);
+        assert!(demo.advance(1).is_some());
+        assert_eq!(demo.advance(50), None);
+    }
+
+    #[test]
+    fn test_large_step_matches_single_steps() {
+        let mut stepped = DemoMode::with_interval(0xACE1, 100);
+        for _ in 0..3 {
+            stepped.advance(100);
+        }
+        stepped.advance(40);
+        let mut jumped = DemoMode::with_interval(0xACE1, 100);
+        assert_eq!(
+            jumped.advance(100 * (LFSR_PERIOD + 3) + 40),
+            Some(stepped.current())
+        );
+        assert_eq!(jumped, stepped);
+    }
+
+    #[test]
+    fn test_full_period_step_still_switches() {
+        let mut demo = DemoMode::with_interval(0xACE1, 1);
+        let first = demo.current();
+        assert_eq!(demo.advance(LFSR_PERIOD), Some(first));
+        assert!(demo.advance(u64::MAX).is_some());
+    }
+
+    #[test]
+    fn test_zero_interval_never_switches() {
+        let mut demo = DemoMode::with_interval(7, 0);
+        let first = demo.current();
+        assert_eq!(demo.advance(u64::MAX), None);
+        assert_eq!(demo.current(), first);
+    }
+}
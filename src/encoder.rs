@@ -0,0 +1,149 @@
+/*
+ * @file encoder.rs
+ * @brief Rotary encoder delay mapping
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: encoder.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Rotary Encoder Delay Mapping.
+//!
+//! BRIEF:
+//! Converts signed encoder detent counts into blink delay changes.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::ENCODER_STEP_MS;
+use crate::led::LedController;
+
+/// Rotary encoder to blink delay mapping.
+///
+/// # Details
+/// Converts signed detent counts into delay adjustments.
+/// Independent of the encoder peripheral so it can be tested on host.
+///
+/// # Fields
+/// * `step_ms` - Delay change per detent in milliseconds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct EncoderMap {
+    step_ms: u64,
+}
+
+impl Default for EncoderMap {
+    /// Returns default EncoderMap instance.
+    ///
+    /// # Details
+    /// Delegates to new() with the configured detent step.
+    ///
+    /// # Returns
+    /// * `Self` - New EncoderMap with default step
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(ENCODER_STEP_MS)
+    }
+}
+
+impl EncoderMap {
+    /// Creates new encoder map with the given detent step.
+    ///
+    /// # Arguments
+    /// * `step_ms` - Delay change per detent in milliseconds
+    ///
+    /// # Returns
+    /// * `Self` - New EncoderMap instance
+    #[allow(dead_code)]
+    pub fn new(step_ms: u64) -> Self {
+        Self { step_ms }
+    }
+
+    /// Applies an encoder delta to the controller delay.
+    ///
+    /// # Details
+    /// Positive deltas lengthen the delay and negative deltas shorten
+    /// it. The result is clamped to the controller bounds.
+    ///
+    /// # Arguments
+    /// * `ctrl` - Controller to adjust
+    /// * `delta` - Signed detent count since the last call
+    #[allow(dead_code)]
+    pub fn apply_delta(&self, ctrl: &mut LedController, delta: i32) {
+        let change = self.step_ms.saturating_mul(u64::from(delta.unsigned_abs()));
+        let delay_ms = if delta >= 0 {
+            ctrl.delay_ms().saturating_add(change)
+        } else {
+            ctrl.delay_ms().saturating_sub(change)
+        };
+        ctrl.set_delay(delay_ms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{BLINK_DELAY_MS, MAX_BLINK_DELAY_MS, MIN_BLINK_DELAY_MS};
+
+    // ==================== EncoderMap Tests ====================
+
+    #[test]
+    fn test_default_uses_config_step() {
+        assert_eq!(EncoderMap::default(), EncoderMap::new(ENCODER_STEP_MS));
+    }
+
+    #[test]
+    fn test_positive_delta_lengthens() {
+        let mut ctrl = LedController::new();
+        EncoderMap::new(10).apply_delta(&mut ctrl, 3);
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS + 30);
+    }
+
+    #[test]
+    fn test_negative_delta_shortens() {
+        let mut ctrl = LedController::new();
+        EncoderMap::new(10).apply_delta(&mut ctrl, -5);
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS - 50);
+    }
+
+    #[test]
+    fn test_zero_delta_unchanged() {
+        let mut ctrl = LedController::new();
+        EncoderMap::new(10).apply_delta(&mut ctrl, 0);
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_clamps_at_bounds() {
+        let mut ctrl = LedController::new();
+        let map = EncoderMap::new(10);
+        map.apply_delta(&mut ctrl, i32::MIN);
+        assert_eq!(ctrl.delay_ms(), MIN_BLINK_DELAY_MS);
+        map.apply_delta(&mut ctrl, i32::MAX);
+        assert_eq!(ctrl.delay_ms(), MAX_BLINK_DELAY_MS);
+    }
+}
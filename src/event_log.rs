@@ -0,0 +1,190 @@
+/*
+ * @file event_log.rs
+ * @brief Blink event logging
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: event_log.rs
+//!
+//! DESCRIPTION:
+//! Blink Transition Event Logging.
+//!
+//! BRIEF:
+//! Filters blink transitions into timestamped events and queues them
+//! on a bounded channel for a flash-logging task.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+#[cfg(feature = "embassy-sync")]
+use crate::config::EVENT_CHANNEL_CAPACITY;
+use crate::led::LedState;
+#[cfg(feature = "embassy-sync")]
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+#[cfg(feature = "embassy-sync")]
+use embassy_sync::channel::Channel;
+
+/// Logged blink transition.
+///
+/// # Fields
+/// * `state` - LED state entered
+/// * `timestamp_ms` - Time of the transition in milliseconds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct BlinkEvent {
+    pub state: LedState,
+    pub timestamp_ms: u64,
+}
+
+/// Blink events awaiting the flash-logging task.
+///
+/// # Details
+/// Bounded; events sent while full are dropped rather than stalling
+/// the blink task.
+#[cfg(feature = "embassy-sync")]
+#[allow(dead_code)]
+pub static BLINK_EVENTS: Channel<CriticalSectionRawMutex, BlinkEvent, EVENT_CHANNEL_CAPACITY> =
+    Channel::new();
+
+/// Returns whether a state should be logged.
+///
+/// # Details
+/// Only real transitions are logged: the first state seen, then
+/// any state differing from the last one logged.
+///
+/// # Arguments
+/// * `last` - Last logged state, if any
+/// * `state` - State just applied
+///
+/// # Returns
+/// * `bool` - true to emit an event
+#[allow(dead_code)]
+pub fn should_emit(last: Option<LedState>, state: LedState) -> bool {
+    last != Some(state)
+}
+
+/// Blink event emitter.
+///
+/// # Details
+/// Filters applied states through should_emit and builds events for
+/// the ones that are real transitions.
+///
+/// # Fields
+/// * `last` - Last logged state, if any
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct EventEmitter {
+    last: Option<LedState>,
+}
+
+impl EventEmitter {
+    /// Creates new emitter with nothing logged.
+    ///
+    /// # Returns
+    /// * `Self` - New EventEmitter instance
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self { last: None }
+    }
+
+    /// Returns the event for an applied state, if it should be logged.
+    ///
+    /// # Arguments
+    /// * `state` - State just applied
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `Option<BlinkEvent>` - Event to log, or None for a no-op
+    #[allow(dead_code)]
+    pub fn event_for(&mut self, state: LedState, now_ms: u64) -> Option<BlinkEvent> {
+        if !should_emit(self.last, state) {
+            return None;
+        }
+        self.last = Some(state);
+        Some(BlinkEvent {
+            state,
+            timestamp_ms: now_ms,
+        })
+    }
+
+    /// Sends the event for an applied state to BLINK_EVENTS.
+    ///
+    /// # Arguments
+    /// * `state` - State just applied
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `bool` - true if an event was queued
+    #[cfg(feature = "embassy-sync")]
+    #[allow(dead_code)]
+    pub fn emit(&mut self, state: LedState, now_ms: u64) -> bool {
+        self.event_for(state, now_ms)
+            .is_some_and(|event| BLINK_EVENTS.try_send(event).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Filter Tests ====================
+
+    #[test]
+    fn test_should_emit_on_change() {
+        assert!(should_emit(None, LedState::Off));
+        assert!(should_emit(Some(LedState::Off), LedState::On));
+        assert!(should_emit(Some(LedState::On), LedState::Off));
+    }
+
+    #[test]
+    fn test_should_skip_no_op() {
+        assert!(!should_emit(Some(LedState::On), LedState::On));
+        assert!(!should_emit(Some(LedState::Off), LedState::Off));
+    }
+
+    // ==================== Emitter Tests ====================
+
+    #[test]
+    fn test_emitter_logs_only_transitions() {
+        let mut emitter = EventEmitter::new();
+        assert_eq!(
+            emitter.event_for(LedState::On, 10),
+            Some(BlinkEvent {
+                state: LedState::On,
+                timestamp_ms: 10,
+            })
+        );
+        assert_eq!(emitter.event_for(LedState::On, 20), None);
+        assert_eq!(
+            emitter.event_for(LedState::Off, 30),
+            Some(BlinkEvent {
+                state: LedState::Off,
+                timestamp_ms: 30,
+            })
+        );
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * @file frame.rs
+ * @brief CRC-protected command framing
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: frame.rs
+//!
+//! DESCRIPTION:
+//! RP2350 CRC-Protected Command Framing.
+//!
+//! BRIEF:
+//! Validates start byte, length and CRC8 of UART command frames
+//! before the payload reaches the command parser.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::FRAME_START;
+
+/// Frame decode error enumeration.
+///
+/// # Variants
+/// * `TooShort` - Fewer bytes than the start, length and CRC need
+/// * `BadStart` - First byte is not FRAME_START
+/// * `LengthMismatch` - Length byte does not match the payload size
+/// * `BadCrc` - CRC8 over length and payload does not match
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum FrameError {
+    TooShort,
+    BadStart,
+    LengthMismatch,
+    BadCrc,
+}
+
+/// Computes CRC-8 with polynomial 0x07.
+///
+/// # Details
+/// MSB-first, zero initial value and no final XOR (CRC-8/SMBUS).
+///
+/// # Arguments
+/// * `data` - Bytes to checksum
+///
+/// # Returns
+/// * `u8` - CRC8 value
+#[allow(dead_code)]
+pub fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Validates a command frame and returns its payload.
+///
+/// # Details
+/// Frame layout is `[FRAME_START, len, payload[len], crc8]` where the
+/// CRC covers the length byte and payload. The payload is only handed
+/// to the command parser once framing and CRC check out.
+///
+/// # Arguments
+/// * `bytes` - Received frame bytes
+///
+/// # Returns
+/// * `Result<&[u8], FrameError>` - Payload or the framing error
+#[allow(dead_code)]
+pub fn decode_frame(bytes: &[u8]) -> Result<&[u8], FrameError> {
+    if bytes.len() < 3 {
+        return Err(FrameError::TooShort);
+    }
+    if bytes[0] != FRAME_START {
+        return Err(FrameError::BadStart);
+    }
+    let len = usize::from(bytes[1]);
+    if bytes.len() != len + 3 {
+        return Err(FrameError::LengthMismatch);
+    }
+    let (body, crc) = bytes[1..].split_at(len + 1);
+    if crc8(body) != crc[0] {
+        return Err(FrameError::BadCrc);
+    }
+    Ok(&body[1..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(payload: &[u8], out: &mut [u8]) -> usize {
+        out[0] = FRAME_START;
+        out[1] = payload.len() as u8;
+        out[2..2 + payload.len()].copy_from_slice(payload);
+        out[2 + payload.len()] = crc8(&out[1..2 + payload.len()]);
+        payload.len() + 3
+    }
+
+    // ==================== CRC8 Tests ====================
+
+    #[test]
+    fn test_crc8_check_value() {
+        assert_eq!(crc8(b"123456789"), 0xF4);
+    }
+
+    #[test]
+    fn test_crc8_empty_is_zero() {
+        assert_eq!(crc8(&[]), 0);
+    }
+
+    // ==================== Frame Decode Tests ====================
+
+    #[test]
+    fn test_valid_frame_returns_payload() {
+        let mut buf = [0u8; 16];
+        let n = frame(b"delay 250", &mut buf);
+        assert_eq!(decode_frame(&buf[..n]), Ok(&b"delay 250"[..]));
+    }
+
+    #[test]
+    fn test_empty_payload_frame() {
+        let mut buf = [0u8; 4];
+        let n = frame(b"", &mut buf);
+        assert_eq!(decode_frame(&buf[..n]), Ok(&b""[..]));
+    }
+
+    #[test]
+    fn test_bad_crc_rejected() {
+        let mut buf = [0u8; 16];
+        let n = frame(b"stats", &mut buf);
+        buf[n - 1] ^= 0x01;
+        assert_eq!(decode_frame(&buf[..n]), Err(FrameError::BadCrc));
+    }
+
+    #[test]
+    fn test_corrupted_payload_rejected() {
+        let mut buf = [0u8; 16];
+        let n = frame(b"stats", &mut buf);
+        buf[3] ^= 0x20;
+        assert_eq!(decode_frame(&buf[..n]), Err(FrameError::BadCrc));
+    }
+
+    #[test]
+    fn test_length_mismatch_rejected() {
+        let mut buf = [0u8; 16];
+        let n = frame(b"stats", &mut buf);
+        assert_eq!(decode_frame(&buf[..n - 1]), Err(FrameError::LengthMismatch));
+        buf[1] = 9;
+        assert_eq!(decode_frame(&buf[..n]), Err(FrameError::LengthMismatch));
+    }
+
+    #[test]
+    fn test_bad_start_rejected() {
+        let mut buf = [0u8; 16];
+        let n = frame(b"help", &mut buf);
+        buf[0] = 0x00;
+        assert_eq!(decode_frame(&buf[..n]), Err(FrameError::BadStart));
+    }
+
+    #[test]
+    fn test_too_short_rejected() {
+        assert_eq!(decode_frame(&[FRAME_START, 0]), Err(FrameError::TooShort));
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ * @file group_flash.rs
+ * @brief Group sync flash overlay
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: group_flash.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Group Sync Flash Overlay.
+//!
+//! BRIEF:
+//! Briefly overrides every LED to On as an attention grabber, then
+//! restores the individual patterns.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::GROUP_FLASH_MS;
+use crate::led::LedState;
+
+/// Group sync flash overlay.
+///
+/// # Details
+/// When triggered, overrides every LED to On for a short flash.
+/// Individual patterns keep running underneath and show again once
+/// the flash ends.
+///
+/// # Fields
+/// * `duration_ms` - Flash duration in milliseconds
+/// * `remaining_ms` - Flash time left, 0 when inactive
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct GroupFlash {
+    duration_ms: u64,
+    remaining_ms: u64,
+}
+
+impl Default for GroupFlash {
+    /// Returns default GroupFlash instance.
+    ///
+    /// # Details
+    /// Delegates to new() with the configured flash duration.
+    ///
+    /// # Returns
+    /// * `Self` - New inactive GroupFlash
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(GROUP_FLASH_MS)
+    }
+}
+
+impl GroupFlash {
+    /// Creates new inactive group flash.
+    ///
+    /// # Arguments
+    /// * `duration_ms` - Flash duration in milliseconds
+    ///
+    /// # Returns
+    /// * `Self` - New GroupFlash instance
+    #[allow(dead_code)]
+    pub fn new(duration_ms: u64) -> Self {
+        Self {
+            duration_ms,
+            remaining_ms: 0,
+        }
+    }
+
+    /// Starts a sync flash, restarting one already in progress.
+    #[allow(dead_code)]
+    pub fn trigger(&mut self) {
+        self.remaining_ms = self.duration_ms;
+    }
+
+    /// Advances flash time.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance
+    #[allow(dead_code)]
+    pub fn advance(&mut self, elapsed_ms: u64) {
+        self.remaining_ms = self.remaining_ms.saturating_sub(elapsed_ms);
+    }
+
+    /// Returns whether the flash is overriding the LEDs.
+    ///
+    /// # Returns
+    /// * `bool` - true during the flash
+    #[allow(dead_code)]
+    pub fn is_active(&self) -> bool {
+        self.remaining_ms > 0
+    }
+
+    /// Applies the overlay to individual LED states.
+    ///
+    /// # Arguments
+    /// * `states` - Per-LED states from their own patterns
+    ///
+    /// # Returns
+    /// * `[LedState; N]` - All On during the flash, else unchanged
+    #[allow(dead_code)]
+    pub fn apply<const N: usize>(&self, states: [LedState; N]) -> [LedState; N] {
+        if self.is_active() {
+            [LedState::On; N]
+        } else {
+            states
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATES: [LedState; 3] = [LedState::Off, LedState::On, LedState::Off];
+
+    // ==================== GroupFlash Tests ====================
+
+    #[test]
+    fn test_inactive_passes_states_through() {
+        let flash = GroupFlash::default();
+        assert!(!flash.is_active());
+        assert_eq!(flash.apply(STATES), STATES);
+    }
+
+    #[test]
+    fn test_flash_overrides_all_on() {
+        let mut flash = GroupFlash::new(100);
+        flash.trigger();
+        assert_eq!(flash.apply(STATES), [LedState::On; 3]);
+        flash.advance(99);
+        assert_eq!(flash.apply(STATES), [LedState::On; 3]);
+    }
+
+    #[test]
+    fn test_individual_states_resume_after_flash() {
+        let mut flash = GroupFlash::new(100);
+        flash.trigger();
+        flash.advance(100);
+        assert!(!flash.is_active());
+        assert_eq!(flash.apply(STATES), STATES);
+    }
+
+    #[test]
+    fn test_retrigger_restarts_flash() {
+        let mut flash = GroupFlash::new(100);
+        flash.trigger();
+        flash.advance(80);
+        flash.trigger();
+        flash.advance(80);
+        assert!(flash.is_active());
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * @file guard.rs
+ * @brief LED off-on-drop guard
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: guard.rs
+//!
+//! DESCRIPTION:
+//! RP2350 LED Off-On-Drop Guard.
+//!
+//! BRIEF:
+//! Wraps the LED pin so it is guaranteed off when dropped.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::LedState;
+use crate::pin::LedPin;
+use crate::polarity::{next_level, Polarity};
+
+/// LED pin guard that turns the LED off on drop.
+///
+/// # Details
+/// Owns the LED pin and drives it to the OFF level when dropped,
+/// so the LED is never left lit when the task or controller goes away.
+/// The OFF level respects the configured polarity.
+///
+/// # Fields
+/// * `pin` - Owned LED pin
+/// * `polarity` - LED polarity used to compute levels
+/// * `invert` - true to swap on and off while blinking
+#[allow(dead_code)]
+pub struct BlinkGuard<P: LedPin> {
+    pin: P,
+    polarity: Polarity,
+    invert: bool,
+}
+
+impl<P: LedPin> BlinkGuard<P> {
+    /// Creates new guard around an LED pin.
+    ///
+    /// # Details
+    /// Does not change the pin level until set_state is called.
+    ///
+    /// # Arguments
+    /// * `pin` - LED pin to own
+    /// * `polarity` - LED polarity
+    ///
+    /// # Returns
+    /// * `Self` - New BlinkGuard instance
+    #[allow(dead_code)]
+    pub fn new(pin: P, polarity: Polarity) -> Self {
+        Self {
+            pin,
+            polarity,
+            invert: false,
+        }
+    }
+
+    /// Sets live blink inversion.
+    ///
+    /// # Details
+    /// Takes effect on the next set_state call.
+    ///
+    /// # Arguments
+    /// * `invert` - true to swap on and off
+    #[allow(dead_code)]
+    pub fn set_invert(&mut self, invert: bool) {
+        self.invert = invert;
+    }
+
+    /// Drives the LED to the given state.
+    ///
+    /// # Details
+    /// The level is inverted while the invert flag is set.
+    ///
+    /// # Arguments
+    /// * `state` - LED state to apply
+    #[allow(dead_code)]
+    pub fn set_state(&mut self, state: LedState) {
+        self.pin
+            .set_level(next_level(self.polarity, state, self.invert));
+    }
+}
+
+impl<P: LedPin> Drop for BlinkGuard<P> {
+    /// Drives the LED off on drop.
+    ///
+    /// # Details
+    /// Uses the polarity-aware OFF level and ignores inversion so the
+    /// LED really goes dark.
+    fn drop(&mut self) {
+        self.pin.set_level(self.polarity.level_for(LedState::Off));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Mock pin recording every level written.
+    struct MockPin {
+        levels: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl LedPin for MockPin {
+        fn set_level(&mut self, high: bool) {
+            self.levels.borrow_mut().push(high);
+        }
+    }
+
+    fn mock() -> (MockPin, Rc<RefCell<Vec<bool>>>) {
+        let levels = Rc::new(RefCell::new(Vec::new()));
+        (
+            MockPin {
+                levels: levels.clone(),
+            },
+            levels,
+        )
+    }
+
+    // ==================== BlinkGuard Tests ====================
+
+    #[test]
+    fn test_set_state_active_high() {
+        let (pin, levels) = mock();
+        let mut guard = BlinkGuard::new(pin, Polarity::ActiveHigh);
+        guard.set_state(LedState::On);
+        guard.set_state(LedState::Off);
+        assert_eq!(*levels.borrow(), vec![true, false]);
+    }
+
+    #[test]
+    fn test_drop_drives_off_active_high() {
+        let (pin, levels) = mock();
+        {
+            let mut guard = BlinkGuard::new(pin, Polarity::ActiveHigh);
+            guard.set_state(LedState::On);
+        }
+        assert_eq!(levels.borrow().last(), Some(&false));
+    }
+
+    #[test]
+    fn test_drop_drives_off_active_low() {
+        let (pin, levels) = mock();
+        {
+            let mut guard = BlinkGuard::new(pin, Polarity::ActiveLow);
+            guard.set_state(LedState::On);
+        }
+        assert_eq!(*levels.borrow(), vec![false, true]);
+    }
+
+    #[test]
+    fn test_invert_swaps_levels() {
+        let (pin, levels) = mock();
+        let mut guard = BlinkGuard::new(pin, Polarity::ActiveHigh);
+        guard.set_invert(true);
+        guard.set_state(LedState::On);
+        guard.set_state(LedState::Off);
+        assert_eq!(*levels.borrow(), vec![false, true]);
+    }
+
+    #[test]
+    fn test_drop_ignores_invert() {
+        let (pin, levels) = mock();
+        {
+            let mut guard = BlinkGuard::new(pin, Polarity::ActiveHigh);
+            guard.set_invert(true);
+        }
+        assert_eq!(*levels.borrow(), vec![false]);
+    }
+
+    #[test]
+    fn test_new_does_not_drive_pin() {
+        let (pin, levels) = mock();
+        let guard = BlinkGuard::new(pin, Polarity::ActiveHigh);
+        assert!(levels.borrow().is_empty());
+        drop(guard);
+        assert_eq!(*levels.borrow(), vec![false]);
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * @file history.rs
+ * @brief Console command history
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: history.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Console Command History.
+//!
+//! BRIEF:
+//! Provides a heap-free ring buffer of recent console commands
+//! for up-arrow recall.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::HISTORY_LINE_LEN;
+
+/// Heap-free ring buffer of recent console commands.
+///
+/// # Details
+/// Stores up to N fixed-length lines for up-arrow recall.
+/// Pushing into a full history evicts the oldest line.
+///
+/// # Fields
+/// * `lines` - Line storage
+/// * `lens` - Stored length of each line in bytes
+/// * `next` - Slot written by the next push
+/// * `count` - Number of stored lines
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct History<const N: usize> {
+    lines: [[u8; HISTORY_LINE_LEN]; N],
+    lens: [usize; N],
+    next: usize,
+    count: usize,
+}
+
+impl<const N: usize> Default for History<N> {
+    /// Returns default History instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty History
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> History<N> {
+    /// Creates new empty history.
+    ///
+    /// # Returns
+    /// * `Self` - New History instance
+    #[allow(dead_code)]
+    pub const fn new() -> Self {
+        Self {
+            lines: [[0; HISTORY_LINE_LEN]; N],
+            lens: [0; N],
+            next: 0,
+            count: 0,
+        }
+    }
+
+    /// Records a command line.
+    ///
+    /// # Details
+    /// Lines longer than HISTORY_LINE_LEN are truncated on a
+    /// character boundary. Evicts the oldest line when full.
+    ///
+    /// # Arguments
+    /// * `line` - Command line to store
+    #[allow(dead_code)]
+    pub fn push(&mut self, line: &str) {
+        if N == 0 {
+            return;
+        }
+        let mut len = line.len().min(HISTORY_LINE_LEN);
+        while !line.is_char_boundary(len) {
+            len -= 1;
+        }
+        self.lines[self.next][..len].copy_from_slice(&line.as_bytes()[..len]);
+        self.lens[self.next] = len;
+        self.next = (self.next + 1) % N;
+        self.count = (self.count + 1).min(N);
+    }
+
+    /// Returns a stored line by recall index.
+    ///
+    /// # Details
+    /// Index 0 is the most recent line, 1 the one before it.
+    ///
+    /// # Arguments
+    /// * `index` - Recall index
+    ///
+    /// # Returns
+    /// * `Option<&str>` - Stored line, or None past the oldest
+    #[allow(dead_code)]
+    pub fn get(&self, index: usize) -> Option<&str> {
+        if index >= self.count {
+            return None;
+        }
+        let slot = (self.next + N - 1 - index) % N;
+        core::str::from_utf8(&self.lines[slot][..self.lens[slot]]).ok()
+    }
+
+    /// Returns number of stored lines.
+    ///
+    /// # Returns
+    /// * `usize` - Stored line count
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns whether the history is empty.
+    ///
+    /// # Returns
+    /// * `bool` - true if no lines are stored
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== History Tests ====================
+
+    #[test]
+    fn test_new_history_empty() {
+        let history: History<4> = History::new();
+        assert!(history.is_empty());
+        assert_eq!(history.get(0), None);
+    }
+
+    #[test]
+    fn test_get_most_recent_first() {
+        let mut history: History<4> = History::new();
+        history.push("delay 100");
+        history.push("help");
+        assert_eq!(history.get(0), Some("help"));
+        assert_eq!(history.get(1), Some("delay 100"));
+        assert_eq!(history.get(2), None);
+    }
+
+    #[test]
+    fn test_push_evicts_oldest() {
+        let mut history: History<2> = History::new();
+        history.push("a");
+        history.push("b");
+        history.push("c");
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get(0), Some("c"));
+        assert_eq!(history.get(1), Some("b"));
+        assert_eq!(history.get(2), None);
+    }
+
+    #[test]
+    fn test_long_line_truncated() {
+        let mut history: History<1> = History::new();
+        let long = "x".repeat(HISTORY_LINE_LEN + 8);
+        history.push(&long);
+        assert_eq!(history.get(0), Some(&long[..HISTORY_LINE_LEN]));
+    }
+
+    #[test]
+    fn test_truncation_respects_char_boundary() {
+        let mut history: History<1> = History::new();
+        let line = format!("{}é", "x".repeat(HISTORY_LINE_LEN - 1));
+        history.push(&line);
+        assert_eq!(history.get(0), Some(&line[..HISTORY_LINE_LEN - 1]));
+    }
+}
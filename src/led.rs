@@ -37,9 +37,22 @@
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: December 5, 2025
-//! UPDATE DATE: December 6, 2025
+//! UPDATE DATE: October 14, 2026
 
-use crate::config::BLINK_DELAY_MS;
+use crate::config::{
+    BLINK_DELAY_MS, DEFAULT_DUTY_PERCENT, DEFAULT_INTENSITY, DEFAULT_OFF_DUTY_PERCENT,
+    DEFAULT_SPEED_PERCENT, DISABLE_RAMP_MS, DISABLE_RAMP_STEP_MS, DUTY_INVERT, MAX_BLINK_DELAY_MS,
+    MAX_ON_MS, MAX_STARTUP_DELAY_MS, MIN_BLINK_DELAY_MS, PERCEPTIBLE_FLOOR_MS, PROGRESS_FAST_MS,
+    PROGRESS_SLOW_MS, SETTLE_US, SKIP_FIRST_TOGGLE, STARTUP_DELAY_MS, STATUS_DELAY_UNIT_MS,
+    UNLOCK_CODE, WARMUP_BLINKS, WARMUP_DELAY_MS, WATCHDOG_SAFE_PERCENT, WATCHDOG_TIMEOUT_MS,
+};
+use crate::pattern::{BlinkPattern, Pattern, PatternPlayer};
+use crate::pin::{BackendOutput, OutputBackend};
+use crate::polarity::{next_level, Polarity};
+use crate::profile::BlinkProfile;
+use crate::pwm::duty_to_compare;
+use crate::stats::{BlinkStats, BufWriter};
+use core::fmt::Write;
 
 /// LED state enumeration.
 ///
@@ -57,6 +70,138 @@ pub enum LedState {
     Off,
 }
 
+/// LED display mode enumeration.
+///
+/// # Details
+/// Describes how the LED should behave at a high level.
+/// Used by monitors that classify external conditions.
+///
+/// # Variants
+/// * `Blink` - LED toggles at the blink rate
+/// * `Solid` - LED is held steadily on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum LedMode {
+    Blink,
+    Solid,
+}
+
+/// Snapshot of a frozen controller.
+///
+/// # Details
+/// Captures LED state and intra-phase timing for thaw().
+///
+/// # Fields
+/// * `state` - LED state at freeze time
+/// * `phase_elapsed_ms` - Time already spent in the phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct FrozenState {
+    pub state: LedState,
+    pub phase_elapsed_ms: u64,
+}
+
+/// Result of one blink tick.
+///
+/// # Details
+/// Everything the blink task needs to apply one transition.
+///
+/// # Fields
+/// * `state` - New LED state to drive
+/// * `delay_ms` - Blink delay to wait in milliseconds
+/// * `settle_us` - Settle time to wait after the pin write in microseconds
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Tick {
+    pub state: LedState,
+    pub delay_ms: u64,
+    pub settle_us: u64,
+}
+
+/// Handling of blink delays below the perceptible floor.
+///
+/// # Variants
+/// * `ClampUp` - Raise the delay to the floor
+/// * `SolidOn` - Stop blinking and hold the LED on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum SubPerceptualMode {
+    #[default]
+    ClampUp,
+    SolidOn,
+}
+
+/// Runtime delay bounds error.
+///
+/// # Variants
+/// * `Inverted` - Minimum bound is greater than the maximum
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum BoundsError {
+    Inverted,
+}
+
+/// Watchdog delay check error.
+///
+/// # Variants
+/// * `ExceedsWatchdog` - Delay is beyond the safe share of the watchdog timeout
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum WatchdogError {
+    ExceedsWatchdog,
+}
+
+/// Configuration lock error.
+///
+/// # Variants
+/// * `Locked` - Configuration is locked against changes
+/// * `BadCode` - Unlock code did not match
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum LockError {
+    Locked,
+    BadCode,
+}
+
+/// Blink rate transition in progress.
+///
+/// # Details
+/// Linear ramp of the blink delay from a start to a target value.
+///
+/// # Fields
+/// * `from_ms` - Delay when the transition started
+/// * `target_ms` - Delay reached at the end of the transition
+/// * `over_ms` - Transition duration in milliseconds
+/// * `elapsed_ms` - Time spent in the transition so far
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct RateTransition {
+    from_ms: u64,
+    target_ms: u64,
+    over_ms: u64,
+    elapsed_ms: u64,
+}
+
+impl RateTransition {
+    /// Returns the interpolated delay at the current elapsed time.
+    ///
+    /// # Returns
+    /// * `u64` - Delay in milliseconds, exactly target_ms at the end
+    #[allow(dead_code)]
+    fn delay_ms(&self) -> u64 {
+        if self.elapsed_ms >= self.over_ms {
+            return self.target_ms;
+        }
+        let span = (u128::from(self.target_ms.abs_diff(self.from_ms)) * u128::from(self.elapsed_ms)
+            / u128::from(self.over_ms)) as u64;
+        if self.target_ms >= self.from_ms {
+            self.from_ms + span
+        } else {
+            self.from_ms - span
+        }
+    }
+}
+
 /// LED controller with state tracking.
 ///
 /// # Details
@@ -66,11 +211,69 @@ pub enum LedState {
 /// # Fields
 /// * `state` - Current LED state
 /// * `delay_ms` - Blink delay in milliseconds
+/// * `toggle_count` - Number of toggles since creation
+/// * `player` - Player for the active pattern's step table
+/// * `queued_pattern` - Pattern to switch to at the next Off to On boundary
+/// * `duty` - PWM duty cycle in percent while lit
+/// * `intensity` - Logical brightness level (0-255)
+/// * `polarity` - LED polarity
+/// * `phase_elapsed_ms` - Time spent in the current phase
+/// * `frozen` - true while frozen by freeze()
+/// * `stall_ms` - Stall recorded by inhibit_during() still to discount
+/// * `warmup_steps` - Warmup steps left before the steady pattern
+/// * `speed_percent` - Timing scale factor in percent
+/// * `transition` - Blink rate ramp in progress, if any
+/// * `min_ms` - Runtime minimum blink delay
+/// * `max_ms` - Runtime maximum blink delay
+/// * `max_on_ms` - Failsafe limit on continuous on-time, 0 disables
+/// * `failsafe_tripped` - true once the on-time failsafe has fired
+/// * `held_on_ms` - Continuous on-time while latched or held solid on
+/// * `enabled` - false holds the LED off
+/// * `on_subperceptible` - Handling of delays below the perceptible floor
+/// * `latch` - Blink steps left before latching on, Some(0) once latched
+/// * `identify` - Pattern to restore and identify time left, if identifying
+/// * `stats` - Accumulated on and off time
+/// * `disable_ramp_ms` - Duty ramp-down time on disable, 0 cuts off at once
+/// * `ramp_down_ms` - Time into the disable ramp, if ramping down
+/// * `off_duty` - PWM duty cycle in percent during the off phase
+/// * `watchdog_timeout_ms` - Watchdog timeout to respect, 0 for none
+/// * `locked` - true while configuration commands are rejected
+/// * `skip_first_toggle` - true while the next toggle is still to be skipped
+/// * `settle_us` - Settle time after each pin write in microseconds
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[allow(dead_code)]
 pub struct LedController {
     state: LedState,
     delay_ms: u64,
+    toggle_count: u64,
+    player: PatternPlayer,
+    queued_pattern: Option<Pattern>,
+    duty: u8,
+    intensity: u8,
+    polarity: Polarity,
+    phase_elapsed_ms: u64,
+    frozen: bool,
+    stall_ms: u64,
+    warmup_steps: u16,
+    speed_percent: u16,
+    transition: Option<RateTransition>,
+    min_ms: u64,
+    max_ms: u64,
+    max_on_ms: u64,
+    failsafe_tripped: bool,
+    held_on_ms: u64,
+    enabled: bool,
+    on_subperceptible: SubPerceptualMode,
+    latch: Option<u16>,
+    identify: Option<(Pattern, u64)>,
+    stats: BlinkStats,
+    disable_ramp_ms: u64,
+    ramp_down_ms: Option<u64>,
+    off_duty: u8,
+    watchdog_timeout_ms: u64,
+    locked: bool,
+    skip_first_toggle: bool,
+    settle_us: u64,
 }
 
 impl Default for LedController {
@@ -100,6 +303,35 @@ impl LedController {
         Self {
             state: LedState::Off,
             delay_ms: BLINK_DELAY_MS,
+            toggle_count: 0,
+            player: PatternPlayer::new(Pattern::Steady),
+            queued_pattern: None,
+            duty: DEFAULT_DUTY_PERCENT,
+            intensity: DEFAULT_INTENSITY,
+            polarity: Polarity::ActiveHigh,
+            phase_elapsed_ms: 0,
+            frozen: false,
+            stall_ms: 0,
+            warmup_steps: u16::from(WARMUP_BLINKS) * 2,
+            speed_percent: DEFAULT_SPEED_PERCENT,
+            transition: None,
+            min_ms: MIN_BLINK_DELAY_MS,
+            max_ms: MAX_BLINK_DELAY_MS,
+            max_on_ms: MAX_ON_MS,
+            failsafe_tripped: false,
+            held_on_ms: 0,
+            enabled: true,
+            on_subperceptible: SubPerceptualMode::ClampUp,
+            latch: None,
+            identify: None,
+            stats: BlinkStats::new(),
+            disable_ramp_ms: DISABLE_RAMP_MS,
+            ramp_down_ms: None,
+            off_duty: DEFAULT_OFF_DUTY_PERCENT,
+            watchdog_timeout_ms: WATCHDOG_TIMEOUT_MS,
+            locked: false,
+            skip_first_toggle: SKIP_FIRST_TOGGLE,
+            settle_us: SETTLE_US,
         }
     }
 
@@ -107,121 +339,2757 @@ impl LedController {
     ///
     /// # Details
     /// Transitions LED from On to Off or Off to On.
+    /// Increments the toggle count. With skip_first_toggle armed the
+    /// first toggle leaves the state and count unchanged and disarms it.
     ///
     /// # Returns
     /// * `LedState` - New LED state after toggle
     #[allow(dead_code)]
     pub fn toggle(&mut self) -> LedState {
+        if self.skip_first_toggle {
+            self.skip_first_toggle = false;
+            return self.state;
+        }
         self.state = match self.state {
             LedState::On => LedState::Off,
             LedState::Off => LedState::On,
         };
+        self.toggle_count = self.toggle_count.wrapping_add(1);
         self.state
     }
 
-    /// Returns current blink delay.
+    /// Toggles the LED n times at once.
     ///
     /// # Details
-    /// Delay used for blink timing in milliseconds.
+    /// Equivalent to n toggle() calls without looping. An armed
+    /// skip_first_toggle is consumed by the first of them.
+    ///
+    /// # Arguments
+    /// * `n` - Number of toggles
     ///
     /// # Returns
-    /// * `u64` - Delay in milliseconds
+    /// * `LedState` - New LED state
     #[allow(dead_code)]
-    pub fn delay_ms(&self) -> u64 {
-        self.delay_ms
+    pub fn toggle_n(&mut self, n: u64) -> LedState {
+        let n = if self.skip_first_toggle && n > 0 {
+            self.skip_first_toggle = false;
+            n - 1
+        } else {
+            n
+        };
+        self.state = toggle_parity(self.state, n);
+        self.toggle_count = self.toggle_count.wrapping_add(n);
+        self.state
     }
-}
 
-/// Converts LedState to boolean for GPIO control.
-///
-/// # Details
-/// Maps On state to true (high), Off state to false (low).
-///
-/// # Arguments
-/// * `state` - LED state to convert
-///
-/// # Returns
-/// * `bool` - true for On, false for Off
-#[allow(dead_code)]
-pub fn led_state_to_level(state: LedState) -> bool {
-    matches!(state, LedState::On)
-}
+    /// Forces a specific LED state.
+    ///
+    /// # Details
+    /// Sets the state directly instead of toggling. The toggle count
+    /// is only incremented when `count` is set and the state changed.
+    ///
+    /// # Arguments
+    /// * `state` - LED state to apply
+    /// * `count` - true to count a change as a toggle
+    ///
+    /// # Returns
+    /// * `bool` - Applied pin level, polarity-aware
+    #[allow(dead_code)]
+    pub fn set_state(&mut self, state: LedState, count: bool) -> bool {
+        if count && self.state != state {
+            self.toggle_count = self.toggle_count.wrapping_add(1);
+        }
+        self.state = state;
+        self.polarity.level_for(state)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Advances the controller by one blink transition.
+    ///
+    /// # Details
+    /// Toggles the LED and reports the timing to apply.
+    /// Pending warmup blinks use the fast warmup delay first.
+    /// An on-phase longer than the failsafe limit is cut short.
+    /// A frozen controller holds its state without toggling.
+    /// A disable ramp holds the state in DISABLE_RAMP_STEP_MS steps
+    /// while the output duty falls, then turns the LED off.
+    /// A disabled controller holds the LED off without toggling.
+    /// A latched controller holds the LED on until clear().
+    /// Latched and solid-on holds count toward the failsafe limit and
+    /// are held off once it is reached, until clear_failsafe().
+    /// A pattern other than Steady plays its step table, with hold
+    /// times scaled by the speed factor.
+    /// Delays below the perceptible floor are clamped up or held
+    /// solid on according to the sub-perceptual mode.
+    /// The settle time is additive to the blink delay.
+    /// The reported hold time is added to the blink statistics and
+    /// counted off a running identify blink.
+    ///
+    /// # Returns
+    /// * `Tick` - New state, blink delay and settle time
+    #[allow(dead_code)]
+    pub fn tick(&mut self) -> Tick {
+        let tick = self.next_tick();
+        self.stats.record(tick.state, tick.delay_ms);
+        self.advance_identify(tick.delay_ms);
+        tick
+    }
 
-    // ==================== LedState Enum Tests ====================
+    /// Computes the next tick without recording statistics.
+    ///
+    /// # Returns
+    /// * `Tick` - New state, blink delay and settle time
+    fn next_tick(&mut self) -> Tick {
+        if self.frozen {
+            return Tick {
+                state: self.state,
+                delay_ms: self.effective_delay_ms(),
+                settle_us: self.settle_us(),
+            };
+        }
+        if let Some(tick) = self.ramp_down_tick() {
+            return tick;
+        }
+        if !self.enabled {
+            self.state = LedState::Off;
+            return Tick {
+                state: LedState::Off,
+                delay_ms: self.effective_delay_ms(),
+                settle_us: self.settle_us(),
+            };
+        }
+        match self.latch {
+            Some(0) => return self.hold_on(self.effective_delay_ms()),
+            Some(steps) => self.latch = Some(steps - 1),
+            None => {}
+        }
+        if self.state == LedState::Off {
+            if let Some(pattern) = self.queued_pattern.take() {
+                self.switch_pattern(pattern);
+            }
+        }
+        let (state, delay_ms) = if self.warmup_steps > 0 {
+            self.warmup_steps -= 1;
+            (self.toggle(), WARMUP_DELAY_MS)
+        } else if self.pattern() != Pattern::Steady {
+            self.next_pattern_step()
+        } else {
+            match perceptible_delay(self.effective_delay_ms(), self.on_subperceptible) {
+                Some(delay_ms) => (self.toggle(), delay_ms),
+                None => return self.hold_on(PERCEPTIBLE_FLOOR_MS),
+            }
+        };
+        self.held_on_ms = 0;
+        let limit = self.phase_limit(delay_ms);
+        if limit < delay_ms {
+            self.failsafe_tripped = true;
+        }
+        Tick {
+            state,
+            delay_ms: limit,
+            settle_us: self.settle_us(),
+        }
+    }
 
-    #[test]
-    fn test_led_state_equality() {
-        assert_eq!(LedState::On, LedState::On);
-        assert_eq!(LedState::Off, LedState::Off);
-        assert_ne!(LedState::On, LedState::Off);
+    /// Steps a disable ramp in progress by one tick.
+    ///
+    /// # Details
+    /// Holds the LED state for one ramp step so the output duty can
+    /// fall, and disables once the ramp has run its full time.
+    ///
+    /// # Returns
+    /// * `Option<Tick>` - Hold tick while ramping, None otherwise
+    fn ramp_down_tick(&mut self) -> Option<Tick> {
+        let elapsed_ms = self.ramp_down_ms?;
+        if elapsed_ms >= self.disable_ramp_ms {
+            self.disable_now();
+            return None;
+        }
+        let hold_ms = DISABLE_RAMP_STEP_MS.min(self.disable_ramp_ms - elapsed_ms);
+        self.ramp_down_ms = Some(elapsed_ms + hold_ms);
+        Some(Tick {
+            state: self.state,
+            delay_ms: hold_ms,
+            settle_us: self.settle_us(),
+        })
     }
 
-    #[test]
-    fn test_led_state_copy() {
-        let state = LedState::On;
-        let copy = state;
-        assert_eq!(state, copy);
+    /// Holds the LED on for one tick under the on-time failsafe.
+    ///
+    /// # Details
+    /// Continuous on-time accumulates across held ticks and the hold
+    /// is cut to the time left before max_on_ms. Once the limit is
+    /// reached the failsafe trips and the LED is held off instead.
+    ///
+    /// # Arguments
+    /// * `delay_ms` - Nominal hold time in milliseconds
+    ///
+    /// # Returns
+    /// * `Tick` - Held state, hold time and settle time
+    fn hold_on(&mut self, delay_ms: u64) -> Tick {
+        let settle_us = self.settle_us();
+        if self.max_on_ms == 0 {
+            self.state = LedState::On;
+            return Tick {
+                state: LedState::On,
+                delay_ms,
+                settle_us,
+            };
+        }
+        if self.held_on_ms >= self.max_on_ms {
+            self.failsafe_tripped = true;
+            self.state = LedState::Off;
+            return Tick {
+                state: LedState::Off,
+                delay_ms,
+                settle_us,
+            };
+        }
+        let hold_ms = delay_ms.min(self.max_on_ms - self.held_on_ms);
+        self.held_on_ms += hold_ms;
+        self.state = LedState::On;
+        Tick {
+            state: LedState::On,
+            delay_ms: hold_ms,
+            settle_us,
+        }
     }
 
-    #[test]
-    fn test_led_state_to_level_on() {
-        assert!(led_state_to_level(LedState::On));
+    /// Applies the next step of the selected pattern.
+    ///
+    /// # Details
+    /// Counts a state change as a toggle; the player wraps at the end
+    /// of the step table.
+    ///
+    /// # Returns
+    /// * `(LedState, u64)` - Step state and hold time scaled by the speed factor
+    fn next_pattern_step(&mut self) -> (LedState, u64) {
+        let (state, hold_ms) = self.player.next_step();
+        self.set_state(state, true);
+        let hold_ms = hold_ms.saturating_mul(u64::from(self.speed_percent)) / 100;
+        (state, hold_ms)
     }
 
-    #[test]
-    fn test_led_state_to_level_off() {
-        assert!(!led_state_to_level(LedState::Off));
+    /// Switches the active pattern, restarting its step table.
+    ///
+    /// # Arguments
+    /// * `pattern` - Pattern to play
+    fn switch_pattern(&mut self, pattern: Pattern) {
+        if pattern != self.pattern() {
+            self.player = PatternPlayer::new(pattern);
+        }
     }
 
-    // ==================== LedController Tests ====================
+    /// Blinks a few times then latches the LED on.
+    ///
+    /// # Details
+    /// Indicates an event awaiting acknowledgement. Starts from Off
+    /// so every blink is visible; the LED stays on until clear().
+    ///
+    /// # Arguments
+    /// * `blinks` - Number of blinks before latching
+    #[allow(dead_code)]
+    pub fn blink_then_latch(&mut self, blinks: u8) {
+        self.state = LedState::Off;
+        self.latch = Some(u16::from(blinks) * 2);
+    }
 
-    #[test]
-    fn test_new_controller() {
-        let ctrl = LedController::new();
-        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS);
+    /// Returns whether the LED is latched on.
+    ///
+    /// # Returns
+    /// * `bool` - true after blink_then_latch finished and before clear()
+    #[allow(dead_code)]
+    pub fn is_latched(&self) -> bool {
+        self.latch == Some(0)
     }
 
-    #[test]
-    fn test_default_equals_new() {
-        let default = LedController::default();
-        let new = LedController::new();
-        assert_eq!(default.delay_ms(), new.delay_ms());
+    /// Clears an event latch and resumes normal blinking.
+    #[allow(dead_code)]
+    pub fn clear(&mut self) {
+        self.latch = None;
     }
 
-    #[test]
-    fn test_toggle_off_to_on() {
-        let mut ctrl = LedController::new();
-        assert_eq!(ctrl.toggle(), LedState::On);
+    /// Sets handling of delays below the perceptible floor.
+    ///
+    /// # Arguments
+    /// * `mode` - ClampUp or SolidOn
+    #[allow(dead_code)]
+    pub fn set_subperceptual_mode(&mut self, mode: SubPerceptualMode) {
+        self.on_subperceptible = mode;
     }
 
-    #[test]
-    fn test_toggle_on_to_off() {
-        let mut ctrl = LedController::new();
-        ctrl.toggle();
-        assert_eq!(ctrl.toggle(), LedState::Off);
+    /// Returns the longest time the current phase may last.
+    ///
+    /// # Details
+    /// The failsafe caps on-phases at max_on_ms when enabled.
+    ///
+    /// # Arguments
+    /// * `delay_ms` - Nominal phase delay in milliseconds
+    ///
+    /// # Returns
+    /// * `u64` - Phase limit in milliseconds
+    fn phase_limit(&self, delay_ms: u64) -> u64 {
+        if self.state == LedState::On && self.max_on_ms > 0 {
+            delay_ms.min(self.max_on_ms)
+        } else {
+            delay_ms
+        }
     }
 
-    #[test]
-    fn test_multiple_toggles() {
-        let mut ctrl = LedController::new();
-        assert_eq!(ctrl.toggle(), LedState::On);
-        assert_eq!(ctrl.toggle(), LedState::Off);
-        assert_eq!(ctrl.toggle(), LedState::On);
-        assert_eq!(ctrl.toggle(), LedState::Off);
+    /// Sets the failsafe limit on continuous on-time.
+    ///
+    /// # Arguments
+    /// * `max_on_ms` - Limit in milliseconds, 0 disables the failsafe
+    #[allow(dead_code)]
+    pub fn set_max_on_ms(&mut self, max_on_ms: u64) {
+        self.max_on_ms = max_on_ms;
     }
 
-    #[test]
-    fn test_initial_state_off() {
-        let ctrl = LedController::new();
-        let expected = LedController {
-            state: LedState::Off,
-            delay_ms: BLINK_DELAY_MS,
-        };
-        assert_eq!(ctrl, expected);
+    /// Returns whether the on-time failsafe has fired.
+    ///
+    /// # Returns
+    /// * `bool` - true once an on-phase was forced off
+    #[allow(dead_code)]
+    pub fn failsafe_tripped(&self) -> bool {
+        self.failsafe_tripped
+    }
+
+    /// Clears the on-time failsafe flag.
+    ///
+    /// # Details
+    /// Also restarts the continuous on-time of a latched or solid-on
+    /// hold, so the LED may light again.
+    #[allow(dead_code)]
+    pub fn clear_failsafe(&mut self) {
+        self.failsafe_tripped = false;
+        self.held_on_ms = 0;
+    }
+
+    /// Sets number of warmup blinks to run before the steady pattern.
+    ///
+    /// # Details
+    /// Restarts the one-shot warmup phase.
+    ///
+    /// # Arguments
+    /// * `blinks` - Number of fast warmup blinks
+    #[allow(dead_code)]
+    pub fn set_warmup_blinks(&mut self, blinks: u8) {
+        self.warmup_steps = u16::from(blinks) * 2;
+    }
+
+    /// Returns whether warmup blinks are still pending.
+    ///
+    /// # Returns
+    /// * `bool` - true until the warmup phase completes
+    #[allow(dead_code)]
+    pub fn in_warmup(&self) -> bool {
+        self.warmup_steps > 0
+    }
+
+    /// Starts a smooth ramp of the blink delay to a new rate.
+    ///
+    /// # Details
+    /// The delay is interpolated linearly by advance() from its
+    /// current value to the clamped target over the given time.
+    /// A zero duration applies the target immediately.
+    ///
+    /// # Arguments
+    /// * `target_ms` - Delay to reach in milliseconds
+    /// * `over_ms` - Ramp duration in milliseconds
+    #[allow(dead_code)]
+    pub fn transition_to(&mut self, target_ms: u64, over_ms: u64) {
+        let target_ms = self.clamp(target_ms);
+        if over_ms == 0 {
+            self.delay_ms = target_ms;
+            self.transition = None;
+            return;
+        }
+        self.transition = Some(RateTransition {
+            from_ms: self.delay_ms,
+            target_ms,
+            over_ms,
+            elapsed_ms: 0,
+        });
+    }
+
+    /// Returns whether a blink rate transition is in progress.
+    ///
+    /// # Returns
+    /// * `bool` - true until the ramp reaches its target
+    #[allow(dead_code)]
+    pub fn in_transition(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Steps an active rate transition by elapsed time.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance
+    fn advance_transition(&mut self, elapsed_ms: u64) {
+        if let Some(transition) = self.transition.as_mut() {
+            transition.elapsed_ms = transition.elapsed_ms.saturating_add(elapsed_ms);
+            self.delay_ms = transition.delay_ms();
+            if transition.elapsed_ms >= transition.over_ms {
+                self.transition = None;
+            }
+        }
+    }
+
+    /// Advances phase timing by elapsed time.
+    ///
+    /// # Details
+    /// Steps any rate transition, then toggles once the current
+    /// phase has lasted the blink delay, carrying any excess into
+    /// the next phase. A stall recorded by inhibit_during() is
+    /// discounted first. Elapsed time is added to the blink statistics
+    /// under the state held. An on-phase reaching the failsafe limit is
+    /// forced off and trips the failsafe. Does nothing while frozen
+    /// or disabled. Steps any disable ramp and latches off once it
+    /// completes.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance
+    ///
+    /// # Returns
+    /// * `Option<LedState>` - New state if a toggle occurred
+    #[allow(dead_code)]
+    pub fn advance(&mut self, elapsed_ms: u64) -> Option<LedState> {
+        let discount_ms = elapsed_ms.min(self.stall_ms);
+        self.stall_ms -= discount_ms;
+        let elapsed_ms = elapsed_ms - discount_ms;
+        self.advance_identify(elapsed_ms);
+        if self.frozen || !self.enabled {
+            return None;
+        }
+        if self.advance_ramp_down(elapsed_ms) {
+            return None;
+        }
+        self.advance_transition(elapsed_ms);
+        self.stats.record(self.state, elapsed_ms);
+        self.phase_elapsed_ms = self.phase_elapsed_ms.saturating_add(elapsed_ms);
+        let delay_ms = self.effective_delay_ms();
+        let limit = self.phase_limit(delay_ms);
+        if self.phase_elapsed_ms < limit {
+            return None;
+        }
+        if limit < delay_ms {
+            self.failsafe_tripped = true;
+        }
+        self.phase_elapsed_ms = (self.phase_elapsed_ms - limit).min(delay_ms);
+        Some(self.toggle())
+    }
+
+    /// Starts the identify blink for a number of seconds.
+    ///
+    /// # Details
+    /// Overrides the selected pattern with Pattern::Identify and
+    /// restores it once tick() or advance() has used up the duration.
+    /// Calling again while
+    /// identifying restarts the countdown. Zero seconds does nothing.
+    ///
+    /// # Arguments
+    /// * `duration_s` - Identify duration in seconds
+    #[allow(dead_code)]
+    pub fn identify(&mut self, duration_s: u16) {
+        if duration_s == 0 {
+            return;
+        }
+        self.identify = Some((self.selected_pattern(), u64::from(duration_s) * 1000));
+        self.switch_pattern(Pattern::Identify);
+    }
+
+    /// Returns whether the identify blink is running.
+    ///
+    /// # Returns
+    /// * `bool` - true while identifying
+    #[allow(dead_code)]
+    pub fn is_identifying(&self) -> bool {
+        self.identify.is_some()
+    }
+
+    /// Counts down the identify blink.
+    ///
+    /// # Details
+    /// Restores the overridden pattern once the time is used up.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance or tick
+    fn advance_identify(&mut self, elapsed_ms: u64) {
+        if let Some((restore, remaining_ms)) = self.identify {
+            let remaining_ms = remaining_ms.saturating_sub(elapsed_ms);
+            if remaining_ms == 0 {
+                self.switch_pattern(restore);
+                self.identify = None;
+            } else {
+                self.identify = Some((restore, remaining_ms));
+            }
+        }
+    }
+
+    /// Enables or disables blinking.
+    ///
+    /// # Details
+    /// Disabling turns the LED off immediately unless a disable ramp
+    /// is configured, in which case duty ramps to zero over
+    /// disable_ramp_ms in advance() or tick() before latching off. Enabling
+    /// cancels a ramp in progress.
+    ///
+    /// # Arguments
+    /// * `enabled` - false holds the LED off
+    #[allow(dead_code)]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if enabled {
+            self.enabled = true;
+            self.ramp_down_ms = None;
+        } else if self.enabled && self.disable_ramp_ms > 0 {
+            self.ramp_down_ms.get_or_insert(0);
+        } else {
+            self.disable_now();
+        }
+    }
+
+    /// Sets the duty ramp-down time used on disable.
+    ///
+    /// # Arguments
+    /// * `ramp_ms` - Ramp duration in milliseconds, 0 for instant off
+    #[allow(dead_code)]
+    pub fn set_disable_ramp_ms(&mut self, ramp_ms: u64) {
+        self.disable_ramp_ms = ramp_ms;
+    }
+
+    /// Returns whether a disable ramp is in progress.
+    ///
+    /// # Returns
+    /// * `bool` - true while ramping down to off
+    #[allow(dead_code)]
+    pub fn is_ramping_down(&self) -> bool {
+        self.ramp_down_ms.is_some()
+    }
+
+    /// Returns the PWM duty to apply now.
+    ///
+    /// # Details
+    /// The duty setting, scaled down linearly during a disable ramp,
+    /// and 0 once disabled.
+    ///
+    /// # Returns
+    /// * `u8` - Output duty in percent
+    #[allow(dead_code)]
+    pub fn output_duty(&self) -> u8 {
+        self.ramped(self.duty)
+    }
+
+    /// Scales a duty by the disable ramp.
+    ///
+    /// # Arguments
+    /// * `duty` - Duty in percent
+    ///
+    /// # Returns
+    /// * `u8` - Duty scaled down during a ramp, 0 once disabled
+    fn ramped(&self, duty: u8) -> u8 {
+        if !self.enabled {
+            return 0;
+        }
+        match self.ramp_down_ms {
+            Some(elapsed_ms) => {
+                let left_ms = self.disable_ramp_ms.saturating_sub(elapsed_ms);
+                (u64::from(duty) * left_ms)
+                    .checked_div(self.disable_ramp_ms)
+                    .unwrap_or(0) as u8
+            }
+            None => duty,
+        }
+    }
+
+    /// Steps a disable ramp in progress.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance
+    ///
+    /// # Returns
+    /// * `bool` - true if the ramp completed and the LED latched off
+    fn advance_ramp_down(&mut self, elapsed_ms: u64) -> bool {
+        let Some(ramp_ms) = self.ramp_down_ms.as_mut() else {
+            return false;
+        };
+        *ramp_ms = ramp_ms.saturating_add(elapsed_ms);
+        if *ramp_ms < self.disable_ramp_ms {
+            return false;
+        }
+        self.disable_now();
+        true
+    }
+
+    /// Disables blinking and turns the LED off at once.
+    fn disable_now(&mut self) {
+        self.enabled = false;
+        self.ramp_down_ms = None;
+        self.state = LedState::Off;
+    }
+
+    /// Returns whether blinking is enabled.
+    ///
+    /// # Returns
+    /// * `bool` - true while enabled
+    #[allow(dead_code)]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Packs the controller status into a register-style word.
+    ///
+    /// # Details
+    /// Bit layout:
+    /// * bit 0 - enabled
+    /// * bit 1 - paused (frozen)
+    /// * bit 2 - LED state, 1 for On
+    /// * bits 3..8 - reserved, zero
+    /// * bits 8..16 - duty in percent
+    /// * bits 16..32 - delay index in STATUS_DELAY_UNIT_MS units,
+    ///   saturating at 0xFFFF
+    ///
+    /// # Returns
+    /// * `u32` - Packed status word
+    #[allow(dead_code)]
+    pub fn status_word(&self) -> u32 {
+        let delay_index = (self.delay_ms / STATUS_DELAY_UNIT_MS).min(0xFFFF) as u32;
+        u32::from(self.enabled)
+            | u32::from(self.frozen) << 1
+            | u32::from(self.state == LedState::On) << 2
+            | u32::from(self.duty) << 8
+            | delay_index << 16
+    }
+
+    /// Reconstructs a controller from a status word.
+    ///
+    /// # Details
+    /// Restores the mutable subset: enabled, paused, LED state, duty
+    /// and delay. Duty is capped at 100 and the delay is clamped; all
+    /// other settings take their defaults.
+    ///
+    /// # Arguments
+    /// * `word` - Word produced by status_word()
+    ///
+    /// # Returns
+    /// * `Self` - Controller with the packed settings
+    #[allow(dead_code)]
+    pub fn from_status_word(word: u32) -> Self {
+        let mut ctrl = Self::new();
+        ctrl.enabled = word & 1 != 0;
+        ctrl.frozen = word & (1 << 1) != 0;
+        ctrl.state = if word & (1 << 2) != 0 {
+            LedState::On
+        } else {
+            LedState::Off
+        };
+        ctrl.duty = ((word >> 8) as u8).min(100);
+        ctrl.delay_ms = ctrl.clamp(u64::from(word >> 16) * STATUS_DELAY_UNIT_MS);
+        ctrl
+    }
+
+    /// Returns the next N toggle times in milliseconds.
+    ///
+    /// # Details
+    /// Accounts for time already spent in the current phase.
+    ///
+    /// # Arguments
+    /// * `base_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `[u64; N]` - Absolute toggle times in milliseconds
+    #[allow(dead_code)]
+    pub fn upcoming_deadlines_ms<const N: usize>(&self, base_ms: u64) -> [u64; N] {
+        deadlines_ms(base_ms, self.effective_delay_ms(), self.phase_elapsed_ms)
+    }
+
+    /// Returns the next N toggle instants.
+    ///
+    /// # Arguments
+    /// * `base` - Current instant
+    ///
+    /// # Returns
+    /// * `[embassy_time::Instant; N]` - Absolute toggle instants
+    #[cfg(feature = "embassy-time")]
+    #[allow(dead_code)]
+    pub fn upcoming_deadlines<const N: usize>(
+        &self,
+        base: embassy_time::Instant,
+    ) -> [embassy_time::Instant; N] {
+        self.upcoming_deadlines_ms::<N>(base.as_millis())
+            .map(embassy_time::Instant::from_millis)
+    }
+
+    /// Returns time spent in the current phase.
+    ///
+    /// # Returns
+    /// * `u64` - Elapsed phase time in milliseconds
+    #[allow(dead_code)]
+    pub fn phase_elapsed_ms(&self) -> u64 {
+        self.phase_elapsed_ms
+    }
+
+    /// Freezes the LED in its current state.
+    ///
+    /// # Details
+    /// Captures state and intra-phase timing and stops advance() and
+    /// tick() toggling until thaw() is called, e.g. around a flash write.
+    ///
+    /// # Returns
+    /// * `FrozenState` - Snapshot to pass to thaw()
+    #[allow(dead_code)]
+    pub fn freeze(&mut self) -> FrozenState {
+        self.frozen = true;
+        FrozenState {
+            state: self.state,
+            phase_elapsed_ms: self.phase_elapsed_ms,
+        }
+    }
+
+    /// Restores a frozen snapshot and resumes timing.
+    ///
+    /// # Details
+    /// The phase continues exactly where it was frozen.
+    ///
+    /// # Arguments
+    /// * `frozen` - Snapshot returned by freeze()
+    #[allow(dead_code)]
+    pub fn thaw(&mut self, frozen: FrozenState) {
+        self.state = frozen.state;
+        self.phase_elapsed_ms = frozen.phase_elapsed_ms;
+        self.frozen = false;
+    }
+
+    /// Runs a closure with the blink held.
+    ///
+    /// # Details
+    /// Reads the clock around the closure and records the stall, such
+    /// as a flash erase, so the next advance() calls discount it from
+    /// their elapsed time. The phase resumes from its accumulated
+    /// elapsed time instead of being cut short or skipped.
+    ///
+    /// # Arguments
+    /// * `now` - Millisecond clock that advance() elapsed times come from
+    /// * `f` - Work to run while inhibited
+    ///
+    /// # Returns
+    /// * `R` - Result of the closure
+    #[allow(dead_code)]
+    pub fn inhibit_during<R, C: FnMut() -> u64, F: FnOnce() -> R>(
+        &mut self,
+        mut now: C,
+        f: F,
+    ) -> R {
+        let start_ms = now();
+        let result = f();
+        let stall_ms = now().saturating_sub(start_ms);
+        self.stall_ms = self.stall_ms.saturating_add(stall_ms);
+        result
+    }
+
+    /// Returns whether the controller is frozen.
+    ///
+    /// # Returns
+    /// * `bool` - true between freeze() and thaw()
+    #[allow(dead_code)]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Returns number of toggles since creation.
+    ///
+    /// # Returns
+    /// * `u64` - Toggle count
+    #[allow(dead_code)]
+    pub fn toggle_count(&self) -> u64 {
+        self.toggle_count
+    }
+
+    /// Returns number of full blink cycles completed.
+    ///
+    /// # Details
+    /// One cycle is an ON toggle followed by an OFF toggle.
+    ///
+    /// # Returns
+    /// * `u64` - Completed cycles
+    #[allow(dead_code)]
+    pub fn cycles_completed(&self) -> u64 {
+        self.toggle_count / 2
+    }
+
+    /// Returns whether the LED is in the ON phase.
+    ///
+    /// # Returns
+    /// * `bool` - true if the LED is currently on
+    #[allow(dead_code)]
+    pub fn in_on_phase(&self) -> bool {
+        self.state == LedState::On
+    }
+
+    /// Returns current blink delay.
+    ///
+    /// # Details
+    /// Delay used for blink timing in milliseconds.
+    ///
+    /// # Returns
+    /// * `u64` - Delay in milliseconds
+    #[allow(dead_code)]
+    pub fn delay_ms(&self) -> u64 {
+        self.delay_ms
+    }
+
+    /// Sets blink delay.
+    ///
+    /// # Details
+    /// Delay is clamped to the allowed range. Callers can skip
+    /// peripheral reconfiguration when nothing changed.
+    ///
+    /// # Arguments
+    /// * `delay_ms` - Requested delay in milliseconds
+    ///
+    /// # Returns
+    /// * `bool` - true if the clamped delay differs from the old one
+    #[allow(dead_code)]
+    pub fn set_delay(&mut self, delay_ms: u64) -> bool {
+        let delay_ms = self.clamp(delay_ms);
+        let changed = delay_ms != self.delay_ms;
+        self.delay_ms = delay_ms;
+        changed
+    }
+
+    /// Sets blink delay only if it is safe for the watchdog.
+    ///
+    /// # Details
+    /// Rejects a delay whose effective phase time, after clamping and
+    /// the speed factor, exceeds WATCHDOG_SAFE_PERCENT of the watchdog
+    /// timeout. The delay is left unchanged on rejection.
+    ///
+    /// # Arguments
+    /// * `delay_ms` - Requested delay in milliseconds
+    ///
+    /// # Returns
+    /// * `Result<bool, WatchdogError>` - Whether the delay changed, or ExceedsWatchdog
+    #[allow(dead_code)]
+    pub fn set_delay_checked(&mut self, delay_ms: u64) -> Result<bool, WatchdogError> {
+        let scaled = self
+            .clamp(delay_ms)
+            .saturating_mul(u64::from(self.speed_percent))
+            / 100;
+        if !watchdog_safe(self.clamp(scaled), self.watchdog_timeout_ms) {
+            return Err(WatchdogError::ExceedsWatchdog);
+        }
+        Ok(self.set_delay(delay_ms))
+    }
+
+    /// Sets the watchdog timeout delays are checked against.
+    ///
+    /// # Arguments
+    /// * `timeout_ms` - Watchdog timeout in milliseconds, 0 for none
+    #[allow(dead_code)]
+    pub fn set_watchdog_timeout_ms(&mut self, timeout_ms: u64) {
+        self.watchdog_timeout_ms = timeout_ms;
+    }
+
+    /// Arms or clears skipping of the first toggle.
+    ///
+    /// # Details
+    /// When armed the next toggle is a no-op, holding the LED in its
+    /// current state for one extra phase before blinking starts.
+    ///
+    /// # Arguments
+    /// * `skip` - true to skip the next toggle
+    #[allow(dead_code)]
+    pub fn set_skip_first_toggle(&mut self, skip: bool) {
+        self.skip_first_toggle = skip;
+    }
+
+    /// Locks the configuration against commands.
+    ///
+    /// # Details
+    /// Mutating commands are rejected until unlock() is given
+    /// UNLOCK_CODE. Direct controller calls are not affected.
+    #[allow(dead_code)]
+    pub fn lock(&mut self) {
+        self.locked = true;
+    }
+
+    /// Unlocks the configuration.
+    ///
+    /// # Details
+    /// A wrong code leaves the lock in place. Unlocking an unlocked
+    /// controller with the right code is a no-op.
+    ///
+    /// # Arguments
+    /// * `code` - Unlock code to check against UNLOCK_CODE
+    ///
+    /// # Returns
+    /// * `Result<(), LockError>` - Ok once unlocked, or BadCode
+    #[allow(dead_code)]
+    pub fn unlock(&mut self, code: u32) -> Result<(), LockError> {
+        if code != UNLOCK_CODE {
+            return Err(LockError::BadCode);
+        }
+        self.locked = false;
+        Ok(())
+    }
+
+    /// Returns whether the configuration is locked.
+    ///
+    /// # Returns
+    /// * `bool` - true between lock() and a successful unlock()
+    #[allow(dead_code)]
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Checks that configuration changes are allowed.
+    ///
+    /// # Returns
+    /// * `Result<(), LockError>` - Ok when unlocked, or Locked
+    #[allow(dead_code)]
+    pub fn check_unlocked(&self) -> Result<(), LockError> {
+        if self.locked {
+            Err(LockError::Locked)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sets blink delay from a completion percentage.
+    ///
+    /// # Details
+    /// Blinks faster as progress approaches 100 percent.
+    ///
+    /// # Arguments
+    /// * `pct` - Completion in percent, capped at 100
+    #[allow(dead_code)]
+    pub fn set_progress(&mut self, pct: u8) {
+        self.set_delay(delay_for_progress(pct));
+    }
+
+    /// Sets runtime blink delay bounds.
+    ///
+    /// # Details
+    /// Replaces the configured limits for this controller and
+    /// re-clamps the current delay into the new range.
+    ///
+    /// # Arguments
+    /// * `min_ms` - Minimum blink delay in milliseconds
+    /// * `max_ms` - Maximum blink delay in milliseconds
+    ///
+    /// # Returns
+    /// * `Result<(), BoundsError>` - Ok, or Inverted if min exceeds max
+    #[allow(dead_code)]
+    pub fn set_bounds(&mut self, min_ms: u64, max_ms: u64) -> Result<(), BoundsError> {
+        if min_ms > max_ms {
+            return Err(BoundsError::Inverted);
+        }
+        self.min_ms = min_ms;
+        self.max_ms = max_ms;
+        self.delay_ms = self.clamp(self.delay_ms);
+        Ok(())
+    }
+
+    /// Returns runtime blink delay bounds.
+    ///
+    /// # Returns
+    /// * `(u64, u64)` - Minimum and maximum delay in milliseconds
+    #[allow(dead_code)]
+    pub fn bounds(&self) -> (u64, u64) {
+        (self.min_ms, self.max_ms)
+    }
+
+    /// Clamps a delay to the runtime bounds.
+    ///
+    /// # Arguments
+    /// * `delay_ms` - Requested delay in milliseconds
+    ///
+    /// # Returns
+    /// * `u64` - Delay within the runtime bounds
+    #[allow(dead_code)]
+    pub fn clamp(&self, delay_ms: u64) -> u64 {
+        delay_ms.clamp(self.min_ms, self.max_ms)
+    }
+
+    /// Sets the global timing speed factor.
+    ///
+    /// # Details
+    /// 100 is normal speed, 200 doubles every delay (half speed)
+    /// and 50 halves it (double speed).
+    ///
+    /// # Arguments
+    /// * `factor_percent` - Speed factor in percent
+    #[allow(dead_code)]
+    pub fn set_speed_factor(&mut self, factor_percent: u16) {
+        self.speed_percent = factor_percent;
+    }
+
+    /// Returns blink delay scaled by the speed factor.
+    ///
+    /// # Details
+    /// Result is clamped to the allowed delay range.
+    ///
+    /// # Returns
+    /// * `u64` - Effective delay in milliseconds
+    #[allow(dead_code)]
+    pub fn effective_delay_ms(&self) -> u64 {
+        self.clamp(self.delay_ms.saturating_mul(u64::from(self.speed_percent)) / 100)
+    }
+
+    /// Computes a timing correction from measured phase durations.
+    ///
+    /// # Details
+    /// Each sample is the measured time between two toggles. The
+    /// mean overshoot against the effective delay is the loop
+    /// overhead to subtract from the delay so real timing matches the
+    /// request. Samples at or below the request give no correction.
+    ///
+    /// # Arguments
+    /// * `samples` - Measured phase durations in milliseconds
+    ///
+    /// # Returns
+    /// * `u64` - Correction in milliseconds, 0 if samples is empty
+    #[allow(dead_code)]
+    pub fn calibrate(&self, samples: &[u64]) -> u64 {
+        if samples.is_empty() {
+            return 0;
+        }
+        let total: u128 = samples.iter().map(|&ms| u128::from(ms)).sum();
+        let mean = (total / samples.len() as u128) as u64;
+        mean.saturating_sub(self.effective_delay_ms())
+    }
+
+    /// Returns the blink rate for UI display.
+    ///
+    /// # Returns
+    /// * `u64` - Full blinks per minute at the effective delay
+    #[allow(dead_code)]
+    pub fn blinks_per_minute(&self) -> u64 {
+        blinks_per_minute(self.effective_delay_ms())
+    }
+
+    /// Returns accumulated blink statistics.
+    ///
+    /// # Returns
+    /// * `&BlinkStats` - On and off time recorded by tick() and advance()
+    #[allow(dead_code)]
+    pub fn stats(&self) -> &BlinkStats {
+        &self.stats
+    }
+
+    /// Returns a relative energy estimate.
+    ///
+    /// # Details
+    /// Accumulated on-time multiplied by the duty setting, saturating.
+    /// Unitless (millisecond-percent) and only meaningful for comparing
+    /// budgets against each other, not as an absolute power figure.
+    ///
+    /// # Returns
+    /// * `u64` - Energy proxy
+    #[allow(dead_code)]
+    pub fn energy_proxy(&self) -> u64 {
+        self.stats.on_ms().saturating_mul(u64::from(self.duty))
+    }
+
+    /// Writes a compact key=value dump of the controller state.
+    ///
+    /// # Details
+    /// Writes `state=<On|Off> delay=<ms> count=<toggles> duty=<pct>`
+    /// with integer formatting only. Output that does not fit the
+    /// buffer is truncated.
+    ///
+    /// # Arguments
+    /// * `buf` - Destination buffer
+    ///
+    /// # Returns
+    /// * `usize` - Number of bytes written
+    #[allow(dead_code)]
+    pub fn debug_dump(&self, buf: &mut [u8]) -> usize {
+        let mut writer = BufWriter::new(buf);
+        let _ = write!(
+            writer,
+            "state={:?} delay={} count={} duty={}",
+            self.state, self.delay_ms, self.toggle_count, self.duty
+        );
+        writer.len()
+    }
+
+    /// Returns post-toggle settle time.
+    ///
+    /// # Details
+    /// Wait inserted after each pin write for noisy loads.
+    ///
+    /// # Returns
+    /// * `u64` - Settle time in microseconds
+    #[allow(dead_code)]
+    pub fn settle_us(&self) -> u64 {
+        self.settle_us
+    }
+
+    /// Sets post-toggle settle time.
+    ///
+    /// # Arguments
+    /// * `settle_us` - Settle time in microseconds, 0 disables it
+    #[allow(dead_code)]
+    pub fn set_settle_us(&mut self, settle_us: u64) {
+        self.settle_us = settle_us;
+    }
+
+    /// Returns startup delay before the first blink.
+    ///
+    /// # Details
+    /// Configured startup delay clamped to the allowed maximum.
+    ///
+    /// # Returns
+    /// * `u64` - Startup delay in milliseconds
+    #[allow(dead_code)]
+    pub fn startup_delay_ms(&self) -> u64 {
+        clamp_startup_delay(STARTUP_DELAY_MS)
+    }
+
+    /// Returns selected blink pattern.
+    ///
+    /// # Returns
+    /// * `Pattern` - Current pattern
+    #[allow(dead_code)]
+    pub fn pattern(&self) -> Pattern {
+        self.player.pattern()
+    }
+
+    /// Returns the pattern selected by the user.
+    ///
+    /// # Details
+    /// Same as pattern() except while identifying, when it returns the
+    /// pattern that identify will restore, or while a queued pattern
+    /// waits for its boundary.
+    ///
+    /// # Returns
+    /// * `Pattern` - Selected pattern
+    #[allow(dead_code)]
+    pub fn selected_pattern(&self) -> Pattern {
+        match self.identify {
+            Some((pattern, _)) => pattern,
+            None => self.queued_pattern.unwrap_or(self.pattern()),
+        }
+    }
+
+    /// Selects the blink pattern at once.
+    ///
+    /// # Details
+    /// While identifying, the selection takes effect when identify ends.
+    /// Replaces any queued pattern.
+    ///
+    /// # Arguments
+    /// * `pattern` - Pattern to select
+    #[allow(dead_code)]
+    pub fn set_pattern(&mut self, pattern: Pattern) {
+        self.queued_pattern = None;
+        match self.identify.as_mut() {
+            Some((restore, _)) => *restore = pattern,
+            None => self.switch_pattern(pattern),
+        }
+    }
+
+    /// Queues a pattern to switch to without a mid-phase glitch.
+    ///
+    /// # Details
+    /// The switch is deferred to the next tick that starts from Off,
+    /// so the current phase always runs its full hold. Queuing again
+    /// replaces the pending pattern; queuing the active pattern
+    /// cancels it. While identifying, the selection takes effect when
+    /// identify ends.
+    ///
+    /// # Arguments
+    /// * `pattern` - Pattern to switch to
+    #[allow(dead_code)]
+    pub fn queue_pattern(&mut self, pattern: Pattern) {
+        let active = self.pattern();
+        match self.identify.as_mut() {
+            Some((restore, _)) => *restore = pattern,
+            None => self.queued_pattern = (pattern != active).then_some(pattern),
+        }
+    }
+
+    /// Returns PWM duty cycle while lit.
+    ///
+    /// # Returns
+    /// * `u8` - Duty in percent (0-100)
+    #[allow(dead_code)]
+    pub fn duty(&self) -> u8 {
+        self.duty
+    }
+
+    /// Sets the on-phase PWM duty.
+    ///
+    /// # Details
+    /// Callers can skip peripheral reconfiguration when nothing changed.
+    ///
+    /// # Arguments
+    /// * `duty` - Duty in percent, capped at 100
+    ///
+    /// # Returns
+    /// * `bool` - true if the capped duty differs from the old one
+    #[allow(dead_code)]
+    pub fn set_duty(&mut self, duty: u8) -> bool {
+        let duty = duty.min(100);
+        let changed = duty != self.duty;
+        self.duty = duty;
+        changed
+    }
+
+    /// Returns the off-phase glow duty.
+    ///
+    /// # Returns
+    /// * `u8` - Duty in percent (0-100)
+    #[allow(dead_code)]
+    pub fn off_duty(&self) -> u8 {
+        self.off_duty
+    }
+
+    /// Sets the off-phase glow duty.
+    ///
+    /// # Arguments
+    /// * `duty` - Duty in percent, capped at 100
+    #[allow(dead_code)]
+    pub fn set_off_duty(&mut self, duty: u8) {
+        self.off_duty = duty.min(100);
+    }
+
+    /// Returns the PWM level for the current phase.
+    ///
+    /// # Details
+    /// Scales the on duty during the on phase and the off glow duty
+    /// during the off phase, both following a disable ramp. A
+    /// disabled controller returns 0.
+    ///
+    /// # Arguments
+    /// * `max` - Level for 100 percent duty
+    ///
+    /// # Returns
+    /// * `u16` - Level in 0..=max
+    #[allow(dead_code)]
+    pub fn duty_for_phase(&self, max: u16) -> u16 {
+        (u32::from(max) * u32::from(self.phase_duty()) / 100) as u16
+    }
+
+    /// Returns the duty for the current phase.
+    ///
+    /// # Returns
+    /// * `u8` - On or off duty in percent, scaled by the disable ramp
+    fn phase_duty(&self) -> u8 {
+        self.ramped(match self.state {
+            LedState::On => self.duty,
+            LedState::Off => self.off_duty,
+        })
+    }
+
+    /// Returns the output to apply for a backend.
+    ///
+    /// # Details
+    /// Digital maps the LED state through the polarity and the live
+    /// invert flag. PWM converts the phase duty with
+    /// pwm::duty_to_compare, inverted by DUTY_INVERT XOR the invert
+    /// flag.
+    ///
+    /// # Arguments
+    /// * `backend` - Output backend in use
+    /// * `top` - PWM counter top value
+    /// * `invert` - true to swap on and off
+    ///
+    /// # Returns
+    /// * `BackendOutput` - Pin level or PWM compare value
+    #[allow(dead_code)]
+    pub fn output_for(&self, backend: OutputBackend, top: u16, invert: bool) -> BackendOutput {
+        match backend {
+            OutputBackend::Digital => {
+                BackendOutput::Level(next_level(self.polarity, self.state, invert))
+            }
+            OutputBackend::Pwm => BackendOutput::Duty(duty_to_compare(
+                self.phase_duty(),
+                top,
+                DUTY_INVERT ^ invert,
+            )),
+        }
+    }
+
+    /// Returns logical brightness level.
+    ///
+    /// # Returns
+    /// * `u8` - Intensity (0-255)
+    #[allow(dead_code)]
+    pub fn intensity(&self) -> u8 {
+        self.intensity
+    }
+
+    /// Returns LED polarity.
+    ///
+    /// # Returns
+    /// * `Polarity` - Current polarity
+    #[allow(dead_code)]
+    pub fn polarity(&self) -> Polarity {
+        self.polarity
+    }
+
+    /// Applies a blink profile in one step.
+    ///
+    /// # Details
+    /// Sets pattern, delay, duty, intensity and polarity together.
+    /// Delay is clamped to the allowed range and duty to 100%.
+    /// LED state and toggle count are preserved.
+    ///
+    /// # Arguments
+    /// * `profile` - Profile to apply
+    #[allow(dead_code)]
+    pub fn apply_profile(&mut self, profile: &BlinkProfile) {
+        self.switch_pattern(profile.pattern);
+        self.delay_ms = self.clamp(profile.delay_ms);
+        self.duty = profile.duty.min(100);
+        self.intensity = profile.intensity;
+        self.polarity = if profile.active_low {
+            Polarity::ActiveLow
+        } else {
+            Polarity::ActiveHigh
+        };
+    }
+}
+
+/// White-box accessors for unit tests.
+///
+/// # Details
+/// Compiled only for tests so internal counters can be asserted
+/// without widening the public API.
+#[cfg(test)]
+impl LedController {
+    /// Returns the raw LED state field.
+    pub(crate) fn raw_state(&self) -> LedState {
+        self.state
+    }
+
+    /// Returns the raw blink delay field, before speed and bounds.
+    pub(crate) fn raw_delay_ms(&self) -> u64 {
+        self.delay_ms
+    }
+
+    /// Returns the raw toggle counter.
+    pub(crate) fn raw_toggle_count(&self) -> u64 {
+        self.toggle_count
+    }
+
+    /// Returns the raw phase counter.
+    pub(crate) fn raw_phase_elapsed_ms(&self) -> u64 {
+        self.phase_elapsed_ms
+    }
+
+    /// Returns the warmup steps left.
+    pub(crate) fn raw_warmup_steps(&self) -> u16 {
+        self.warmup_steps
+    }
+
+    /// Returns the latch countdown.
+    pub(crate) fn raw_latch(&self) -> Option<u16> {
+        self.latch
+    }
+
+    /// Returns the time into the disable ramp.
+    pub(crate) fn raw_ramp_down_ms(&self) -> Option<u64> {
+        self.ramp_down_ms
+    }
+}
+
+/// Returns the LED state after n toggles.
+///
+/// # Details
+/// An even count returns to the start state.
+///
+/// # Arguments
+/// * `start` - Initial LED state
+/// * `n` - Number of toggles
+///
+/// # Returns
+/// * `LedState` - Resulting LED state
+#[allow(dead_code)]
+pub fn toggle_parity(start: LedState, n: u64) -> LedState {
+    match (start, n.is_multiple_of(2)) {
+        (state, true) => state,
+        (LedState::On, false) => LedState::Off,
+        (LedState::Off, false) => LedState::On,
+    }
+}
+
+/// Applies the perceptible floor to a blink delay.
+///
+/// # Arguments
+/// * `delay_ms` - Requested delay in milliseconds
+/// * `mode` - Handling below the floor
+///
+/// # Returns
+/// * `Option<u64>` - Delay to blink with, or None to hold solid on
+#[allow(dead_code)]
+pub fn perceptible_delay(delay_ms: u64, mode: SubPerceptualMode) -> Option<u64> {
+    match mode {
+        _ if delay_ms >= PERCEPTIBLE_FLOOR_MS => Some(delay_ms),
+        SubPerceptualMode::ClampUp => Some(PERCEPTIBLE_FLOOR_MS),
+        SubPerceptualMode::SolidOn => None,
+    }
+}
+
+/// Computes evenly spaced toggle deadlines.
+///
+/// # Details
+/// The first deadline ends the current phase; each later one is a
+/// further delay apart. Saturates at u64::MAX.
+///
+/// # Arguments
+/// * `base_ms` - Current time in milliseconds
+/// * `delay_ms` - Phase delay in milliseconds
+/// * `phase_elapsed_ms` - Time already spent in the current phase
+///
+/// # Returns
+/// * `[u64; N]` - Absolute toggle times in milliseconds
+#[allow(dead_code)]
+pub fn deadlines_ms<const N: usize>(
+    base_ms: u64,
+    delay_ms: u64,
+    phase_elapsed_ms: u64,
+) -> [u64; N] {
+    let first = base_ms.saturating_add(delay_ms.saturating_sub(phase_elapsed_ms));
+    core::array::from_fn(|i| first.saturating_add(delay_ms.saturating_mul(i as u64)))
+}
+
+/// Maps a completion percentage to a blink delay.
+///
+/// # Details
+/// Interpolates linearly from PROGRESS_SLOW_MS at 0 percent to
+/// PROGRESS_FAST_MS at 100 percent. Percentages above 100 are capped.
+///
+/// # Arguments
+/// * `pct` - Completion in percent
+///
+/// # Returns
+/// * `u64` - Blink delay in milliseconds
+#[allow(dead_code)]
+pub fn delay_for_progress(pct: u8) -> u64 {
+    let pct = u64::from(pct.min(100));
+    clamp_delay(PROGRESS_SLOW_MS - (PROGRESS_SLOW_MS - PROGRESS_FAST_MS) * pct / 100)
+}
+
+/// Computes blinks per minute from a blink delay.
+///
+/// # Details
+/// One blink is an on phase plus an off phase, so the period is twice
+/// the delay. A zero delay is treated as 1 ms to avoid dividing by zero.
+///
+/// # Arguments
+/// * `delay_ms` - Delay per phase in milliseconds
+///
+/// # Returns
+/// * `u64` - Full blinks per minute
+#[allow(dead_code)]
+pub const fn blinks_per_minute(delay_ms: u64) -> u64 {
+    let delay_ms = if delay_ms == 0 { 1 } else { delay_ms };
+    60_000 / delay_ms.saturating_mul(2)
+}
+
+/// Returns whether a phase delay is safe for the watchdog.
+///
+/// # Details
+/// Safe when the delay is at most WATCHDOG_SAFE_PERCENT of the
+/// timeout. A zero timeout means no watchdog, so every delay is safe.
+///
+/// # Arguments
+/// * `delay_ms` - Phase delay in milliseconds
+/// * `timeout_ms` - Watchdog timeout in milliseconds
+///
+/// # Returns
+/// * `bool` - true if the delay cannot starve the watchdog
+#[allow(dead_code)]
+pub fn watchdog_safe(delay_ms: u64, timeout_ms: u64) -> bool {
+    timeout_ms == 0 || delay_ms <= timeout_ms.saturating_mul(WATCHDOG_SAFE_PERCENT) / 100
+}
+
+/// Clamps a blink delay to the allowed range.
+///
+/// # Arguments
+/// * `delay_ms` - Requested delay in milliseconds
+///
+/// # Returns
+/// * `u64` - Delay within MIN_BLINK_DELAY_MS..=MAX_BLINK_DELAY_MS
+#[allow(dead_code)]
+pub fn clamp_delay(delay_ms: u64) -> u64 {
+    delay_ms.clamp(MIN_BLINK_DELAY_MS, MAX_BLINK_DELAY_MS)
+}
+
+/// Clamps a startup delay to the allowed maximum.
+///
+/// # Details
+/// Limits how long the LED is held off after init.
+///
+/// # Arguments
+/// * `delay_ms` - Requested startup delay in milliseconds
+///
+/// # Returns
+/// * `u64` - Delay no greater than MAX_STARTUP_DELAY_MS
+#[allow(dead_code)]
+pub fn clamp_startup_delay(delay_ms: u64) -> u64 {
+    delay_ms.min(MAX_STARTUP_DELAY_MS)
+}
+
+/// Converts LedState to boolean for GPIO control.
+///
+/// # Details
+/// Maps On state to true (high), Off state to false (low).
+///
+/// # Arguments
+/// * `state` - LED state to convert
+///
+/// # Returns
+/// * `bool` - true for On, false for Off
+#[allow(dead_code)]
+pub fn led_state_to_level(state: LedState) -> bool {
+    matches!(state, LedState::On)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== LedState Enum Tests ====================
+
+    #[test]
+    fn test_led_state_equality() {
+        assert_eq!(LedState::On, LedState::On);
+        assert_eq!(LedState::Off, LedState::Off);
+        assert_ne!(LedState::On, LedState::Off);
+    }
+
+    #[test]
+    fn test_led_state_copy() {
+        let state = LedState::On;
+        let copy = state;
+        assert_eq!(state, copy);
+    }
+
+    #[test]
+    fn test_led_state_to_level_on() {
+        assert!(led_state_to_level(LedState::On));
+    }
+
+    #[test]
+    fn test_led_state_to_level_off() {
+        assert!(!led_state_to_level(LedState::Off));
+    }
+
+    // ==================== LedController Tests ====================
+
+    #[test]
+    fn test_new_controller() {
+        let ctrl = LedController::new();
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_default_equals_new() {
+        let default = LedController::default();
+        let new = LedController::new();
+        assert_eq!(default.delay_ms(), new.delay_ms());
+    }
+
+    #[test]
+    fn test_toggle_off_to_on() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.toggle(), LedState::On);
+    }
+
+    #[test]
+    fn test_toggle_on_to_off() {
+        let mut ctrl = LedController::new();
+        ctrl.toggle();
+        assert_eq!(ctrl.toggle(), LedState::Off);
+    }
+
+    #[test]
+    fn test_multiple_toggles() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.toggle(), LedState::On);
+        assert_eq!(ctrl.toggle(), LedState::Off);
+        assert_eq!(ctrl.toggle(), LedState::On);
+        assert_eq!(ctrl.toggle(), LedState::Off);
+    }
+
+    #[test]
+    fn test_initial_state_off() {
+        let ctrl = LedController::new();
+        let expected = LedController {
+            state: LedState::Off,
+            delay_ms: BLINK_DELAY_MS,
+            toggle_count: 0,
+            player: PatternPlayer::new(Pattern::Steady),
+            queued_pattern: None,
+            duty: DEFAULT_DUTY_PERCENT,
+            intensity: DEFAULT_INTENSITY,
+            polarity: Polarity::ActiveHigh,
+            phase_elapsed_ms: 0,
+            frozen: false,
+            stall_ms: 0,
+            warmup_steps: u16::from(WARMUP_BLINKS) * 2,
+            speed_percent: DEFAULT_SPEED_PERCENT,
+            transition: None,
+            min_ms: MIN_BLINK_DELAY_MS,
+            max_ms: MAX_BLINK_DELAY_MS,
+            max_on_ms: MAX_ON_MS,
+            failsafe_tripped: false,
+            held_on_ms: 0,
+            enabled: true,
+            on_subperceptible: SubPerceptualMode::ClampUp,
+            latch: None,
+            identify: None,
+            stats: BlinkStats::new(),
+            disable_ramp_ms: DISABLE_RAMP_MS,
+            ramp_down_ms: None,
+            off_duty: DEFAULT_OFF_DUTY_PERCENT,
+            watchdog_timeout_ms: WATCHDOG_TIMEOUT_MS,
+            locked: false,
+            skip_first_toggle: SKIP_FIRST_TOGGLE,
+            settle_us: SETTLE_US,
+        };
+        assert_eq!(ctrl, expected);
+    }
+
+    #[test]
+    fn test_set_state_forces_without_counting() {
+        let mut ctrl = LedController::new();
+        assert!(ctrl.set_state(LedState::On, false));
+        assert!(ctrl.in_on_phase());
+        assert!(ctrl.set_state(LedState::On, false));
+        assert!(!ctrl.set_state(LedState::Off, false));
+        assert!(!ctrl.in_on_phase());
+        assert_eq!(ctrl.toggle_count(), 0);
+    }
+
+    #[test]
+    fn test_set_state_counts_changes_when_asked() {
+        let mut ctrl = LedController::new();
+        ctrl.set_state(LedState::On, true);
+        ctrl.set_state(LedState::On, true);
+        ctrl.set_state(LedState::Off, true);
+        assert_eq!(ctrl.toggle_count(), 2);
+    }
+
+    #[test]
+    fn test_set_state_level_respects_polarity() {
+        let mut ctrl = LedController::new();
+        ctrl.apply_profile(&crate::profile::BlinkProfile {
+            active_low: true,
+            ..crate::profile::PROFILE_DEFAULT
+        });
+        assert!(!ctrl.set_state(LedState::On, false));
+        assert!(ctrl.set_state(LedState::Off, false));
+    }
+
+    #[test]
+    fn test_blink_then_latch_holds_on() {
+        use crate::pattern::BlinkPattern;
+        let mut ctrl = LedController::new();
+        ctrl.toggle();
+        ctrl.blink_then_latch(2);
+        let states: Vec<LedState> = (0..4).map(|_| ctrl.next_step().0).collect();
+        assert_eq!(
+            states,
+            [LedState::On, LedState::Off, LedState::On, LedState::Off]
+        );
+        for _ in 0..5 {
+            assert_eq!(ctrl.next_step().0, LedState::On);
+        }
+        assert!(ctrl.is_latched());
+    }
+
+    #[test]
+    fn test_clear_resumes_blinking() {
+        let mut ctrl = LedController::new();
+        ctrl.blink_then_latch(0);
+        assert_eq!(ctrl.tick().state, LedState::On);
+        ctrl.clear();
+        assert!(!ctrl.is_latched());
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert_eq!(ctrl.tick().state, LedState::On);
+    }
+
+    #[test]
+    fn test_toggle_parity_matches_repeated_toggles() {
+        for start in [LedState::Off, LedState::On] {
+            let mut ctrl = LedController::new();
+            ctrl.set_state(start, false);
+            for n in 1..=4096u64 {
+                ctrl.toggle();
+                assert_eq!(ctrl.in_on_phase(), toggle_parity(start, n) == LedState::On);
+                assert_eq!(ctrl.toggle_count(), n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_toggle_n_even_returns_to_start() {
+        for n in (0..=4000u64).step_by(2) {
+            let mut ctrl = LedController::new();
+            assert_eq!(ctrl.toggle_n(n), LedState::Off);
+            assert_eq!(ctrl.toggle_count(), n);
+        }
+    }
+
+    #[test]
+    fn test_toggle_n_odd_flips() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.toggle_n(3001), LedState::On);
+        assert_eq!(ctrl.toggle_count(), 3001);
+    }
+
+    #[test]
+    fn test_toggle_count_increments() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.toggle_count(), 0);
+        ctrl.toggle();
+        ctrl.toggle();
+        ctrl.toggle();
+        assert_eq!(ctrl.toggle_count(), 3);
+    }
+
+    #[test]
+    fn test_startup_delay_default() {
+        let ctrl = LedController::new();
+        assert_eq!(ctrl.startup_delay_ms(), STARTUP_DELAY_MS);
+    }
+
+    #[test]
+    fn test_clamp_startup_delay_within_range() {
+        assert_eq!(clamp_startup_delay(0), 0);
+        assert_eq!(clamp_startup_delay(250), 250);
+        assert_eq!(
+            clamp_startup_delay(MAX_STARTUP_DELAY_MS),
+            MAX_STARTUP_DELAY_MS
+        );
+    }
+
+    #[test]
+    fn test_clamp_startup_delay_above_max() {
+        assert_eq!(
+            clamp_startup_delay(MAX_STARTUP_DELAY_MS + 1),
+            MAX_STARTUP_DELAY_MS
+        );
+        assert_eq!(clamp_startup_delay(u64::MAX), MAX_STARTUP_DELAY_MS);
+    }
+
+    #[test]
+    fn test_skip_first_toggle_is_no_op() {
+        let mut ctrl = LedController::new();
+        ctrl.set_skip_first_toggle(true);
+        assert_eq!(ctrl.toggle(), LedState::Off);
+        assert_eq!(ctrl.toggle_count(), 0);
+        assert_eq!(ctrl.toggle(), LedState::On);
+        assert_eq!(ctrl.toggle_count(), 1);
+        assert_eq!(ctrl.toggle(), LedState::Off);
+    }
+
+    #[test]
+    fn test_skip_first_toggle_cleared_toggles_normally() {
+        let mut ctrl = LedController::new();
+        ctrl.set_skip_first_toggle(true);
+        ctrl.set_skip_first_toggle(false);
+        assert_eq!(ctrl.toggle(), LedState::On);
+        assert_eq!(ctrl.toggle_count(), 1);
+    }
+
+    #[test]
+    fn test_toggle_n_consumes_skip_first_toggle() {
+        let mut ctrl = LedController::new();
+        ctrl.set_skip_first_toggle(true);
+        assert_eq!(ctrl.toggle_n(0), LedState::Off);
+        assert_eq!(ctrl.toggle_n(1), LedState::Off);
+        assert_eq!(ctrl.toggle_count(), 0);
+        assert_eq!(ctrl.toggle_n(1), LedState::On);
+        let mut ctrl = LedController::new();
+        ctrl.set_skip_first_toggle(true);
+        assert_eq!(ctrl.toggle_n(4), LedState::On);
+        assert_eq!(ctrl.toggle_count(), 3);
+    }
+
+    #[test]
+    fn test_cycles_after_even_toggles() {
+        let mut ctrl = LedController::new();
+        for _ in 0..6 {
+            ctrl.toggle();
+        }
+        assert_eq!(ctrl.cycles_completed(), 3);
+        assert!(!ctrl.in_on_phase());
+    }
+
+    #[test]
+    fn test_cycles_after_odd_toggles() {
+        let mut ctrl = LedController::new();
+        for _ in 0..7 {
+            ctrl.toggle();
+        }
+        assert_eq!(ctrl.cycles_completed(), 3);
+        assert!(ctrl.in_on_phase());
+    }
+
+    #[test]
+    fn test_advance_toggles_after_delay() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.advance(BLINK_DELAY_MS - 1), None);
+        assert_eq!(ctrl.advance(1), Some(LedState::On));
+        assert_eq!(ctrl.phase_elapsed_ms(), 0);
+    }
+
+    #[test]
+    fn test_advance_carries_excess() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.advance(BLINK_DELAY_MS + 30), Some(LedState::On));
+        assert_eq!(ctrl.phase_elapsed_ms(), 30);
+    }
+
+    #[test]
+    fn test_freeze_thaw_round_trip() {
+        let mut ctrl = LedController::new();
+        ctrl.advance(BLINK_DELAY_MS);
+        ctrl.advance(120);
+        let frozen = ctrl.freeze();
+        assert_eq!(
+            frozen,
+            FrozenState {
+                state: LedState::On,
+                phase_elapsed_ms: 120,
+            }
+        );
+        assert!(ctrl.is_frozen());
+        ctrl.thaw(frozen);
+        assert!(!ctrl.is_frozen());
+        assert_eq!(ctrl.phase_elapsed_ms(), 120);
+        assert!(ctrl.in_on_phase());
+    }
+
+    #[test]
+    fn test_frozen_ignores_advance() {
+        let mut ctrl = LedController::new();
+        ctrl.advance(200);
+        let frozen = ctrl.freeze();
+        assert_eq!(ctrl.advance(10 * BLINK_DELAY_MS), None);
+        ctrl.thaw(frozen);
+        assert_eq!(ctrl.advance(BLINK_DELAY_MS - 201), None);
+        assert_eq!(ctrl.advance(1), Some(LedState::On));
+    }
+
+    #[test]
+    fn test_frozen_tick_holds_state() {
+        let mut ctrl = LedController::new();
+        ctrl.tick();
+        let frozen = ctrl.freeze();
+        for _ in 0..3 {
+            let tick = ctrl.tick();
+            assert_eq!(tick.state, LedState::On);
+            assert_eq!(tick.delay_ms, BLINK_DELAY_MS);
+        }
+        assert_eq!(ctrl.toggle_count(), 1);
+        ctrl.thaw(frozen);
+        assert_eq!(ctrl.tick().state, LedState::Off);
+    }
+
+    #[test]
+    fn test_inhibit_during_resumes_timing() {
+        let clock = core::cell::Cell::new(1000u64);
+        let mut ctrl = LedController::new();
+        ctrl.advance(200);
+        let result = ctrl.inhibit_during(
+            || clock.get(),
+            || {
+                clock.set(clock.get() + 5 * BLINK_DELAY_MS);
+                42
+            },
+        );
+        assert_eq!(result, 42);
+        assert_eq!(ctrl.advance(5 * BLINK_DELAY_MS), None);
+        assert_eq!(ctrl.phase_elapsed_ms(), 200);
+        assert_eq!(ctrl.raw_state(), LedState::Off);
+        assert_eq!(ctrl.advance(BLINK_DELAY_MS - 201), None);
+        assert_eq!(ctrl.advance(1), Some(LedState::On));
+    }
+
+    #[test]
+    fn test_inhibit_during_stall_spans_advances() {
+        let clock = core::cell::Cell::new(0u64);
+        let mut ctrl = LedController::new();
+        ctrl.inhibit_during(|| clock.get(), || clock.set(700));
+        assert_eq!(ctrl.advance(300), None);
+        assert_eq!(ctrl.phase_elapsed_ms(), 0);
+        assert_eq!(ctrl.advance(500), None);
+        assert_eq!(ctrl.phase_elapsed_ms(), 100);
+        assert_eq!(ctrl.advance(BLINK_DELAY_MS - 100), Some(LedState::On));
+    }
+
+    #[test]
+    fn test_transition_interpolates_monotonically() {
+        let mut ctrl = LedController::new();
+        ctrl.transition_to(100, 400);
+        let mut last = ctrl.delay_ms();
+        for _ in 0..4 {
+            ctrl.advance(100);
+            assert!(ctrl.delay_ms() <= last);
+            last = ctrl.delay_ms();
+        }
+        assert_eq!(ctrl.delay_ms(), 100);
+        assert!(!ctrl.in_transition());
+    }
+
+    #[test]
+    fn test_transition_midpoint_and_exact_end() {
+        let mut ctrl = LedController::new();
+        ctrl.transition_to(1500, 1000);
+        ctrl.advance(500);
+        assert_eq!(ctrl.delay_ms(), 1000);
+        assert!(ctrl.in_transition());
+        ctrl.advance(700);
+        assert_eq!(ctrl.delay_ms(), 1500);
+        assert!(!ctrl.in_transition());
+    }
+
+    #[test]
+    fn test_transition_large_inputs_do_not_overflow() {
+        let rising = RateTransition {
+            from_ms: 0,
+            target_ms: u64::MAX,
+            over_ms: u64::MAX,
+            elapsed_ms: u64::MAX / 2,
+        };
+        assert_eq!(rising.delay_ms(), u64::MAX / 2);
+        let falling = RateTransition {
+            from_ms: u64::MAX,
+            target_ms: 1,
+            over_ms: 1 << 40,
+            elapsed_ms: 1 << 39,
+        };
+        assert_eq!(falling.delay_ms(), u64::MAX - (u64::MAX - 1) / 2);
+    }
+
+    #[test]
+    fn test_transition_zero_duration_immediate() {
+        let mut ctrl = LedController::new();
+        ctrl.transition_to(250, 0);
+        assert_eq!(ctrl.delay_ms(), 250);
+        assert!(!ctrl.in_transition());
+    }
+
+    #[test]
+    fn test_failsafe_forces_off_in_advance() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(1000);
+        ctrl.set_max_on_ms(300);
+        assert_eq!(ctrl.advance(1000), Some(LedState::On));
+        assert!(!ctrl.failsafe_tripped());
+        assert_eq!(ctrl.advance(299), None);
+        assert_eq!(ctrl.advance(1), Some(LedState::Off));
+        assert!(ctrl.failsafe_tripped());
+    }
+
+    #[test]
+    fn test_failsafe_caps_tick_on_phase() {
+        let mut ctrl = LedController::new();
+        ctrl.set_max_on_ms(200);
+        let on = ctrl.tick();
+        assert_eq!((on.state, on.delay_ms), (LedState::On, 200));
+        assert!(ctrl.failsafe_tripped());
+        let off = ctrl.tick();
+        assert_eq!((off.state, off.delay_ms), (LedState::Off, BLINK_DELAY_MS));
+        ctrl.clear_failsafe();
+        assert!(!ctrl.failsafe_tripped());
+    }
+
+    #[test]
+    fn test_failsafe_forces_latched_led_off() {
+        let mut ctrl = LedController::new();
+        ctrl.set_max_on_ms(1200);
+        ctrl.blink_then_latch(1);
+        assert_eq!(ctrl.tick().state, LedState::On);
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        let holds: Vec<_> = (0..3).map(|_| ctrl.tick()).collect();
+        assert!(holds.iter().all(|tick| tick.state == LedState::On));
+        assert_eq!(holds.iter().map(|tick| tick.delay_ms).sum::<u64>(), 1200);
+        assert!(!ctrl.failsafe_tripped());
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert!(ctrl.failsafe_tripped());
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        ctrl.clear_failsafe();
+        assert_eq!(ctrl.tick().state, LedState::On);
+    }
+
+    #[test]
+    fn test_failsafe_forces_solid_on_off() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(MIN_BLINK_DELAY_MS);
+        ctrl.set_subperceptual_mode(SubPerceptualMode::SolidOn);
+        ctrl.set_max_on_ms(PERCEPTIBLE_FLOOR_MS);
+        let tick = ctrl.tick();
+        assert_eq!(
+            (tick.state, tick.delay_ms),
+            (LedState::On, PERCEPTIBLE_FLOOR_MS)
+        );
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert!(ctrl.failsafe_tripped());
+    }
+
+    #[test]
+    fn test_failsafe_not_tripped_within_limit() {
+        let mut ctrl = LedController::new();
+        ctrl.set_max_on_ms(BLINK_DELAY_MS);
+        ctrl.tick();
+        ctrl.advance(BLINK_DELAY_MS);
+        assert!(!ctrl.failsafe_tripped());
+    }
+
+    #[test]
+    fn test_disabled_holds_off() {
+        let mut ctrl = LedController::new();
+        ctrl.tick();
+        ctrl.set_enabled(false);
+        assert!(!ctrl.is_enabled());
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert_eq!(ctrl.advance(BLINK_DELAY_MS), None);
+        assert_eq!(ctrl.toggle_count(), 1);
+    }
+
+    #[test]
+    fn test_blinks_per_minute_500ms() {
+        assert_eq!(blinks_per_minute(500), 60);
+    }
+
+    #[test]
+    fn test_blinks_per_minute_small_delay_guarded() {
+        assert_eq!(blinks_per_minute(0), 30_000);
+        assert_eq!(blinks_per_minute(1), 30_000);
+        assert_eq!(blinks_per_minute(u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_controller_blinks_per_minute() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(500);
+        assert_eq!(ctrl.blinks_per_minute(), 60);
+    }
+
+    #[test]
+    fn test_identify_overrides_pattern() {
+        let mut ctrl = LedController::new();
+        ctrl.set_pattern(Pattern::Heartbeat);
+        ctrl.identify(2);
+        assert!(ctrl.is_identifying());
+        assert_eq!(ctrl.pattern(), Pattern::Identify);
+        ctrl.advance(1999);
+        assert_eq!(ctrl.pattern(), Pattern::Identify);
+    }
+
+    #[test]
+    fn test_identify_restores_after_duration() {
+        let mut ctrl = LedController::new();
+        ctrl.set_pattern(Pattern::Heartbeat);
+        ctrl.identify(2);
+        ctrl.advance(1500);
+        ctrl.advance(500);
+        assert!(!ctrl.is_identifying());
+        assert_eq!(ctrl.pattern(), Pattern::Heartbeat);
+    }
+
+    #[test]
+    fn test_identify_retrigger_keeps_original_pattern() {
+        let mut ctrl = LedController::new();
+        ctrl.set_pattern(Pattern::Sos);
+        ctrl.identify(1);
+        ctrl.identify(1);
+        ctrl.advance(1000);
+        assert_eq!(ctrl.pattern(), Pattern::Sos);
+    }
+
+    #[test]
+    fn test_set_pattern_while_identifying_applies_after() {
+        let mut ctrl = LedController::new();
+        ctrl.identify(1);
+        ctrl.set_pattern(Pattern::Heartbeat);
+        assert_eq!(ctrl.pattern(), Pattern::Identify);
+        assert_eq!(ctrl.selected_pattern(), Pattern::Heartbeat);
+        ctrl.advance(1000);
+        assert_eq!(ctrl.pattern(), Pattern::Heartbeat);
+    }
+
+    #[test]
+    fn test_identify_plays_through_tick_then_restores() {
+        let mut ctrl = LedController::new();
+        ctrl.identify(1);
+        for &(state, hold_ms) in Pattern::Identify.steps() {
+            assert!(ctrl.is_identifying());
+            let tick = ctrl.tick();
+            assert_eq!((tick.state, tick.delay_ms), (state, hold_ms));
+        }
+        assert!(!ctrl.is_identifying());
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+        let tick = ctrl.tick();
+        assert_eq!((tick.state, tick.delay_ms), (LedState::On, BLINK_DELAY_MS));
+    }
+
+    #[test]
+    fn test_tick_plays_selected_pattern() {
+        let mut ctrl = LedController::new();
+        ctrl.set_pattern(Pattern::Heartbeat);
+        let steps = Pattern::Heartbeat.steps();
+        for &(state, hold_ms) in steps.iter().chain(steps) {
+            let tick = ctrl.tick();
+            assert_eq!((tick.state, tick.delay_ms), (state, hold_ms));
+        }
+        assert_eq!(ctrl.toggle_count(), 2 * steps.len() as u64);
+    }
+
+    #[test]
+    fn test_queued_pattern_waits_for_off_to_on_boundary() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.tick().state, LedState::On);
+        ctrl.queue_pattern(Pattern::Heartbeat);
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+        assert_eq!(ctrl.selected_pattern(), Pattern::Heartbeat);
+        let tick = ctrl.tick();
+        assert_eq!((tick.state, tick.delay_ms), (LedState::Off, BLINK_DELAY_MS));
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+        let tick = ctrl.tick();
+        assert_eq!((tick.state, tick.delay_ms), Pattern::Heartbeat.steps()[0]);
+        assert_eq!(ctrl.pattern(), Pattern::Heartbeat);
+    }
+
+    #[test]
+    fn test_queued_pattern_finishes_current_pattern_phase() {
+        let mut ctrl = LedController::new();
+        ctrl.set_pattern(Pattern::Heartbeat);
+        let steps = Pattern::Heartbeat.steps();
+        ctrl.tick();
+        ctrl.tick();
+        ctrl.tick();
+        ctrl.queue_pattern(Pattern::Sos);
+        let tick = ctrl.tick();
+        assert_eq!((tick.state, tick.delay_ms), steps[3]);
+        let tick = ctrl.tick();
+        assert_eq!((tick.state, tick.delay_ms), Pattern::Sos.steps()[0]);
+    }
+
+    #[test]
+    fn test_requeue_replaces_pending_pattern() {
+        let mut ctrl = LedController::new();
+        ctrl.tick();
+        ctrl.queue_pattern(Pattern::Heartbeat);
+        ctrl.queue_pattern(Pattern::Sos);
+        ctrl.tick();
+        ctrl.tick();
+        assert_eq!(ctrl.pattern(), Pattern::Sos);
+    }
+
+    #[test]
+    fn test_queue_active_pattern_cancels_pending() {
+        let mut ctrl = LedController::new();
+        ctrl.tick();
+        ctrl.queue_pattern(Pattern::Heartbeat);
+        ctrl.queue_pattern(Pattern::Steady);
+        assert_eq!(ctrl.selected_pattern(), Pattern::Steady);
+        ctrl.tick();
+        ctrl.tick();
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+    }
+
+    #[test]
+    fn test_identify_zero_seconds_ignored() {
+        let mut ctrl = LedController::new();
+        ctrl.identify(0);
+        assert!(!ctrl.is_identifying());
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+    }
+
+    #[test]
+    fn test_tick_and_advance_record_stats() {
+        let mut ctrl = LedController::new();
+        ctrl.tick();
+        assert_eq!(ctrl.stats().on_ms(), BLINK_DELAY_MS);
+        ctrl.advance(BLINK_DELAY_MS);
+        ctrl.advance(10);
+        assert_eq!(ctrl.stats().on_ms(), 2 * BLINK_DELAY_MS);
+        assert_eq!(ctrl.stats().off_ms(), 10);
+    }
+
+    #[test]
+    fn test_energy_proxy_scales_with_on_time_and_duty() {
+        let profile = |duty| crate::profile::BlinkProfile {
+            duty,
+            ..crate::profile::PROFILE_DEFAULT
+        };
+        let mut half = LedController::new();
+        half.apply_profile(&profile(50));
+        half.set_state(LedState::On, false);
+        half.advance(1000);
+        assert_eq!(half.energy_proxy(), 50_000);
+        let mut full = LedController::new();
+        full.apply_profile(&profile(100));
+        full.set_state(LedState::On, false);
+        full.advance(1000);
+        assert_eq!(full.energy_proxy(), 2 * half.energy_proxy());
+        full.advance(1000);
+        full.advance(1000);
+        assert_eq!(full.energy_proxy(), 4 * half.energy_proxy());
+    }
+
+    #[test]
+    fn test_energy_proxy_zero_while_off() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.energy_proxy(), 0);
+        ctrl.advance(400);
+        assert_eq!(ctrl.energy_proxy(), 0);
+    }
+
+    #[test]
+    fn test_calibrate_long_periods_give_positive_correction() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(500);
+        assert_eq!(ctrl.calibrate(&[503, 503, 503]), 3);
+        assert_eq!(ctrl.calibrate(&[502, 504, 506, 508]), 5);
+    }
+
+    #[test]
+    fn test_calibrate_exact_or_short_gives_zero() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(500);
+        assert_eq!(ctrl.calibrate(&[500, 500]), 0);
+        assert_eq!(ctrl.calibrate(&[490, 495]), 0);
+        assert_eq!(ctrl.calibrate(&[]), 0);
+    }
+
+    #[test]
+    fn test_disable_ramp_decreases_duty_to_zero() {
+        let mut ctrl = LedController::new();
+        ctrl.set_disable_ramp_ms(100);
+        ctrl.set_enabled(false);
+        assert!(ctrl.is_ramping_down());
+        assert!(ctrl.is_enabled());
+        let mut last = ctrl.output_duty();
+        assert_eq!(last, DEFAULT_DUTY_PERCENT);
+        for _ in 0..9 {
+            ctrl.advance(10);
+            let duty = ctrl.output_duty();
+            assert!(duty < last);
+            last = duty;
+        }
+        ctrl.advance(10);
+        assert_eq!(ctrl.output_duty(), 0);
+        assert!(!ctrl.is_ramping_down());
+        assert!(!ctrl.is_enabled());
+        assert_eq!(ctrl.tick().state, LedState::Off);
+    }
+
+    #[test]
+    fn test_disable_ramp_runs_through_tick() {
+        let mut ctrl = LedController::new();
+        ctrl.set_disable_ramp_ms(100);
+        ctrl.tick();
+        ctrl.set_enabled(false);
+        let mut last = ctrl.duty_for_phase(1000);
+        assert_eq!(last, 10 * u16::from(DEFAULT_DUTY_PERCENT));
+        for _ in 0..10 {
+            let tick = ctrl.tick();
+            assert_eq!(tick.state, LedState::On);
+            assert_eq!(tick.delay_ms, DISABLE_RAMP_STEP_MS);
+            let duty = ctrl.duty_for_phase(1000);
+            assert!(duty < last);
+            last = duty;
+        }
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Pwm, 1000, false),
+            BackendOutput::Duty(0)
+        );
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert!(!ctrl.is_enabled());
+    }
+
+    #[test]
+    fn test_enable_cancels_disable_ramp() {
+        let mut ctrl = LedController::new();
+        ctrl.set_disable_ramp_ms(100);
+        ctrl.set_enabled(false);
+        ctrl.advance(50);
+        ctrl.set_enabled(true);
+        assert!(!ctrl.is_ramping_down());
+        assert_eq!(ctrl.output_duty(), DEFAULT_DUTY_PERCENT);
+    }
+
+    #[test]
+    fn test_zero_disable_ramp_cuts_off_immediately() {
+        let mut ctrl = LedController::new();
+        ctrl.set_disable_ramp_ms(0);
+        ctrl.set_enabled(false);
+        assert!(!ctrl.is_ramping_down());
+        assert_eq!(ctrl.output_duty(), 0);
+    }
+
+    #[test]
+    fn test_duty_for_phase_glow_levels() {
+        let mut ctrl = LedController::new();
+        ctrl.set_duty(80);
+        ctrl.set_off_duty(10);
+        assert_eq!(ctrl.duty_for_phase(1000), 100);
+        ctrl.toggle();
+        assert_eq!(ctrl.duty_for_phase(1000), 800);
+        ctrl.toggle();
+        assert_eq!(ctrl.duty_for_phase(1000), 100);
+    }
+
+    #[test]
+    fn test_duty_for_phase_defaults_and_limits() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.duty_for_phase(u16::MAX), 0);
+        ctrl.toggle();
+        assert_eq!(ctrl.duty_for_phase(u16::MAX), u16::MAX);
+        ctrl.set_off_duty(200);
+        assert_eq!(ctrl.off_duty(), 100);
+        ctrl.set_enabled(false);
+        assert_eq!(ctrl.duty_for_phase(u16::MAX), 0);
+    }
+
+    #[test]
+    fn test_output_for_digital_follows_state() {
+        let mut ctrl = LedController::new();
+        ctrl.set_duty(40);
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Digital, 1000, false),
+            BackendOutput::Level(false)
+        );
+        ctrl.toggle();
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Digital, 1000, false),
+            BackendOutput::Level(true)
+        );
+    }
+
+    #[test]
+    fn test_output_for_pwm_follows_duty() {
+        let mut ctrl = LedController::new();
+        ctrl.set_duty(40);
+        ctrl.set_off_duty(5);
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Pwm, 1000, false),
+            BackendOutput::Duty(50)
+        );
+        ctrl.toggle();
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Pwm, 1000, false),
+            BackendOutput::Duty(400)
+        );
+    }
+
+    #[test]
+    fn test_output_for_invert_swaps_output() {
+        let mut ctrl = LedController::new();
+        ctrl.set_duty(40);
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Digital, 1000, true),
+            BackendOutput::Level(true)
+        );
+        ctrl.toggle();
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Pwm, 1000, true),
+            BackendOutput::Duty(duty_to_compare(40, 1000, !DUTY_INVERT))
+        );
+    }
+
+    #[test]
+    fn test_output_for_pwm_uses_duty_to_compare() {
+        let mut ctrl = LedController::new();
+        ctrl.set_duty(33);
+        ctrl.toggle();
+        assert_eq!(
+            ctrl.output_for(OutputBackend::Pwm, 4095, false),
+            BackendOutput::Duty(duty_to_compare(33, 4095, DUTY_INVERT))
+        );
+    }
+
+    #[test]
+    fn test_set_delay_reports_change() {
+        let mut ctrl = LedController::new();
+        assert!(!ctrl.set_delay(BLINK_DELAY_MS));
+        assert!(ctrl.set_delay(BLINK_DELAY_MS + 1));
+        assert!(!ctrl.set_delay(BLINK_DELAY_MS + 1));
+    }
+
+    #[test]
+    fn test_set_delay_same_clamped_value_is_no_change() {
+        let mut ctrl = LedController::new();
+        assert!(ctrl.set_delay(MAX_BLINK_DELAY_MS + 1));
+        assert!(!ctrl.set_delay(MAX_BLINK_DELAY_MS));
+        assert!(!ctrl.set_delay(u64::MAX));
+    }
+
+    #[test]
+    fn test_set_duty_reports_change() {
+        let mut ctrl = LedController::new();
+        assert!(!ctrl.set_duty(DEFAULT_DUTY_PERCENT));
+        assert!(ctrl.set_duty(40));
+        assert!(!ctrl.set_duty(40));
+        assert!(ctrl.set_duty(150));
+        assert!(!ctrl.set_duty(100));
+    }
+
+    #[test]
+    fn test_watchdog_safe_bound() {
+        assert!(watchdog_safe(1000, 2000));
+        assert!(!watchdog_safe(1001, 2000));
+        assert!(watchdog_safe(u64::MAX, 0));
+    }
+
+    #[test]
+    fn test_set_delay_checked_within_bound() {
+        let mut ctrl = LedController::new();
+        ctrl.set_watchdog_timeout_ms(2000);
+        assert_eq!(ctrl.set_delay_checked(1000), Ok(true));
+        assert_eq!(ctrl.delay_ms(), 1000);
+    }
+
+    #[test]
+    fn test_set_delay_checked_beyond_bound_rejected() {
+        let mut ctrl = LedController::new();
+        ctrl.set_watchdog_timeout_ms(2000);
+        assert_eq!(
+            ctrl.set_delay_checked(1500),
+            Err(WatchdogError::ExceedsWatchdog)
+        );
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_set_delay_checked_uses_speed_factor() {
+        let mut ctrl = LedController::new();
+        ctrl.set_watchdog_timeout_ms(2000);
+        ctrl.set_speed_factor(50);
+        assert_eq!(ctrl.set_delay_checked(1500), Ok(true));
+    }
+
+    #[test]
+    fn test_debug_dump_exact() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(500);
+        ctrl.set_duty(50);
+        ctrl.toggle_n(12);
+        ctrl.toggle();
+        let mut buf = [0u8; 64];
+        let n = ctrl.debug_dump(&mut buf);
+        assert_eq!(&buf[..n], b"state=On delay=500 count=13 duty=50");
+    }
+
+    #[test]
+    fn test_debug_dump_truncates() {
+        let ctrl = LedController::new();
+        let mut buf = [0u8; 8];
+        assert_eq!(ctrl.debug_dump(&mut buf), 8);
+        assert_eq!(&buf, b"state=Of");
+    }
+
+    // ==================== White-Box Tests ====================
+
+    #[test]
+    fn test_raw_phase_counter_advances_and_carries() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(100);
+        ctrl.advance(30);
+        assert_eq!(ctrl.raw_phase_elapsed_ms(), 30);
+        ctrl.advance(30);
+        assert_eq!(ctrl.raw_phase_elapsed_ms(), 60);
+        ctrl.advance(55);
+        assert_eq!(ctrl.raw_phase_elapsed_ms(), 15);
+        assert_eq!(ctrl.raw_state(), LedState::On);
+        assert_eq!(ctrl.raw_toggle_count(), 1);
+    }
+
+    #[test]
+    fn test_raw_phase_counter_holds_while_frozen() {
+        let mut ctrl = LedController::new();
+        ctrl.advance(40);
+        let frozen = ctrl.freeze();
+        ctrl.advance(40);
+        assert_eq!(ctrl.raw_phase_elapsed_ms(), 40);
+        ctrl.thaw(frozen);
+        ctrl.advance(40);
+        assert_eq!(ctrl.raw_phase_elapsed_ms(), 80);
+    }
+
+    #[test]
+    fn test_raw_fields_track_internal_transitions() {
+        let mut ctrl = LedController::new();
+        ctrl.set_speed_factor(50);
+        ctrl.set_delay(400);
+        assert_eq!(ctrl.raw_delay_ms(), 400);
+        ctrl.set_warmup_blinks(1);
+        assert_eq!(ctrl.raw_warmup_steps(), 2);
+        ctrl.tick();
+        assert_eq!(ctrl.raw_warmup_steps(), 1);
+        ctrl.blink_then_latch(1);
+        assert_eq!(ctrl.raw_latch(), Some(2));
+        ctrl.set_disable_ramp_ms(100);
+        ctrl.set_enabled(false);
+        ctrl.advance(25);
+        assert_eq!(ctrl.raw_ramp_down_ms(), Some(25));
+    }
+
+    // ==================== Status Word Tests ====================
+
+    #[test]
+    fn test_status_word_default_layout() {
+        let ctrl = LedController::new();
+        let expected = 1 | (u32::from(DEFAULT_DUTY_PERCENT) << 8) | (50 << 16);
+        assert_eq!(ctrl.status_word(), expected);
+    }
+
+    #[test]
+    fn test_status_word_round_trip() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(1230);
+        ctrl.toggle();
+        let restored = LedController::from_status_word(ctrl.status_word());
+        assert_eq!(restored.status_word(), ctrl.status_word());
+        assert_eq!(restored.delay_ms(), 1230);
+        assert!(restored.in_on_phase());
+
+        ctrl.set_enabled(false);
+        ctrl.freeze();
+        let restored = LedController::from_status_word(ctrl.status_word());
+        assert!(!restored.is_enabled());
+        assert!(restored.is_frozen());
+        assert_eq!(restored.status_word(), ctrl.status_word());
+    }
+
+    #[test]
+    fn test_from_status_word_clamps() {
+        let restored = LedController::from_status_word(0xFFFF_FF00);
+        assert_eq!(restored.duty(), 100);
+        assert_eq!(restored.delay_ms(), MAX_BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_settle_passthrough() {
+        let ctrl = LedController::new();
+        assert_eq!(ctrl.settle_us(), SETTLE_US);
+    }
+
+    #[test]
+    fn test_tick_includes_settle() {
+        let mut ctrl = LedController::new();
+        let tick = ctrl.tick();
+        assert_eq!(
+            tick,
+            Tick {
+                state: LedState::On,
+                delay_ms: BLINK_DELAY_MS,
+                settle_us: SETTLE_US,
+            }
+        );
+        assert_eq!(ctrl.tick().state, LedState::Off);
+        assert_eq!(ctrl.toggle_count(), 2);
+    }
+
+    #[test]
+    fn test_tick_carries_configured_settle() {
+        let mut ctrl = LedController::new();
+        ctrl.set_settle_us(250);
+        assert_eq!(ctrl.settle_us(), 250);
+        assert_eq!(ctrl.tick().settle_us, 250);
+        assert_eq!(ctrl.tick().settle_us, 250);
+    }
+
+    #[test]
+    fn test_speed_factor_normal() {
+        let mut ctrl = LedController::new();
+        ctrl.set_speed_factor(100);
+        assert_eq!(ctrl.effective_delay_ms(), BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_speed_factor_half_and_double() {
+        let mut ctrl = LedController::new();
+        ctrl.set_speed_factor(50);
+        assert_eq!(ctrl.effective_delay_ms(), BLINK_DELAY_MS / 2);
+        ctrl.set_speed_factor(200);
+        assert_eq!(ctrl.effective_delay_ms(), BLINK_DELAY_MS * 2);
+        assert_eq!(ctrl.delay_ms(), BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_speed_factor_clamped() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(MAX_BLINK_DELAY_MS);
+        ctrl.set_speed_factor(200);
+        assert_eq!(ctrl.effective_delay_ms(), MAX_BLINK_DELAY_MS);
+        ctrl.set_speed_factor(0);
+        assert_eq!(ctrl.effective_delay_ms(), MIN_BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_tick_uses_effective_delay() {
+        let mut ctrl = LedController::new();
+        ctrl.set_speed_factor(200);
+        assert_eq!(ctrl.tick().delay_ms, BLINK_DELAY_MS * 2);
+    }
+
+    #[test]
+    fn test_warmup_precedes_steady_pattern() {
+        let mut ctrl = LedController::new();
+        ctrl.set_warmup_blinks(2);
+        let steps: Vec<(LedState, u64)> = (0..6)
+            .map(|_| {
+                let tick = ctrl.tick();
+                (tick.state, tick.delay_ms)
+            })
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (LedState::On, WARMUP_DELAY_MS),
+                (LedState::Off, WARMUP_DELAY_MS),
+                (LedState::On, WARMUP_DELAY_MS),
+                (LedState::Off, WARMUP_DELAY_MS),
+                (LedState::On, BLINK_DELAY_MS),
+                (LedState::Off, BLINK_DELAY_MS),
+            ]
+        );
+        assert!(!ctrl.in_warmup());
+    }
+
+    #[test]
+    fn test_default_warmup_from_config() {
+        let ctrl = LedController::new();
+        assert_eq!(ctrl.in_warmup(), WARMUP_BLINKS != 0);
+    }
+
+    #[test]
+    fn test_set_delay_clamps() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(250);
+        assert_eq!(ctrl.delay_ms(), 250);
+        ctrl.set_delay(0);
+        assert_eq!(ctrl.delay_ms(), MIN_BLINK_DELAY_MS);
+        ctrl.set_delay(u64::MAX);
+        assert_eq!(ctrl.delay_ms(), MAX_BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_default_bounds_from_config() {
+        let ctrl = LedController::new();
+        assert_eq!(ctrl.bounds(), (MIN_BLINK_DELAY_MS, MAX_BLINK_DELAY_MS));
+    }
+
+    #[test]
+    fn test_set_bounds_custom() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.set_bounds(100, 2000), Ok(()));
+        assert_eq!(ctrl.bounds(), (100, 2000));
+        assert_eq!(ctrl.clamp(50), 100);
+        assert_eq!(ctrl.clamp(5000), 2000);
+    }
+
+    #[test]
+    fn test_set_bounds_rejects_inverted() {
+        let mut ctrl = LedController::new();
+        assert_eq!(ctrl.set_bounds(2000, 100), Err(BoundsError::Inverted));
+        assert_eq!(ctrl.bounds(), (MIN_BLINK_DELAY_MS, MAX_BLINK_DELAY_MS));
+    }
+
+    #[test]
+    fn test_set_delay_respects_runtime_bounds() {
+        let mut ctrl = LedController::new();
+        ctrl.set_bounds(200, 800).unwrap();
+        ctrl.set_delay(50);
+        assert_eq!(ctrl.delay_ms(), 200);
+        ctrl.set_delay(20000);
+        assert_eq!(ctrl.delay_ms(), 800);
+    }
+
+    #[test]
+    fn test_set_bounds_reclamps_current_delay() {
+        let mut ctrl = LedController::new();
+        ctrl.set_bounds(600, 900).unwrap();
+        assert_eq!(ctrl.delay_ms(), 600);
+    }
+
+    #[test]
+    fn test_delay_for_progress() {
+        assert_eq!(delay_for_progress(0), 1000);
+        assert_eq!(delay_for_progress(50), 525);
+        assert_eq!(delay_for_progress(100), 50);
+        assert_eq!(delay_for_progress(255), 50);
+    }
+
+    #[test]
+    fn test_set_progress_updates_delay() {
+        let mut ctrl = LedController::new();
+        ctrl.set_progress(50);
+        assert_eq!(ctrl.delay_ms(), delay_for_progress(50));
+    }
+
+    #[test]
+    fn test_deadlines_evenly_spaced() {
+        assert_eq!(deadlines_ms::<4>(1000, 500, 0), [1500, 2000, 2500, 3000]);
+    }
+
+    #[test]
+    fn test_deadlines_account_for_phase() {
+        assert_eq!(deadlines_ms::<3>(1000, 500, 200), [1300, 1800, 2300]);
+    }
+
+    #[test]
+    fn test_deadlines_saturate() {
+        assert_eq!(
+            deadlines_ms::<2>(u64::MAX - 1, 500, 0),
+            [u64::MAX, u64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_controller_upcoming_deadlines() {
+        let mut ctrl = LedController::new();
+        ctrl.advance(100);
+        assert_eq!(ctrl.upcoming_deadlines_ms::<3>(0), [400, 900, 1400]);
+    }
+
+    #[test]
+    fn test_perceptible_delay_above_floor() {
+        for mode in [SubPerceptualMode::ClampUp, SubPerceptualMode::SolidOn] {
+            assert_eq!(
+                perceptible_delay(PERCEPTIBLE_FLOOR_MS, mode),
+                Some(PERCEPTIBLE_FLOOR_MS)
+            );
+        }
+    }
+
+    #[test]
+    fn test_subperceptual_clamp_up() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(MIN_BLINK_DELAY_MS);
+        let tick = ctrl.tick();
+        assert_eq!(
+            (tick.state, tick.delay_ms),
+            (LedState::On, PERCEPTIBLE_FLOOR_MS)
+        );
+        assert_eq!(ctrl.tick().state, LedState::Off);
+    }
+
+    #[test]
+    fn test_subperceptual_solid_on() {
+        let mut ctrl = LedController::new();
+        ctrl.set_delay(MIN_BLINK_DELAY_MS);
+        ctrl.set_subperceptual_mode(SubPerceptualMode::SolidOn);
+        assert_eq!(ctrl.tick().state, LedState::On);
+        assert_eq!(ctrl.tick().state, LedState::On);
+        assert_eq!(ctrl.toggle_count(), 0);
+    }
+
+    #[test]
+    fn test_clamp_delay() {
+        assert_eq!(clamp_delay(0), MIN_BLINK_DELAY_MS);
+        assert_eq!(clamp_delay(750), 750);
+        assert_eq!(clamp_delay(u64::MAX), MAX_BLINK_DELAY_MS);
     }
 
     // ==================== Trait Implementation Tests ====================
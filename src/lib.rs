@@ -38,8 +38,55 @@
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: November 28, 2025
-//! UPDATE DATE: December 4, 2025
+//! UPDATE DATE: October 14, 2026
 
 #![cfg_attr(not(test), no_std)]
+pub mod ambient;
+pub mod backend;
+pub mod bang_bang;
+pub mod bank;
+pub mod blink_code;
+pub mod boot;
+pub mod budget;
+pub mod bus_activity;
+pub mod capture;
+pub mod color;
+pub mod color_temp;
+pub mod command;
+pub mod complementary;
 pub mod config;
+pub mod conn;
+pub mod control;
+pub mod deadline;
+pub mod debounce;
+pub mod demo;
+pub mod encoder;
+pub mod event_log;
+pub mod frame;
+pub mod group_flash;
+pub mod guard;
+pub mod history;
 pub mod led;
+pub mod mapped_rate;
+pub mod morse;
+pub mod panic_code;
+pub mod passthrough;
+pub mod pattern;
+pub mod phase_lock;
+pub mod pin;
+pub mod polarity;
+pub mod profile;
+pub mod pwm;
+pub mod raw_blink;
+pub mod recorder;
+pub mod rhythm;
+pub mod runner;
+pub mod rx_blink;
+pub mod schedule;
+pub mod script;
+pub mod shift_register;
+pub mod stats;
+pub mod store;
+pub mod thermal;
+pub mod timer_isr;
+pub mod wave;
@@ -34,47 +34,349 @@
 //!
 //! BRIEF:
 //! Main application entry point for RP2350 GPIO blink driver using Embassy.
-//! Implements async LED blinking on GPIO 16.
+//! Implements async LED blinking on GPIO 16 with an alive LED on GPIO 17.
 //!
 //! AUTHOR: Kevin Thomas
 //! CREATION DATE: November 28, 2025
-//! UPDATE DATE: December 4, 2025
+//! UPDATE DATE: October 14, 2026
 
 #![no_std]
 #![no_main]
 
+#[cfg(all(feature = "backend-embassy-time", feature = "backend-hal-delay"))]
+compile_error!("enable only one of `backend-embassy-time` and `backend-hal-delay`");
+#[cfg(not(any(feature = "backend-embassy-time", feature = "backend-hal-delay")))]
+compile_error!("enable one of `backend-embassy-time` or `backend-hal-delay`");
+
+mod backend;
+mod blink_code;
+mod boot;
+mod command;
 mod config;
+mod control;
+mod debounce;
+mod guard;
 mod led;
+mod panic_code;
+mod passthrough;
+mod pattern;
+mod pin;
+mod polarity;
+mod profile;
+mod pwm;
+mod runner;
+mod script;
+mod stats;
+mod store;
+mod timer_isr;
 
+use backend::{backend_delay, ns_to_cycles};
+use blink_code::version_blink;
+use boot::{boot_blink_pattern, read_boot_reason};
+use config::{CPU_HZ, HEARTBEAT_LED_PIN, INVERT_INPUT_PIN, PASSTHROUGH_INPUT_PIN};
+#[cfg(not(feature = "timer-isr"))]
+use config::{OUTPUT_BACKEND, OUTPUT_MODE, PASSTHROUGH_MODE, PWM_TOP};
+#[cfg(not(feature = "timer-isr"))]
+use control::{drain_commands, INVERT, TOGGLE_COUNT};
+use core::panic::PanicInfo;
+#[cfg(not(feature = "timer-isr"))]
+use core::sync::atomic::Ordering;
+#[cfg(not(feature = "timer-isr"))]
+use debounce::Debouncer;
 use embassy_executor::Spawner;
+use embassy_rp::flash::Flash;
+#[cfg(not(feature = "timer-isr"))]
+use embassy_rp::gpio::{Input, OutputOpenDrain, Pull};
 use embassy_rp::gpio::{Level, Output};
-use embassy_time::Timer;
-use led::{led_state_to_level, LedController};
-use panic_halt as _;
+#[cfg(not(feature = "timer-isr"))]
+use embassy_rp::pwm::{Config as PwmConfig, Pwm};
+use embedded_hal_async::delay::DelayNs;
+use guard::BlinkGuard;
+use led::{LedController, LedState};
+use panic_code::{panic_code_for, panic_pattern_for};
+#[cfg(not(feature = "timer-isr"))]
+use passthrough::{follow_input, Passthrough};
+use pin::LedPin;
+#[cfg(not(feature = "timer-isr"))]
+use pin::{BackendOutput, OutputBackend, OutputMode};
+use profile::alive_controller;
+use runner::{blink_once, blink_step, delay_arg, play_once};
+use store::{ConfigStore, FlashStore};
+
+/// Compile-time check that the configured pins match the ones main claims.
+const _: () = assert!(
+    HEARTBEAT_LED_PIN == 17,
+    "main claims PIN_17 for the heartbeat LED"
+);
+const _: () = assert!(
+    INVERT_INPUT_PIN == 15,
+    "main claims PIN_15 for the invert switch"
+);
+const _: () = assert!(
+    PASSTHROUGH_INPUT_PIN == 14,
+    "main claims PIN_14 for the passthrough input"
+);
 
 /// Main application entry point.
 ///
 /// # Details
 /// Initializes Embassy runtime and runs the main blink loop.
 /// Uses BlinkController for state management.
+/// Plays a reset-reason boot pattern once before blinking.
+/// Constructs the pin for the configured output backend and mode.
+/// Spawns the alive heartbeat task on its own LED.
+/// Spawns the invert task on the inversion switch input.
+/// Restores the persisted configuration, including the last-used
+/// pattern, before blinking, and hands the store to the blink loop
+/// so pattern changes are saved.
+/// In passthrough mode spawns the passthrough task on the main LED
+/// instead of blinking.
+/// With the `timer-isr` feature the TIMER1 interrupt blinks instead.
 ///
 /// # Arguments
-/// * `_spawner` - Embassy task spawner (reserved for future async tasks).
+/// * `spawner` - Embassy task spawner.
 ///
 /// # Returns
 /// * `()` - Never returns (infinite loop).
 #[embassy_executor::main]
-async fn main(_spawner: Spawner) {
+async fn main(spawner: Spawner) {
     let p = embassy_rp::init(Default::default());
-    let mut led = Output::new(p.PIN_16, Level::Low);
+    let heartbeat = Output::new(p.PIN_17, Level::Low);
+    spawner.spawn(heartbeat_task(heartbeat).unwrap());
+    #[cfg(not(feature = "timer-isr"))]
+    spawner.spawn(invert_task(Input::new(p.PIN_15, Pull::Down)).unwrap());
     let mut controller = LedController::new();
+    let mut store = FlashStore::new(Flash::new_blocking(p.FLASH));
+    if let Ok(config) = store.load() {
+        controller.apply_profile(&config.to_profile());
+    }
+    #[cfg(feature = "timer-isr")]
+    {
+        let mut led = Output::new(p.PIN_16, Level::Low);
+        boot(&mut led, &controller).await;
+        timer_isr::start(led, controller);
+        core::future::pending::<()>().await;
+    }
+    #[cfg(not(feature = "timer-isr"))]
+    if PASSTHROUGH_MODE {
+        let input = Input::new(p.PIN_14, Pull::Down);
+        let led = Output::new(p.PIN_16, Level::Low);
+        let passthrough = Passthrough::new(controller.polarity());
+        spawner.spawn(passthrough_task(input, led, passthrough).unwrap());
+        core::future::pending::<()>().await;
+    } else {
+        match (OUTPUT_BACKEND, OUTPUT_MODE) {
+            (OutputBackend::Pwm, _) => {
+                let pwm = Pwm::new_output_a(p.PWM_SLICE0, p.PIN_16, PwmConfig::default());
+                pwm_loop(pwm, controller, store).await;
+            }
+            (OutputBackend::Digital, OutputMode::PushPull) => {
+                let mut led = Output::new(p.PIN_16, Level::Low);
+                boot(&mut led, &controller).await;
+                blink_loop(led, controller, store).await;
+            }
+            (OutputBackend::Digital, OutputMode::OpenDrain) => {
+                let mut led = OutputOpenDrain::new(p.PIN_16, Level::Low);
+                boot(&mut led, &controller).await;
+                blink_loop(led, controller, store).await;
+            }
+        }
+    }
+}
+
+/// Alive heartbeat task.
+///
+/// # Details
+/// Blinks the dedicated status LED at a slow 1 Hz forever with its
+/// own controller, proving the executor is still running. Each pass
+/// of the loop is one blink_once cycle.
+///
+/// # Arguments
+/// * `led` - Heartbeat LED output pin
+#[embassy_executor::task]
+async fn heartbeat_task(led: Output<'static>) -> ! {
+    let controller = alive_controller();
+    let mut led = BlinkGuard::new(led, controller.polarity());
+    let mut delay = backend_delay();
+    let mut set = |on| led.set_state(if on { LedState::On } else { LedState::Off });
     loop {
-        let state = controller.toggle();
-        if led_state_to_level(state) {
-            led.set_high();
+        blink_once(&mut set, &mut delay, controller.delay_ms()).await;
+    }
+}
+
+/// Input passthrough task.
+///
+/// # Details
+/// Mirrors the passthrough input onto the main LED forever through
+/// passthrough::follow_input.
+///
+/// # Arguments
+/// * `input` - Passthrough input pin
+/// * `led` - Main LED output pin
+/// * `passthrough` - Level mapping for the LED polarity
+#[cfg(not(feature = "timer-isr"))]
+#[embassy_executor::task]
+async fn passthrough_task(
+    mut input: Input<'static>,
+    mut led: Output<'static>,
+    passthrough: Passthrough,
+) -> ! {
+    follow_input(&mut input, &mut led, passthrough).await
+}
+
+/// Blink inversion input task.
+///
+/// # Details
+/// Mirrors the debounced inversion switch into the shared invert
+/// flag, so the blink output swaps on and off while it is high.
+/// Samples every millisecond while the input is settling and sleeps
+/// on the next edge once it is stable.
+///
+/// # Arguments
+/// * `input` - Inversion switch input pin
+#[cfg(not(feature = "timer-isr"))]
+#[embassy_executor::task]
+async fn invert_task(mut input: Input<'static>) -> ! {
+    let mut debouncer = Debouncer::default();
+    let mut delay = backend_delay();
+    let mut now_ms: u64 = 0;
+    loop {
+        let raw = input.is_high();
+        if let Some(level) = debouncer.update(raw, now_ms) {
+            INVERT.store(level, Ordering::Relaxed);
+        }
+        if raw == debouncer.level() {
+            input.wait_for_any_edge().await;
         } else {
-            led.set_low();
+            delay.delay_ms(1).await;
+        }
+        now_ms += 1;
+    }
+}
+
+/// Runs the startup delay and boot pattern.
+///
+/// # Details
+/// Holds the LED off for the startup delay, plays the reset-reason
+/// pattern once, then flashes the firmware version.
+///
+/// # Arguments
+/// * `led` - LED output pin
+/// * `controller` - Controller providing timing and polarity
+async fn boot<P: LedPin>(led: &mut P, controller: &LedController) {
+    let mut delay = backend_delay();
+    delay
+        .delay_ms(delay_arg(controller.startup_delay_ms()))
+        .await;
+    let boot_pattern = boot_blink_pattern(read_boot_reason());
+    play_once(led, controller, boot_pattern, &mut delay).await;
+    for (state, ms) in version_blink() {
+        led.set_level(controller.polarity().level_for(state));
+        delay.delay_ms(delay_arg(ms)).await;
+    }
+}
+
+/// Async blink loop.
+///
+/// # Details
+/// Toggles the LED and waits the blink delay forever.
+/// Publishes the toggle count after every toggle.
+/// Drains queued commands once per cycle, saving a changed pattern
+/// selection to the store.
+/// Waits the settle time after each pin write when configured.
+/// The pin is held in a BlinkGuard so it is driven off if dropped.
+/// Timing goes through the DelayNs of the selected backend.
+/// Picks up the live invert flag before every step.
+///
+/// # Arguments
+/// * `led` - LED output pin
+/// * `controller` - Controller providing state and timing
+/// * `store` - Configuration store for the selected pattern
+///
+/// # Returns
+/// * `!` - Never returns (infinite loop).
+#[cfg(not(feature = "timer-isr"))]
+async fn blink_loop<P: LedPin, S: ConfigStore>(
+    led: P,
+    mut controller: LedController,
+    mut store: S,
+) -> ! {
+    let mut led = BlinkGuard::new(led, controller.polarity());
+    let mut delay = backend_delay();
+    let mut publish = |count| TOGGLE_COUNT.signal(count);
+    loop {
+        drain_commands(&mut controller, &mut store);
+        led.set_invert(INVERT.load(Ordering::Relaxed));
+        blink_step(&mut led, &mut controller, &mut delay, &mut publish).await;
+    }
+}
+
+/// Async PWM blink loop.
+///
+/// # Details
+/// Ticks the controller and writes the PWM compare for each phase,
+/// so the on and off phases are dimmed by the duty settings.
+/// Publishes the toggle count and drains queued commands like
+/// the digital loop. The live invert flag inverts the compare.
+/// The boot pattern is not played on this backend.
+///
+/// # Arguments
+/// * `pwm` - PWM slice driving the LED on channel A
+/// * `controller` - Controller providing state and timing
+/// * `store` - Configuration store for the selected pattern
+///
+/// # Returns
+/// * `!` - Never returns (infinite loop).
+#[cfg(not(feature = "timer-isr"))]
+async fn pwm_loop<S: ConfigStore>(
+    mut pwm: Pwm<'static>,
+    mut controller: LedController,
+    mut store: S,
+) -> ! {
+    let mut config = PwmConfig::default();
+    config.top = PWM_TOP;
+    let mut delay = backend_delay();
+    loop {
+        drain_commands(&mut controller, &mut store);
+        let tick = controller.tick();
+        let invert = INVERT.load(Ordering::Relaxed);
+        if let BackendOutput::Duty(compare) =
+            controller.output_for(OutputBackend::Pwm, PWM_TOP, invert)
+        {
+            config.compare_a = compare;
+            pwm.set_config(&config);
+        }
+        TOGGLE_COUNT.signal(controller.toggle_count());
+        delay.delay_ms(delay_arg(tick.delay_ms)).await;
+    }
+}
+
+/// Panic handler.
+///
+/// # Details
+/// Classifies the panic message and blinks the matching distress
+/// pattern forever with the configured LED polarity. The executor
+/// cannot run here, so interrupts are masked, the LED pin is stolen
+/// and every hold is a cycle-counted busy-wait.
+///
+/// # Arguments
+/// * `info` - Panic information
+///
+/// # Returns
+/// * `!` - Never returns (infinite loop).
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    cortex_m::interrupt::disable();
+    let steps = panic_pattern_for(panic_code_for(info.message()));
+    let pin = unsafe { embassy_rp::peripherals::PIN_16::steal() };
+    let polarity = LedController::new().polarity();
+    let mut led = Output::new(pin, Level::Low);
+    loop {
+        for &(state, hold_ms) in steps {
+            led.set_level(Level::from(polarity.level_for(state)));
+            for _ in 0..hold_ms {
+                cortex_m::asm::delay(ns_to_cycles(1_000_000, CPU_HZ));
+            }
         }
-        Timer::after_millis(controller.delay_ms()).await;
     }
 }
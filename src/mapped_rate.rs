@@ -0,0 +1,156 @@
+/*
+ * @file mapped_rate.rs
+ * @brief Counter value to blink rate mapping
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: mapped_rate.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Counter Mapped Blink Rate.
+//!
+//! BRIEF:
+//! Maps a changing counter such as queue depth onto blink speed,
+//! scaled to a configurable maximum.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{PROGRESS_FAST_MS, PROGRESS_SLOW_MS};
+
+/// Counter value to blink delay mapping.
+///
+/// # Details
+/// Linearly maps 0..=max_value onto PROGRESS_SLOW_MS..=PROGRESS_FAST_MS
+/// so a growing counter blinks faster. Values above the maximum are
+/// clamped to the fastest rate.
+///
+/// # Fields
+/// * `max_value` - Counter value mapped to the fastest rate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct MappedRate {
+    max_value: u32,
+}
+
+impl MappedRate {
+    /// Creates new mapping scaled to the given maximum.
+    ///
+    /// # Arguments
+    /// * `max_value` - Counter value mapped to the fastest rate
+    ///
+    /// # Returns
+    /// * `Self` - New MappedRate instance
+    #[allow(dead_code)]
+    pub fn new(max_value: u32) -> Self {
+        Self { max_value }
+    }
+
+    /// Rescales the mapping to a new maximum.
+    ///
+    /// # Arguments
+    /// * `max_value` - Counter value mapped to the fastest rate
+    #[allow(dead_code)]
+    pub fn set_max(&mut self, max_value: u32) {
+        self.max_value = max_value;
+    }
+
+    /// Returns the current maximum.
+    ///
+    /// # Returns
+    /// * `u32` - Counter value mapped to the fastest rate
+    #[allow(dead_code)]
+    pub fn max_value(&self) -> u32 {
+        self.max_value
+    }
+
+    /// Maps a counter value to a blink delay.
+    ///
+    /// # Details
+    /// A zero maximum maps every value to the fastest rate.
+    ///
+    /// # Arguments
+    /// * `value` - Current counter value
+    ///
+    /// # Returns
+    /// * `u64` - Blink delay in milliseconds
+    #[allow(dead_code)]
+    pub fn delay_for_value(&self, value: u32) -> u64 {
+        if self.max_value == 0 {
+            return PROGRESS_FAST_MS;
+        }
+        let value = u64::from(value.min(self.max_value));
+        let span = PROGRESS_SLOW_MS - PROGRESS_FAST_MS;
+        PROGRESS_SLOW_MS - span * value / u64::from(self.max_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== MappedRate Tests ====================
+
+    #[test]
+    fn test_zero_is_slowest() {
+        assert_eq!(MappedRate::new(100).delay_for_value(0), PROGRESS_SLOW_MS);
+    }
+
+    #[test]
+    fn test_half_max_is_midpoint() {
+        let mid = (PROGRESS_SLOW_MS + PROGRESS_FAST_MS) / 2;
+        assert_eq!(MappedRate::new(100).delay_for_value(50), mid);
+    }
+
+    #[test]
+    fn test_max_is_fastest() {
+        assert_eq!(MappedRate::new(100).delay_for_value(100), PROGRESS_FAST_MS);
+    }
+
+    #[test]
+    fn test_above_max_is_clamped() {
+        let rate = MappedRate::new(100);
+        assert_eq!(rate.delay_for_value(101), PROGRESS_FAST_MS);
+        assert_eq!(rate.delay_for_value(u32::MAX), PROGRESS_FAST_MS);
+    }
+
+    #[test]
+    fn test_set_max_rescales() {
+        let mut rate = MappedRate::new(100);
+        rate.set_max(200);
+        assert_eq!(rate.max_value(), 200);
+        assert_eq!(
+            rate.delay_for_value(100),
+            (PROGRESS_SLOW_MS + PROGRESS_FAST_MS) / 2
+        );
+    }
+
+    #[test]
+    fn test_zero_max_is_fastest() {
+        assert_eq!(MappedRate::new(0).delay_for_value(0), PROGRESS_FAST_MS);
+    }
+}
@@ -0,0 +1,294 @@
+/*
+ * @file morse.rs
+ * @brief Morse code message encoding
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: morse.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Morse Code Message Encoding.
+//!
+//! BRIEF:
+//! Precomputes a message's Morse pulse sequence into a fixed buffer.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::MORSE_UNIT_MS;
+use crate::led::LedState;
+
+/// Dot and inter-symbol gap length in units.
+const DOT_UNITS: u64 = 1;
+
+/// Dash length in units.
+const DASH_UNITS: u64 = 3;
+
+/// Gap between characters in units.
+const CHAR_GAP_UNITS: u64 = 3;
+
+/// Gap between words in units.
+const WORD_GAP_UNITS: u64 = 7;
+
+/// Looks up the Morse symbols for a character.
+///
+/// # Details
+/// Supports letters (case-insensitive) and digits.
+///
+/// # Arguments
+/// * `c` - Character to encode
+///
+/// # Returns
+/// * `Option<&'static str>` - Dots and dashes, or None if unsupported
+#[allow(dead_code)]
+pub fn morse_symbols(c: char) -> Option<&'static str> {
+    let symbols = match c.to_ascii_uppercase() {
+        'A' => ".-",
+        'B' => "-...",
+        'C' => "-.-.",
+        'D' => "-..",
+        'E' => ".",
+        'F' => "..-.",
+        'G' => "--.",
+        'H' => "....",
+        'I' => "..",
+        'J' => ".---",
+        'K' => "-.-",
+        'L' => ".-..",
+        'M' => "--",
+        'N' => "-.",
+        'O' => "---",
+        'P' => ".--.",
+        'Q' => "--.-",
+        'R' => ".-.",
+        'S' => "...",
+        'T' => "-",
+        'U' => "..-",
+        'V' => "...-",
+        'W' => ".--",
+        'X' => "-..-",
+        'Y' => "-.--",
+        'Z' => "--..",
+        '0' => "-----",
+        '1' => ".----",
+        '2' => "..---",
+        '3' => "...--",
+        '4' => "....-",
+        '5' => ".....",
+        '6' => "-....",
+        '7' => "--...",
+        '8' => "---..",
+        '9' => "----.",
+        _ => return None,
+    };
+    Some(symbols)
+}
+
+/// Fixed-capacity buffer of precomputed Morse steps.
+///
+/// # Details
+/// Holds a whole message as `(LedState, u64)` pairs computed at init.
+/// Every symbol occupies an ON step followed by an OFF step.
+///
+/// # Fields
+/// * `steps` - Step storage
+/// * `len` - Number of steps in use
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct MorseBuffer<const N: usize> {
+    steps: [(LedState, u64); N],
+    len: usize,
+}
+
+impl<const N: usize> MorseBuffer<N> {
+    /// Encodes text into a new Morse buffer.
+    ///
+    /// # Details
+    /// Unsupported characters are skipped and whitespace separates
+    /// words by stretching the previous OFF step to a word gap, so
+    /// empty or whitespace-only text yields no steps. Encoding stops
+    /// at the last whole character that fits, so long messages are
+    /// truncated without splitting a character.
+    ///
+    /// # Arguments
+    /// * `text` - Message to encode
+    ///
+    /// # Returns
+    /// * `(Self, usize)` - Filled buffer and number of steps used
+    #[allow(dead_code)]
+    pub fn encode(text: &str) -> (Self, usize) {
+        let mut buf = Self {
+            steps: [(LedState::Off, 0); N],
+            len: 0,
+        };
+        for c in text.chars() {
+            if c.is_whitespace() {
+                if let Some(last) = buf.len.checked_sub(1) {
+                    buf.steps[last].1 = WORD_GAP_UNITS * MORSE_UNIT_MS;
+                }
+                continue;
+            }
+            let Some(symbols) = morse_symbols(c) else {
+                continue;
+            };
+            if buf.len + 2 * symbols.len() > N {
+                break;
+            }
+            for symbol in symbols.chars() {
+                let units = if symbol == '-' { DASH_UNITS } else { DOT_UNITS };
+                buf.push((LedState::On, units * MORSE_UNIT_MS));
+                buf.push((LedState::Off, DOT_UNITS * MORSE_UNIT_MS));
+            }
+            buf.steps[buf.len - 1].1 = CHAR_GAP_UNITS * MORSE_UNIT_MS;
+        }
+        let len = buf.len;
+        (buf, len)
+    }
+
+    /// Returns the encoded steps.
+    ///
+    /// # Returns
+    /// * `&[(LedState, u64)]` - Steps in playback order
+    #[allow(dead_code)]
+    pub fn steps(&self) -> &[(LedState, u64)] {
+        &self.steps[..self.len]
+    }
+
+    /// Appends a step if capacity allows.
+    ///
+    /// # Arguments
+    /// * `step` - Step to append
+    fn push(&mut self, step: (LedState, u64)) {
+        if self.len < N {
+            self.steps[self.len] = step;
+            self.len += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Symbol Table Tests ====================
+
+    #[test]
+    fn test_symbols_case_insensitive() {
+        assert_eq!(morse_symbols('s'), Some("..."));
+        assert_eq!(morse_symbols('S'), Some("..."));
+    }
+
+    #[test]
+    fn test_symbols_unsupported() {
+        assert_eq!(morse_symbols('#'), None);
+    }
+
+    // ==================== MorseBuffer Tests ====================
+
+    #[test]
+    fn test_sos_slot_count() {
+        let (_, len) = MorseBuffer::<32>::encode("SOS");
+        assert_eq!(len, 18);
+    }
+
+    #[test]
+    fn test_sos_steps() {
+        let (buf, _) = MorseBuffer::<32>::encode("SOS");
+        let dot = MORSE_UNIT_MS;
+        let dash = 3 * MORSE_UNIT_MS;
+        let steps = buf.steps();
+        assert_eq!(steps[0], (LedState::On, dot));
+        assert_eq!(steps[1], (LedState::Off, dot));
+        assert_eq!(steps[5], (LedState::Off, 3 * MORSE_UNIT_MS));
+        assert_eq!(steps[6], (LedState::On, dash));
+        assert_eq!(steps[17], (LedState::Off, 3 * MORSE_UNIT_MS));
+    }
+
+    #[test]
+    fn test_truncates_at_character_boundary() {
+        let (buf, len) = MorseBuffer::<8>::encode("SOS");
+        assert_eq!(len, 6);
+        assert_eq!(buf.steps(), MorseBuffer::<8>::encode("S").0.steps());
+        assert_eq!(buf.steps()[5], (LedState::Off, 3 * MORSE_UNIT_MS));
+    }
+
+    #[test]
+    fn test_truncation_does_not_skip_ahead() {
+        let (_, len) = MorseBuffer::<8>::encode("SOE");
+        assert_eq!(len, 6);
+    }
+
+    #[test]
+    fn test_skips_unsupported_characters() {
+        let (_, with_symbol) = MorseBuffer::<32>::encode("S#S");
+        let (_, without) = MorseBuffer::<32>::encode("SS");
+        assert_eq!(with_symbol, without);
+    }
+
+    #[test]
+    fn test_empty_text_has_no_steps() {
+        let (buf, len) = MorseBuffer::<32>::encode("");
+        assert_eq!(len, 0);
+        assert!(buf.steps().is_empty());
+    }
+
+    #[test]
+    fn test_single_space_has_no_steps() {
+        let (buf, len) = MorseBuffer::<32>::encode(" ");
+        assert_eq!(len, 0);
+        assert!(buf.steps().is_empty());
+    }
+
+    #[test]
+    fn test_multiple_spaces_have_no_steps() {
+        let (buf, len) = MorseBuffer::<32>::encode("   \t ");
+        assert_eq!(len, 0);
+        assert!(buf.steps().is_empty());
+    }
+
+    #[test]
+    fn test_space_stretches_gap_to_word_gap() {
+        let (buf, len) = MorseBuffer::<32>::encode("E  E");
+        assert_eq!(len, 4);
+        assert_eq!(
+            buf.steps()[1],
+            (LedState::Off, WORD_GAP_UNITS * MORSE_UNIT_MS)
+        );
+        assert_eq!(
+            buf.steps()[3],
+            (LedState::Off, CHAR_GAP_UNITS * MORSE_UNIT_MS)
+        );
+    }
+
+    #[test]
+    fn test_leading_space_ignored() {
+        assert_eq!(
+            MorseBuffer::<32>::encode(" SOS"),
+            MorseBuffer::<32>::encode("SOS")
+        );
+    }
+}
@@ -0,0 +1,244 @@
+/*
+ * @file panic_code.rs
+ * @brief Panic category blink patterns
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: panic_code.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Panic Category Blink Patterns.
+//!
+//! BRIEF:
+//! Classifies panic messages and maps panic category codes to static
+//! distress pulse tables.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::LedState;
+use crate::stats::BufWriter;
+use core::fmt::{Display, Write};
+
+/// Generic panic pulse table.
+///
+/// # Details
+/// Rapid even flashing for uncategorized panics.
+#[allow(dead_code)]
+const PANIC_GENERIC_STEPS: [(LedState, u64); 2] = [(LedState::On, 50), (LedState::Off, 50)];
+
+/// Assertion failure pulse table.
+///
+/// # Details
+/// Three short flashes followed by a rest.
+#[allow(dead_code)]
+const PANIC_ASSERT_STEPS: [(LedState, u64); 6] = [
+    (LedState::On, 100),
+    (LedState::Off, 100),
+    (LedState::On, 100),
+    (LedState::Off, 100),
+    (LedState::On, 100),
+    (LedState::Off, 900),
+];
+
+/// Bounds check failure pulse table.
+///
+/// # Details
+/// Two long flashes followed by a rest.
+#[allow(dead_code)]
+const PANIC_BOUNDS_STEPS: [(LedState, u64); 4] = [
+    (LedState::On, 600),
+    (LedState::Off, 200),
+    (LedState::On, 600),
+    (LedState::Off, 900),
+];
+
+/// Arithmetic failure pulse table.
+///
+/// # Details
+/// Long, short, long flashes followed by a rest.
+#[allow(dead_code)]
+const PANIC_ARITHMETIC_STEPS: [(LedState, u64); 6] = [
+    (LedState::On, 600),
+    (LedState::Off, 200),
+    (LedState::On, 100),
+    (LedState::Off, 200),
+    (LedState::On, 600),
+    (LedState::Off, 900),
+];
+
+/// Assertion failure panic code.
+#[allow(dead_code)]
+pub const PANIC_CODE_ASSERT: u8 = 1;
+
+/// Bounds check failure panic code.
+#[allow(dead_code)]
+pub const PANIC_CODE_BOUNDS: u8 = 2;
+
+/// Arithmetic failure panic code.
+#[allow(dead_code)]
+pub const PANIC_CODE_ARITHMETIC: u8 = 3;
+
+/// Returns the panic category code for a panic message.
+///
+/// # Details
+/// Formats the start of the message into a small stack buffer and
+/// matches the core panic wording: `assertion ...`, `... out of
+/// bounds` or `... out of range`, and `attempt to ...` for arithmetic
+/// checks. Anything else is the generic code 0.
+///
+/// # Arguments
+/// * `message` - Panic message
+///
+/// # Returns
+/// * `u8` - Panic category code
+#[allow(dead_code)]
+pub fn panic_code_for(message: impl Display) -> u8 {
+    let mut buf = [0u8; 64];
+    let mut writer = BufWriter::new(&mut buf);
+    let _ = write!(writer, "{}", message);
+    let len = writer.len();
+    let text = &buf[..len];
+    if text.starts_with(b"assertion") {
+        PANIC_CODE_ASSERT
+    } else if contains(text, b"out of bounds") || contains(text, b"out of range") {
+        PANIC_CODE_BOUNDS
+    } else if text.starts_with(b"attempt to ") {
+        PANIC_CODE_ARITHMETIC
+    } else {
+        0
+    }
+}
+
+/// Returns whether a byte string contains a needle.
+///
+/// # Arguments
+/// * `text` - Bytes to search
+/// * `needle` - Bytes to find
+///
+/// # Returns
+/// * `bool` - true if needle occurs in text
+fn contains(text: &[u8], needle: &[u8]) -> bool {
+    text.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Returns the distress pulse table for a panic category.
+///
+/// # Details
+/// Tables are static and repeat from the start when exhausted.
+/// Unknown codes fall back to the generic rapid flash.
+///
+/// # Arguments
+/// * `code` - Panic category code
+///
+/// # Returns
+/// * `&'static [(LedState, u64)]` - Pulse steps for the code
+#[allow(dead_code)]
+pub fn panic_pattern_for(code: u8) -> &'static [(LedState, u64)] {
+    match code {
+        PANIC_CODE_ASSERT => &PANIC_ASSERT_STEPS,
+        PANIC_CODE_BOUNDS => &PANIC_BOUNDS_STEPS,
+        PANIC_CODE_ARITHMETIC => &PANIC_ARITHMETIC_STEPS,
+        _ => &PANIC_GENERIC_STEPS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Panic Pattern Tests ====================
+
+    #[test]
+    fn test_codes_map_to_distinct_patterns() {
+        let codes = [
+            0,
+            PANIC_CODE_ASSERT,
+            PANIC_CODE_BOUNDS,
+            PANIC_CODE_ARITHMETIC,
+        ];
+        for (i, a) in codes.iter().enumerate() {
+            for b in &codes[i + 1..] {
+                assert_ne!(panic_pattern_for(*a), panic_pattern_for(*b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_patterns_non_empty_and_alternate() {
+        for code in 0..=u8::MAX {
+            let steps = panic_pattern_for(code);
+            assert!(!steps.is_empty());
+            for (i, (state, ms)) in steps.iter().enumerate() {
+                let expected = if i.is_multiple_of(2) {
+                    LedState::On
+                } else {
+                    LedState::Off
+                };
+                assert_eq!(*state, expected);
+                assert!(*ms > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_messages_map_to_codes() {
+        assert_eq!(panic_code_for("assertion failed: x"), PANIC_CODE_ASSERT);
+        assert_eq!(
+            panic_code_for("assertion `left == right` failed"),
+            PANIC_CODE_ASSERT
+        );
+        assert_eq!(
+            panic_code_for(format_args!(
+                "index out of bounds: the len is {} but the index is {}",
+                3, 7
+            )),
+            PANIC_CODE_BOUNDS
+        );
+        assert_eq!(
+            panic_code_for("range end index 9 out of range for slice of length 4"),
+            PANIC_CODE_BOUNDS
+        );
+        assert_eq!(
+            panic_code_for("attempt to add with overflow"),
+            PANIC_CODE_ARITHMETIC
+        );
+        assert_eq!(
+            panic_code_for("attempt to divide by zero"),
+            PANIC_CODE_ARITHMETIC
+        );
+        assert_eq!(
+            panic_code_for("called `Option::unwrap()` on a `None` value"),
+            0
+        );
+    }
+
+    #[test]
+    fn test_unknown_code_is_generic() {
+        assert_eq!(panic_pattern_for(200), &PANIC_GENERIC_STEPS);
+    }
+}
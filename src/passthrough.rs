@@ -0,0 +1,133 @@
+/*
+ * @file passthrough.rs
+ * @brief Input passthrough LED mode
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: passthrough.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Input Passthrough LED Mode.
+//!
+//! BRIEF:
+//! Mirrors an input pin onto the LED in real time instead of blinking.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::LedState;
+use crate::polarity::Polarity;
+
+/// Input passthrough LED mode.
+///
+/// # Details
+/// Lights the LED while the input is high instead of blinking.
+/// The output level respects the LED polarity.
+///
+/// # Fields
+/// * `polarity` - LED polarity
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Passthrough {
+    polarity: Polarity,
+}
+
+impl Passthrough {
+    /// Creates new passthrough for an LED polarity.
+    ///
+    /// # Arguments
+    /// * `polarity` - LED polarity
+    ///
+    /// # Returns
+    /// * `Self` - New Passthrough instance
+    #[allow(dead_code)]
+    pub fn new(polarity: Polarity) -> Self {
+        Self { polarity }
+    }
+
+    /// Returns the LED pin level mirroring an input level.
+    ///
+    /// # Arguments
+    /// * `input_high` - Current input pin level
+    ///
+    /// # Returns
+    /// * `bool` - true to drive the LED pin high, false for low
+    #[allow(dead_code)]
+    pub fn next_level_from_input(&self, input_high: bool) -> bool {
+        let state = if input_high {
+            LedState::On
+        } else {
+            LedState::Off
+        };
+        self.polarity.level_for(state)
+    }
+}
+
+/// Mirrors an input pin onto the LED forever.
+///
+/// # Details
+/// Applies the current input level, then waits for the next edge so
+/// the LED follows the input in real time without polling. Body of
+/// the passthrough task main spawns in passthrough mode.
+///
+/// # Arguments
+/// * `input` - Input pin to follow
+/// * `led` - LED pin to drive
+/// * `passthrough` - Level mapping
+#[cfg(feature = "embassy-rp")]
+#[allow(dead_code)]
+pub async fn follow_input<P: crate::pin::LedPin>(
+    input: &mut embassy_rp::gpio::Input<'_>,
+    led: &mut P,
+    passthrough: Passthrough,
+) -> ! {
+    loop {
+        led.set_level(passthrough.next_level_from_input(input.is_high()));
+        input.wait_for_any_edge().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Passthrough Tests ====================
+
+    #[test]
+    fn test_active_high_follows_input() {
+        let passthrough = Passthrough::new(Polarity::ActiveHigh);
+        assert!(passthrough.next_level_from_input(true));
+        assert!(!passthrough.next_level_from_input(false));
+    }
+
+    #[test]
+    fn test_active_low_inverts_input() {
+        let passthrough = Passthrough::new(Polarity::ActiveLow);
+        assert!(!passthrough.next_level_from_input(true));
+        assert!(passthrough.next_level_from_input(false));
+    }
+}
@@ -0,0 +1,841 @@
+/*
+ * @file pattern.rs
+ * @brief Blink pattern definitions
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: pattern.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Blink Pattern Definitions.
+//!
+//! BRIEF:
+//! Provides the blink pattern trait, built-in step tables and a
+//! capture helper for pattern conformance vectors.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{BLINK_DELAY_MS, FAST_BLINK_MS};
+use crate::led::{LedController, LedState};
+
+/// Steady blink step table.
+///
+/// # Details
+/// Equal ON and OFF phases at the default blink delay.
+#[allow(dead_code)]
+const STEADY_STEPS: [(LedState, u64); 2] = [
+    (LedState::On, BLINK_DELAY_MS),
+    (LedState::Off, BLINK_DELAY_MS),
+];
+
+/// Heartbeat blink step table.
+///
+/// # Details
+/// Two short pulses followed by a long rest, like a heartbeat.
+#[allow(dead_code)]
+const HEARTBEAT_STEPS: [(LedState, u64); 4] = [
+    (LedState::On, 100),
+    (LedState::Off, 100),
+    (LedState::On, 100),
+    (LedState::Off, 700),
+];
+
+/// SOS blink step table.
+///
+/// # Details
+/// Three short, three long, three short pulses then a word gap.
+#[allow(dead_code)]
+const SOS_STEPS: [(LedState, u64); 18] = [
+    (LedState::On, 200),
+    (LedState::Off, 200),
+    (LedState::On, 200),
+    (LedState::Off, 200),
+    (LedState::On, 200),
+    (LedState::Off, 600),
+    (LedState::On, 600),
+    (LedState::Off, 200),
+    (LedState::On, 600),
+    (LedState::Off, 200),
+    (LedState::On, 600),
+    (LedState::Off, 600),
+    (LedState::On, 200),
+    (LedState::Off, 200),
+    (LedState::On, 200),
+    (LedState::Off, 200),
+    (LedState::On, 200),
+    (LedState::Off, 1400),
+];
+
+/// Identify blink step table.
+///
+/// # Details
+/// Rapid triple flash then a long pause, distinct from the other
+/// patterns so one device stands out in a fleet.
+#[allow(dead_code)]
+const IDENTIFY_STEPS: [(LedState, u64); 6] = [
+    (LedState::On, 50),
+    (LedState::Off, 50),
+    (LedState::On, 50),
+    (LedState::Off, 50),
+    (LedState::On, 50),
+    (LedState::Off, 750),
+];
+
+/// Brownout recovery blink step table.
+///
+/// # Details
+/// Three slow long flashes then a long pause, unlike the short
+/// pulses of the other patterns.
+#[allow(dead_code)]
+const RECOVERY_STEPS: [(LedState, u64); 6] = [
+    (LedState::On, 800),
+    (LedState::Off, 300),
+    (LedState::On, 800),
+    (LedState::Off, 300),
+    (LedState::On, 800),
+    (LedState::Off, 1500),
+];
+
+/// Fast blink step table.
+///
+/// # Details
+/// Equal short ON and OFF phases, e.g. while connecting.
+#[allow(dead_code)]
+const FAST_STEPS: [(LedState, u64); 2] = [
+    (LedState::On, FAST_BLINK_MS),
+    (LedState::Off, FAST_BLINK_MS),
+];
+
+/// Source of timed LED steps.
+///
+/// # Details
+/// Produces an endless sequence of LED states with hold durations.
+/// Implemented by the controller and by the built-in patterns.
+#[allow(dead_code)]
+pub trait BlinkPattern {
+    /// Returns the next LED step.
+    ///
+    /// # Returns
+    /// * `(LedState, u64)` - State to apply and hold time in milliseconds
+    fn next_step(&mut self) -> (LedState, u64);
+}
+
+impl BlinkPattern for LedController {
+    /// Returns the next controller step.
+    ///
+    /// # Details
+    /// Plays the selected pattern through tick() and holds the LED
+    /// for the tick delay.
+    ///
+    /// # Returns
+    /// * `(LedState, u64)` - New state and blink delay
+    #[allow(dead_code)]
+    fn next_step(&mut self) -> (LedState, u64) {
+        let tick = self.tick();
+        (tick.state, tick.delay_ms)
+    }
+}
+
+/// Built-in blink pattern enumeration.
+///
+/// # Details
+/// Selects one of the fixed step tables.
+///
+/// # Variants
+/// * `Steady` - Even on/off blink at the default delay
+/// * `Heartbeat` - Double pulse followed by a rest
+/// * `Sos` - Morse SOS distress signal
+/// * `Identify` - Fast-slow device locate blink
+/// * `Recovery` - Slow long flashes after a brownout reset
+/// * `Fast` - Even on/off blink at the fast blink delay
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Pattern {
+    Steady,
+    Heartbeat,
+    Sos,
+    Identify,
+    Recovery,
+    Fast,
+}
+
+impl Pattern {
+    /// Returns the step table for this pattern.
+    ///
+    /// # Details
+    /// Tables are static and repeat from the start when exhausted.
+    ///
+    /// # Returns
+    /// * `&'static [(LedState, u64)]` - Pattern steps
+    #[allow(dead_code)]
+    pub fn steps(self) -> &'static [(LedState, u64)] {
+        match self {
+            Pattern::Steady => &STEADY_STEPS,
+            Pattern::Heartbeat => &HEARTBEAT_STEPS,
+            Pattern::Sos => &SOS_STEPS,
+            Pattern::Identify => &IDENTIFY_STEPS,
+            Pattern::Recovery => &RECOVERY_STEPS,
+            Pattern::Fast => &FAST_STEPS,
+        }
+    }
+
+    /// Returns the human-readable pattern name.
+    ///
+    /// # Details
+    /// Lowercase names for logs and UI.
+    ///
+    /// # Returns
+    /// * `&'static str` - Pattern name
+    #[allow(dead_code)]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pattern::Steady => "steady",
+            Pattern::Heartbeat => "heartbeat",
+            Pattern::Sos => "sos",
+            Pattern::Identify => "identify",
+            Pattern::Recovery => "recovery",
+            Pattern::Fast => "fast",
+        }
+    }
+
+    /// Looks up a pattern by its name.
+    ///
+    /// # Arguments
+    /// * `name` - Lowercase name as returned by name()
+    ///
+    /// # Returns
+    /// * `Option<Pattern>` - Pattern, or None for an unknown name
+    #[allow(dead_code)]
+    pub fn from_name(name: &str) -> Option<Pattern> {
+        match name {
+            "steady" => Some(Pattern::Steady),
+            "heartbeat" => Some(Pattern::Heartbeat),
+            "sos" => Some(Pattern::Sos),
+            "identify" => Some(Pattern::Identify),
+            "recovery" => Some(Pattern::Recovery),
+            "fast" => Some(Pattern::Fast),
+            _ => None,
+        }
+    }
+
+    /// Returns the storage byte for this pattern.
+    ///
+    /// # Returns
+    /// * `u8` - 0 Steady, 1 Heartbeat, 2 Sos, 3 Identify, 4 Recovery, 5 Fast
+    #[allow(dead_code)]
+    pub fn to_byte(self) -> u8 {
+        match self {
+            Pattern::Steady => 0,
+            Pattern::Heartbeat => 1,
+            Pattern::Sos => 2,
+            Pattern::Identify => 3,
+            Pattern::Recovery => 4,
+            Pattern::Fast => 5,
+        }
+    }
+
+    /// Decodes a pattern from its storage byte.
+    ///
+    /// # Arguments
+    /// * `byte` - Storage byte
+    ///
+    /// # Returns
+    /// * `Option<Pattern>` - Pattern, or None for an unknown byte
+    #[allow(dead_code)]
+    pub fn from_byte(byte: u8) -> Option<Pattern> {
+        match byte {
+            0 => Some(Pattern::Steady),
+            1 => Some(Pattern::Heartbeat),
+            2 => Some(Pattern::Sos),
+            3 => Some(Pattern::Identify),
+            4 => Some(Pattern::Recovery),
+            5 => Some(Pattern::Fast),
+            _ => None,
+        }
+    }
+}
+
+/// Player stepping through a built-in pattern.
+///
+/// # Details
+/// Walks the pattern's step table and wraps at the end.
+///
+/// # Fields
+/// * `pattern` - Pattern being played
+/// * `index` - Index of the next step
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct PatternPlayer {
+    pattern: Pattern,
+    index: usize,
+}
+
+impl PatternPlayer {
+    /// Creates new player at the start of a pattern.
+    ///
+    /// # Arguments
+    /// * `pattern` - Pattern to play
+    ///
+    /// # Returns
+    /// * `Self` - New PatternPlayer instance
+    #[allow(dead_code)]
+    pub fn new(pattern: Pattern) -> Self {
+        Self { pattern, index: 0 }
+    }
+
+    /// Returns the pattern being played.
+    ///
+    /// # Returns
+    /// * `Pattern` - Current pattern
+    #[allow(dead_code)]
+    pub fn pattern(&self) -> Pattern {
+        self.pattern
+    }
+}
+
+impl BlinkPattern for PatternPlayer {
+    /// Returns the next step of the pattern.
+    ///
+    /// # Details
+    /// Advances the index and wraps to the first step.
+    ///
+    /// # Returns
+    /// * `(LedState, u64)` - Step state and hold time
+    #[allow(dead_code)]
+    fn next_step(&mut self) -> (LedState, u64) {
+        let steps = self.pattern.steps();
+        let step = steps[self.index];
+        self.index = (self.index + 1) % steps.len();
+        step
+    }
+}
+
+/// Round-robin playlist of patterns on one LED.
+///
+/// # Details
+/// Spends a configured time on each slot, then moves to the next
+/// and wraps after the last.
+///
+/// # Fields
+/// * `slots` - Pattern and duration in milliseconds for each slot
+/// * `index` - Index of the active slot
+/// * `elapsed_ms` - Time spent in the active slot
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct PatternPlaylist<const N: usize> {
+    slots: [(Pattern, u64); N],
+    index: usize,
+    elapsed_ms: u64,
+}
+
+impl<const N: usize> PatternPlaylist<N> {
+    /// Creates new playlist starting at the first slot.
+    ///
+    /// # Arguments
+    /// * `slots` - Pattern and duration in milliseconds for each slot
+    ///
+    /// # Returns
+    /// * `Self` - New PatternPlaylist instance
+    #[allow(dead_code)]
+    pub fn new(slots: [(Pattern, u64); N]) -> Self {
+        Self {
+            slots,
+            index: 0,
+            elapsed_ms: 0,
+        }
+    }
+
+    /// Returns the pattern of the active slot.
+    ///
+    /// # Returns
+    /// * `Option<Pattern>` - Active pattern, None for an empty playlist
+    #[allow(dead_code)]
+    pub fn current(&self) -> Option<Pattern> {
+        self.slots.get(self.index).map(|&(pattern, _)| pattern)
+    }
+
+    /// Advances playlist time and switches slots as they expire.
+    ///
+    /// # Details
+    /// Whole playlist cycles are skipped, so a large step lands on the
+    /// same slot as stepping one cycle at a time. Zero-duration slots
+    /// are passed over. Does nothing if every slot lasts zero time.
+    /// Playlist time saturates rather than overflowing.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance
+    ///
+    /// # Returns
+    /// * `Option<Pattern>` - New pattern if the active slot changed
+    #[allow(dead_code)]
+    pub fn advance(&mut self, elapsed_ms: u64) -> Option<Pattern> {
+        let total = self
+            .slots
+            .iter()
+            .map(|&(_, ms)| ms)
+            .fold(0u64, u64::saturating_add);
+        if total == 0 {
+            return None;
+        }
+        let start = self.index;
+        self.elapsed_ms = self.elapsed_ms.saturating_add(elapsed_ms % total);
+        while self.elapsed_ms >= self.slots[self.index].1 {
+            self.elapsed_ms -= self.slots[self.index].1;
+            self.index = (self.index + 1) % N;
+        }
+        if self.index == start {
+            None
+        } else {
+            self.current()
+        }
+    }
+}
+
+/// Scripted chain of patterns that stops when done.
+///
+/// # Details
+/// Plays each entry's pattern its repeat count times, in order,
+/// then finishes. Entries with zero repeats are skipped.
+///
+/// # Fields
+/// * `entries` - Pattern and repeat count for each entry
+/// * `entry` - Index of the active entry
+/// * `repeat` - Completed repeats of the active entry
+/// * `step` - Index of the next step within the pattern
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Sequence<const N: usize> {
+    entries: [(Pattern, u8); N],
+    entry: usize,
+    repeat: u8,
+    step: usize,
+}
+
+impl<const N: usize> Sequence<N> {
+    /// Creates new sequence at the first entry.
+    ///
+    /// # Arguments
+    /// * `entries` - Pattern and repeat count for each entry
+    ///
+    /// # Returns
+    /// * `Self` - New Sequence instance
+    #[allow(dead_code)]
+    pub fn new(entries: [(Pattern, u8); N]) -> Self {
+        let mut sequence = Self {
+            entries,
+            entry: 0,
+            repeat: 0,
+            step: 0,
+        };
+        sequence.skip_empty();
+        sequence
+    }
+
+    /// Returns whether every entry has completed.
+    ///
+    /// # Returns
+    /// * `bool` - true once the sequence has finished
+    #[allow(dead_code)]
+    pub fn finished(&self) -> bool {
+        self.entry >= N
+    }
+
+    /// Returns the next step of the sequence.
+    ///
+    /// # Returns
+    /// * `Option<(LedState, u64)>` - Step, or None once finished
+    #[allow(dead_code)]
+    pub fn next_step(&mut self) -> Option<(LedState, u64)> {
+        let (pattern, repeats) = *self.entries.get(self.entry)?;
+        let steps = pattern.steps();
+        let step = steps[self.step];
+        self.step += 1;
+        if self.step == steps.len() {
+            self.step = 0;
+            self.repeat += 1;
+            if self.repeat == repeats {
+                self.repeat = 0;
+                self.entry += 1;
+                self.skip_empty();
+            }
+        }
+        Some(step)
+    }
+
+    /// Moves past entries with zero repeats.
+    fn skip_empty(&mut self) {
+        while self.entry < N && self.entries[self.entry].1 == 0 {
+            self.entry += 1;
+        }
+    }
+}
+
+/// Compound pattern with a flickering on-phase.
+///
+/// # Details
+/// The on-phase is a burst of fast on/off sub-steps ending lit,
+/// followed by one long dark off-phase, then the cycle repeats.
+///
+/// # Fields
+/// * `flickers` - Fast flashes per on-phase
+/// * `flicker_ms` - Length of each fast sub-step
+/// * `off_ms` - Length of the dark off-phase
+/// * `step` - Index of the next step in the cycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Compound {
+    flickers: u8,
+    flicker_ms: u64,
+    off_ms: u64,
+    step: u16,
+}
+
+impl Compound {
+    /// Creates new compound pattern.
+    ///
+    /// # Arguments
+    /// * `flickers` - Fast flashes per on-phase
+    /// * `flicker_ms` - Length of each fast sub-step
+    /// * `off_ms` - Length of the dark off-phase
+    ///
+    /// # Returns
+    /// * `Self` - New Compound at the start of the on-phase
+    #[allow(dead_code)]
+    pub fn new(flickers: u8, flicker_ms: u64, off_ms: u64) -> Self {
+        Self {
+            flickers,
+            flicker_ms,
+            off_ms,
+            step: 0,
+        }
+    }
+}
+
+impl BlinkPattern for Compound {
+    /// Returns the next compound step.
+    ///
+    /// # Details
+    /// A cycle is 2 * flickers - 1 fast sub-steps then the off step.
+    /// Zero flickers leaves only the off step.
+    ///
+    /// # Returns
+    /// * `(LedState, u64)` - Step state and hold time
+    #[allow(dead_code)]
+    fn next_step(&mut self) -> (LedState, u64) {
+        let sub_steps = (u16::from(self.flickers) * 2).saturating_sub(1);
+        if self.step >= sub_steps {
+            self.step = 0;
+            return (LedState::Off, self.off_ms);
+        }
+        let state = if self.step.is_multiple_of(2) {
+            LedState::On
+        } else {
+            LedState::Off
+        };
+        self.step += 1;
+        (state, self.flicker_ms)
+    }
+}
+
+/// Captures the next N steps of a pattern.
+///
+/// # Details
+/// Collects a fixed test vector for pattern conformance checks.
+///
+/// # Arguments
+/// * `pattern` - Pattern to sample
+///
+/// # Returns
+/// * `[(LedState, u64); N]` - Next N steps in order
+#[allow(dead_code)]
+pub fn capture_steps<const N: usize>(pattern: &mut impl BlinkPattern) -> [(LedState, u64); N] {
+    core::array::from_fn(|_| pattern.next_step())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every built-in pattern.
+    const ALL_PATTERNS: [Pattern; 6] = [
+        Pattern::Steady,
+        Pattern::Heartbeat,
+        Pattern::Sos,
+        Pattern::Identify,
+        Pattern::Recovery,
+        Pattern::Fast,
+    ];
+
+    // ==================== Golden Vector Tests ====================
+
+    #[test]
+    fn test_capture_steady() {
+        let mut player = PatternPlayer::new(Pattern::Steady);
+        let golden = [
+            (LedState::On, 500),
+            (LedState::Off, 500),
+            (LedState::On, 500),
+            (LedState::Off, 500),
+        ];
+        assert_eq!(capture_steps::<4>(&mut player), golden);
+    }
+
+    #[test]
+    fn test_capture_heartbeat() {
+        let mut player = PatternPlayer::new(Pattern::Heartbeat);
+        let golden = [
+            (LedState::On, 100),
+            (LedState::Off, 100),
+            (LedState::On, 100),
+            (LedState::Off, 700),
+            (LedState::On, 100),
+            (LedState::Off, 100),
+        ];
+        assert_eq!(capture_steps::<6>(&mut player), golden);
+    }
+
+    #[test]
+    fn test_capture_sos_wraps() {
+        let mut player = PatternPlayer::new(Pattern::Sos);
+        let captured = capture_steps::<19>(&mut player);
+        assert_eq!(&captured[..18], &SOS_STEPS[..]);
+        assert_eq!(captured[18], SOS_STEPS[0]);
+    }
+
+    #[test]
+    fn test_capture_controller_matches_steady() {
+        let mut ctrl = LedController::new();
+        let mut player = PatternPlayer::new(Pattern::Steady);
+        assert_eq!(
+            capture_steps::<8>(&mut ctrl),
+            capture_steps::<8>(&mut player)
+        );
+    }
+
+    #[test]
+    fn test_capture_zero_steps() {
+        let mut player = PatternPlayer::new(Pattern::Steady);
+        let captured: [(LedState, u64); 0] = capture_steps(&mut player);
+        assert!(captured.is_empty());
+        assert_eq!(player.next_step(), (LedState::On, 500));
+    }
+
+    // ==================== Pattern Tests ====================
+
+    #[test]
+    fn test_steps_alternate_and_nonzero() {
+        for pattern in ALL_PATTERNS {
+            let steps = pattern.steps();
+            assert_eq!(steps.len() % 2, 0);
+            for (i, (state, ms)) in steps.iter().enumerate() {
+                let expected = if i % 2 == 0 {
+                    LedState::On
+                } else {
+                    LedState::Off
+                };
+                assert_eq!(*state, expected);
+                assert!(*ms > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pattern_byte_round_trip() {
+        for pattern in ALL_PATTERNS {
+            assert_eq!(Pattern::from_byte(pattern.to_byte()), Some(pattern));
+        }
+    }
+
+    #[test]
+    fn test_pattern_names() {
+        assert_eq!(Pattern::Steady.name(), "steady");
+        assert_eq!(Pattern::Heartbeat.name(), "heartbeat");
+        assert_eq!(Pattern::Sos.name(), "sos");
+        assert_eq!(Pattern::Identify.name(), "identify");
+        assert_eq!(Pattern::Recovery.name(), "recovery");
+        assert_eq!(Pattern::Fast.name(), "fast");
+    }
+
+    #[test]
+    fn test_pattern_names_distinct() {
+        let names = ALL_PATTERNS.map(|pattern| pattern.name());
+        for (i, a) in names.iter().enumerate() {
+            for b in &names[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pattern_name_round_trip() {
+        for pattern in ALL_PATTERNS {
+            assert_eq!(Pattern::from_name(pattern.name()), Some(pattern));
+        }
+        assert_eq!(Pattern::from_name("strobe"), None);
+    }
+
+    #[test]
+    fn test_pattern_unknown_byte() {
+        assert_eq!(Pattern::from_byte(6), None);
+        assert_eq!(Pattern::from_byte(0xFF), None);
+    }
+
+    #[test]
+    fn test_player_reports_pattern() {
+        assert_eq!(PatternPlayer::new(Pattern::Sos).pattern(), Pattern::Sos);
+    }
+
+    // ==================== Sequence Tests ====================
+
+    #[test]
+    fn test_sequence_chains_and_stops() {
+        let mut sequence = Sequence::new([(Pattern::Steady, 2), (Pattern::Heartbeat, 1)]);
+        let mut steps = Vec::new();
+        while let Some(step) = sequence.next_step() {
+            steps.push(step);
+        }
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&STEADY_STEPS);
+        expected.extend_from_slice(&STEADY_STEPS);
+        expected.extend_from_slice(&HEARTBEAT_STEPS);
+        assert_eq!(steps, expected);
+        assert!(sequence.finished());
+        assert_eq!(sequence.next_step(), None);
+    }
+
+    #[test]
+    fn test_sequence_skips_zero_repeats() {
+        let mut sequence =
+            Sequence::new([(Pattern::Sos, 0), (Pattern::Steady, 1), (Pattern::Sos, 0)]);
+        assert_eq!(sequence.next_step(), Some(STEADY_STEPS[0]));
+        assert_eq!(sequence.next_step(), Some(STEADY_STEPS[1]));
+        assert!(sequence.finished());
+    }
+
+    #[test]
+    fn test_empty_sequence_finished() {
+        let mut sequence: Sequence<0> = Sequence::new([]);
+        assert!(sequence.finished());
+        assert_eq!(sequence.next_step(), None);
+    }
+
+    // ==================== Playlist Tests ====================
+
+    #[test]
+    fn test_playlist_stays_within_slot() {
+        let mut playlist =
+            PatternPlaylist::new([(Pattern::Steady, 5000), (Pattern::Heartbeat, 5000)]);
+        assert_eq!(playlist.advance(4999), None);
+        assert_eq!(playlist.current(), Some(Pattern::Steady));
+    }
+
+    #[test]
+    fn test_playlist_crosses_boundary_and_wraps() {
+        let mut playlist = PatternPlaylist::new([
+            (Pattern::Steady, 5000),
+            (Pattern::Heartbeat, 5000),
+            (Pattern::Sos, 2000),
+        ]);
+        assert_eq!(playlist.advance(5000), Some(Pattern::Heartbeat));
+        assert_eq!(playlist.advance(6000), Some(Pattern::Sos));
+        assert_eq!(playlist.advance(1000), Some(Pattern::Steady));
+        assert_eq!(playlist.advance(4999), None);
+    }
+
+    #[test]
+    fn test_playlist_skips_zero_duration_slot() {
+        let mut playlist = PatternPlaylist::new([
+            (Pattern::Steady, 100),
+            (Pattern::Heartbeat, 0),
+            (Pattern::Sos, 100),
+        ]);
+        assert_eq!(playlist.advance(100), Some(Pattern::Sos));
+    }
+
+    #[test]
+    fn test_playlist_all_zero_does_nothing() {
+        let mut playlist = PatternPlaylist::new([(Pattern::Steady, 0), (Pattern::Sos, 0)]);
+        assert_eq!(playlist.advance(1000), None);
+        assert_eq!(playlist.current(), Some(Pattern::Steady));
+    }
+
+    #[test]
+    fn test_playlist_huge_durations_do_not_overflow() {
+        let mut playlist =
+            PatternPlaylist::new([(Pattern::Steady, u64::MAX), (Pattern::Sos, u64::MAX)]);
+        assert_eq!(playlist.advance(u64::MAX - 1), None);
+        assert_eq!(playlist.advance(1), Some(Pattern::Sos));
+        assert_eq!(playlist.advance(u64::MAX), None);
+        assert_eq!(playlist.current(), Some(Pattern::Sos));
+    }
+
+    #[test]
+    fn test_empty_playlist() {
+        let mut playlist: PatternPlaylist<0> = PatternPlaylist::new([]);
+        assert_eq!(playlist.advance(10), None);
+        assert_eq!(playlist.current(), None);
+    }
+
+    #[test]
+    fn test_compound_flickers_then_long_off() {
+        let mut compound = Compound::new(3, 30, 900);
+        let steps: [(LedState, u64); 12] = capture_steps(&mut compound);
+        let cycle = [
+            (LedState::On, 30),
+            (LedState::Off, 30),
+            (LedState::On, 30),
+            (LedState::Off, 30),
+            (LedState::On, 30),
+            (LedState::Off, 900),
+        ];
+        assert_eq!(&steps[..6], &cycle);
+        assert_eq!(&steps[6..], &cycle);
+    }
+
+    #[test]
+    fn test_compound_single_flicker() {
+        let mut compound = Compound::new(1, 30, 900);
+        let steps: [(LedState, u64); 4] = capture_steps(&mut compound);
+        assert_eq!(
+            steps,
+            [
+                (LedState::On, 30),
+                (LedState::Off, 900),
+                (LedState::On, 30),
+                (LedState::Off, 900),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compound_zero_flickers_stays_dark() {
+        let mut compound = Compound::new(0, 30, 900);
+        assert_eq!(compound.next_step(), (LedState::Off, 900));
+        assert_eq!(compound.next_step(), (LedState::Off, 900));
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ * @file phase_lock.rs
+ * @brief Reference clock phase lock
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: phase_lock.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Reference Clock Phase Lock.
+//!
+//! BRIEF:
+//! Nudges blink timing to align toggles with external reference edges.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{BLINK_DELAY_MS, PHASE_LOCK_GAIN_PERCENT};
+
+/// Phase lock helper aligning toggles to a reference clock.
+///
+/// # Details
+/// Compares each toggle time against the latest reference edge and
+/// shortens or lengthens the next delay to pull toggles onto edges.
+///
+/// # Fields
+/// * `period_ms` - Nominal delay between toggles
+/// * `gain_percent` - Fraction of phase error corrected per toggle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct PhaseLock {
+    period_ms: u64,
+    gain_percent: u64,
+}
+
+impl Default for PhaseLock {
+    /// Returns default PhaseLock instance.
+    ///
+    /// # Details
+    /// Uses the default blink delay and configured gain.
+    ///
+    /// # Returns
+    /// * `Self` - New PhaseLock with default settings
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new(BLINK_DELAY_MS, PHASE_LOCK_GAIN_PERCENT)
+    }
+}
+
+impl PhaseLock {
+    /// Creates new phase lock.
+    ///
+    /// # Details
+    /// Gain is limited to 100 percent.
+    ///
+    /// # Arguments
+    /// * `period_ms` - Nominal delay between toggles
+    /// * `gain_percent` - Fraction of phase error corrected per toggle
+    ///
+    /// # Returns
+    /// * `Self` - New PhaseLock instance
+    #[allow(dead_code)]
+    pub fn new(period_ms: u64, gain_percent: u64) -> Self {
+        Self {
+            period_ms,
+            gain_percent: gain_percent.min(100),
+        }
+    }
+
+    /// Returns corrected delay until the next toggle.
+    ///
+    /// # Details
+    /// The phase error is the distance from the toggle to the nearest
+    /// reference-aligned instant. A toggle lagging the reference gets a
+    /// shorter delay; one leading it gets a longer delay.
+    ///
+    /// # Arguments
+    /// * `ref_edge_ms` - Timestamp of the latest reference edge
+    /// * `now_ms` - Timestamp of the toggle just performed
+    ///
+    /// # Returns
+    /// * `u64` - Corrected delay in milliseconds
+    #[allow(dead_code)]
+    pub fn align(&self, ref_edge_ms: u64, now_ms: u64) -> u64 {
+        if self.period_ms == 0 {
+            return 0;
+        }
+        let offset = now_ms as i128 - ref_edge_ms as i128;
+        let error = offset.rem_euclid(self.period_ms as i128) as u64;
+        if error <= self.period_ms / 2 {
+            self.period_ms - error * self.gain_percent / 100
+        } else {
+            let lead = self.period_ms - error;
+            self.period_ms + lead * self.gain_percent / 100
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Alignment Tests ====================
+
+    #[test]
+    fn test_aligned_keeps_period() {
+        let lock = PhaseLock::new(500, 50);
+        assert_eq!(lock.align(1000, 1000), 500);
+        assert_eq!(lock.align(1000, 1500), 500);
+    }
+
+    #[test]
+    fn test_lagging_shortens_delay() {
+        let lock = PhaseLock::new(500, 50);
+        assert_eq!(lock.align(1000, 1040), 480);
+    }
+
+    #[test]
+    fn test_leading_lengthens_delay() {
+        let lock = PhaseLock::new(500, 50);
+        assert_eq!(lock.align(1000, 1460), 520);
+    }
+
+    #[test]
+    fn test_full_gain_snaps_to_edge() {
+        let lock = PhaseLock::new(500, 100);
+        assert_eq!(lock.align(1000, 1040), 460);
+        assert_eq!(lock.align(1000, 1460), 540);
+    }
+
+    #[test]
+    fn test_edge_after_toggle() {
+        let lock = PhaseLock::new(500, 50);
+        assert_eq!(lock.align(1040, 1000), 520);
+    }
+
+    #[test]
+    fn test_converges_onto_reference() {
+        let lock = PhaseLock::new(500, 50);
+        let mut now = 1100;
+        for _ in 0..12 {
+            now += lock.align(0, now);
+        }
+        let error = now % 500;
+        assert!(!(2..=498).contains(&error));
+    }
+
+    #[test]
+    fn test_gain_clamped() {
+        assert_eq!(PhaseLock::new(500, 300), PhaseLock::new(500, 100));
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * @file polarity.rs
+ * @brief LED polarity selection
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: polarity.rs
+//!
+//! DESCRIPTION:
+//! RP2350 LED Polarity Selection.
+//!
+//! BRIEF:
+//! Defines LED polarity, its level mapping and a detection stub
+//! driven by a sense reading.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::POLARITY_SENSE_THRESHOLD;
+use crate::led::{led_state_to_level, LedState};
+
+/// LED polarity enumeration.
+///
+/// # Details
+/// Describes which pin level lights the LED.
+///
+/// # Variants
+/// * `ActiveHigh` - LED is lit when the pin is high
+/// * `ActiveLow` - LED is lit when the pin is low
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Polarity {
+    ActiveHigh,
+    ActiveLow,
+}
+
+impl Default for Polarity {
+    /// Returns default Polarity.
+    ///
+    /// # Details
+    /// Boards without a sense reading are assumed active-high.
+    ///
+    /// # Returns
+    /// * `Self` - ActiveHigh
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Polarity::ActiveHigh
+    }
+}
+
+impl Polarity {
+    /// Returns the pin level for an LED state.
+    ///
+    /// # Details
+    /// Inverts the level for active-low LEDs.
+    ///
+    /// # Arguments
+    /// * `state` - LED state to express
+    ///
+    /// # Returns
+    /// * `bool` - true to drive the pin high, false for low
+    #[allow(dead_code)]
+    pub fn level_for(self, state: LedState) -> bool {
+        match self {
+            Polarity::ActiveHigh => led_state_to_level(state),
+            Polarity::ActiveLow => !led_state_to_level(state),
+        }
+    }
+}
+
+/// Returns the pin level for an LED state with live inversion.
+///
+/// # Details
+/// XORs the polarity-mapped level with the invert flag so a set
+/// flag swaps on and off.
+///
+/// # Arguments
+/// * `polarity` - LED polarity
+/// * `state` - LED state to express
+/// * `invert` - true to swap on and off
+///
+/// # Returns
+/// * `bool` - true to drive the pin high, false for low
+#[allow(dead_code)]
+pub fn next_level(polarity: Polarity, state: LedState, invert: bool) -> bool {
+    polarity.level_for(state) ^ invert
+}
+
+/// Decides LED polarity from an optional sense reading.
+///
+/// # Details
+/// Stub for a probe routine sampling the idle LED pin.
+/// A reading above the threshold means the pin is pulled toward the
+/// supply through the LED, so the LED is active-low.
+/// Without a reading the default polarity is returned.
+///
+/// # Arguments
+/// * `reading` - Optional idle ADC reading of the LED pin
+///
+/// # Returns
+/// * `Polarity` - Detected or default polarity
+#[allow(dead_code)]
+pub fn detect_polarity(reading: Option<u16>) -> Polarity {
+    match reading {
+        Some(value) if value > POLARITY_SENSE_THRESHOLD => Polarity::ActiveLow,
+        Some(_) => Polarity::ActiveHigh,
+        None => Polarity::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Level Mapping Tests ====================
+
+    #[test]
+    fn test_level_for_active_high() {
+        assert!(Polarity::ActiveHigh.level_for(LedState::On));
+        assert!(!Polarity::ActiveHigh.level_for(LedState::Off));
+    }
+
+    #[test]
+    fn test_level_for_active_low() {
+        assert!(!Polarity::ActiveLow.level_for(LedState::On));
+        assert!(Polarity::ActiveLow.level_for(LedState::Off));
+    }
+
+    #[test]
+    fn test_next_level_without_invert_matches_polarity() {
+        for polarity in [Polarity::ActiveHigh, Polarity::ActiveLow] {
+            for state in [LedState::On, LedState::Off] {
+                assert_eq!(
+                    next_level(polarity, state, false),
+                    polarity.level_for(state)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_invert_swaps_level_for_each_state() {
+        for polarity in [Polarity::ActiveHigh, Polarity::ActiveLow] {
+            for state in [LedState::On, LedState::Off] {
+                assert_eq!(
+                    next_level(polarity, state, true),
+                    !next_level(polarity, state, false)
+                );
+            }
+        }
+        assert!(!next_level(Polarity::ActiveHigh, LedState::On, true));
+        assert!(next_level(Polarity::ActiveHigh, LedState::Off, true));
+    }
+
+    // ==================== Polarity Detection Tests ====================
+
+    #[test]
+    fn test_default_is_active_high() {
+        assert_eq!(Polarity::default(), Polarity::ActiveHigh);
+    }
+
+    #[test]
+    fn test_no_reading_returns_default() {
+        assert_eq!(detect_polarity(None), Polarity::default());
+    }
+
+    #[test]
+    fn test_reading_above_threshold_is_active_low() {
+        assert_eq!(
+            detect_polarity(Some(POLARITY_SENSE_THRESHOLD + 1)),
+            Polarity::ActiveLow
+        );
+        assert_eq!(detect_polarity(Some(u16::MAX)), Polarity::ActiveLow);
+    }
+
+    #[test]
+    fn test_reading_below_threshold_is_active_high() {
+        assert_eq!(detect_polarity(Some(0)), Polarity::ActiveHigh);
+        assert_eq!(
+            detect_polarity(Some(POLARITY_SENSE_THRESHOLD)),
+            Polarity::ActiveHigh
+        );
+    }
+}
@@ -0,0 +1,206 @@
+/*
+ * @file profile.rs
+ * @brief Blink profile presets
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: profile.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Blink Profile Presets.
+//!
+//! BRIEF:
+//! Bundles pattern, timing, intensity and polarity into switchable profiles.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{
+    BLINK_DELAY_MS, DEFAULT_DUTY_PERCENT, DEFAULT_INTENSITY, HEARTBEAT_LED_DELAY_MS,
+};
+use crate::led::LedController;
+use crate::pattern::Pattern;
+
+/// Blink profile bundling pattern, timing and intensity.
+///
+/// # Details
+/// Applied to a controller in one step via apply_profile.
+///
+/// # Fields
+/// * `pattern` - Blink pattern
+/// * `delay_ms` - Blink delay in milliseconds
+/// * `duty` - PWM duty cycle in percent while lit
+/// * `intensity` - Logical brightness level (0-255)
+/// * `active_low` - true if the LED is lit by a low pin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct BlinkProfile {
+    pub pattern: Pattern,
+    pub delay_ms: u64,
+    pub duty: u8,
+    pub intensity: u8,
+    pub active_low: bool,
+}
+
+/// Default steady blink profile.
+#[allow(dead_code)]
+pub const PROFILE_DEFAULT: BlinkProfile = BlinkProfile {
+    pattern: Pattern::Steady,
+    delay_ms: BLINK_DELAY_MS,
+    duty: DEFAULT_DUTY_PERCENT,
+    intensity: DEFAULT_INTENSITY,
+    active_low: false,
+};
+
+/// Full-brightness SOS distress profile.
+#[allow(dead_code)]
+pub const PROFILE_SOS: BlinkProfile = BlinkProfile {
+    pattern: Pattern::Sos,
+    delay_ms: 200,
+    duty: 100,
+    intensity: 255,
+    active_low: false,
+};
+
+/// Dimmed heartbeat profile for an idle indicator.
+#[allow(dead_code)]
+pub const PROFILE_HEARTBEAT: BlinkProfile = BlinkProfile {
+    pattern: Pattern::Heartbeat,
+    delay_ms: 1000,
+    duty: 40,
+    intensity: 128,
+    active_low: false,
+};
+
+/// Slow 1 Hz profile for the alive heartbeat LED.
+#[allow(dead_code)]
+pub const PROFILE_ALIVE: BlinkProfile = BlinkProfile {
+    pattern: Pattern::Steady,
+    delay_ms: HEARTBEAT_LED_DELAY_MS,
+    duty: DEFAULT_DUTY_PERCENT,
+    intensity: DEFAULT_INTENSITY,
+    active_low: false,
+};
+
+/// Creates the controller for the alive heartbeat LED.
+///
+/// # Details
+/// Independent of the main indicator controller.
+///
+/// # Returns
+/// * `LedController` - Controller preset to PROFILE_ALIVE
+#[allow(dead_code)]
+pub fn alive_controller() -> LedController {
+    let mut controller = LedController::new();
+    controller.apply_profile(&PROFILE_ALIVE);
+    controller
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{MAX_BLINK_DELAY_MS, MIN_BLINK_DELAY_MS};
+    use crate::led::LedState;
+    use crate::polarity::Polarity;
+
+    // ==================== Profile Application Tests ====================
+
+    #[test]
+    fn test_apply_sos_profile() {
+        let mut ctrl = LedController::new();
+        ctrl.apply_profile(&PROFILE_SOS);
+        assert_eq!(ctrl.pattern(), Pattern::Sos);
+        assert_eq!(ctrl.delay_ms(), 200);
+        assert_eq!(ctrl.duty(), 100);
+        assert_eq!(ctrl.intensity(), 255);
+        assert_eq!(ctrl.polarity(), Polarity::ActiveHigh);
+    }
+
+    #[test]
+    fn test_apply_heartbeat_profile() {
+        let mut ctrl = LedController::new();
+        ctrl.apply_profile(&PROFILE_HEARTBEAT);
+        assert_eq!(ctrl.pattern(), Pattern::Heartbeat);
+        assert_eq!(ctrl.delay_ms(), 1000);
+        assert_eq!(ctrl.duty(), 40);
+        assert_eq!(ctrl.intensity(), 128);
+    }
+
+    #[test]
+    fn test_apply_default_profile_matches_new() {
+        let mut ctrl = LedController::new();
+        ctrl.apply_profile(&PROFILE_SOS);
+        ctrl.apply_profile(&PROFILE_DEFAULT);
+        assert_eq!(ctrl, LedController::new());
+    }
+
+    #[test]
+    fn test_apply_active_low_profile() {
+        let mut ctrl = LedController::new();
+        ctrl.apply_profile(&BlinkProfile {
+            active_low: true,
+            ..PROFILE_DEFAULT
+        });
+        assert_eq!(ctrl.polarity(), Polarity::ActiveLow);
+    }
+
+    #[test]
+    fn test_apply_profile_clamps_values() {
+        let mut ctrl = LedController::new();
+        ctrl.apply_profile(&BlinkProfile {
+            delay_ms: 0,
+            duty: 250,
+            ..PROFILE_DEFAULT
+        });
+        assert_eq!(ctrl.delay_ms(), MIN_BLINK_DELAY_MS);
+        assert_eq!(ctrl.duty(), 100);
+        ctrl.apply_profile(&BlinkProfile {
+            delay_ms: u64::MAX,
+            ..PROFILE_DEFAULT
+        });
+        assert_eq!(ctrl.delay_ms(), MAX_BLINK_DELAY_MS);
+    }
+
+    #[test]
+    fn test_alive_controller_timing() {
+        let mut ctrl = alive_controller();
+        assert_eq!(ctrl.pattern(), Pattern::Steady);
+        assert_eq!(ctrl.delay_ms(), 500);
+        let on = ctrl.tick();
+        let off = ctrl.tick();
+        assert_eq!(on.delay_ms + off.delay_ms, 1000);
+    }
+
+    #[test]
+    fn test_apply_profile_preserves_state() {
+        let mut ctrl = LedController::new();
+        ctrl.toggle();
+        ctrl.apply_profile(&PROFILE_HEARTBEAT);
+        assert_eq!(ctrl.toggle_count(), 1);
+        assert_eq!(ctrl.toggle(), LedState::Off);
+    }
+}
@@ -0,0 +1,641 @@
+/*
+ * @file pwm.rs
+ * @brief PWM duty and brightness math
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: pwm.rs
+//!
+//! DESCRIPTION:
+//! RP2350 PWM Duty and Brightness Math.
+//!
+//! BRIEF:
+//! Provides integer brightness curves and duty helpers for PWM modes.
+//! Includes time-weighted average duty reporting for diagnostics.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::SOFT_START_RAMP_MS;
+
+/// Maximum brightness level.
+const LEVEL_MAX: u64 = u8::MAX as u64;
+
+/// Brightness curve enumeration.
+///
+/// # Details
+/// Selects how a logical brightness level maps to PWM duty.
+/// The eye is far more sensitive at low duty, so steeper curves
+/// give a more even perceived fade.
+///
+/// # Variants
+/// * `Linear` - Duty proportional to level
+/// * `Gamma` - Gamma 2.0 curve (level squared)
+/// * `Logarithmic` - Steep perceptual curve (cubic approximation)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Curve {
+    Linear,
+    Gamma,
+    Logarithmic,
+}
+
+/// Maps a brightness level to a PWM compare value.
+///
+/// # Details
+/// Applies the selected curve using integer math only.
+/// Level 0 always yields 0 and level 255 always yields top.
+///
+/// # Arguments
+/// * `curve` - Brightness curve to apply
+/// * `level` - Logical brightness (0-255)
+/// * `top` - PWM counter top value
+///
+/// # Returns
+/// * `u16` - Compare value in 0..=top
+#[allow(dead_code)]
+pub fn map_brightness(curve: Curve, level: u8, top: u16) -> u16 {
+    let level = u64::from(level);
+    let top = u64::from(top);
+    let duty = match curve {
+        Curve::Linear => level * top / LEVEL_MAX,
+        Curve::Gamma => level * level * top / (LEVEL_MAX * LEVEL_MAX),
+        Curve::Logarithmic => level * level * level * top / (LEVEL_MAX * LEVEL_MAX * LEVEL_MAX),
+    };
+    duty as u16
+}
+
+/// Samples a brightness curve for tuning previews.
+///
+/// # Details
+/// Evaluates the curve at N evenly spaced levels from 0 to 255, the
+/// rising half of a breathe cycle; the falling half mirrors it.
+/// The first sample is always 0 and the last is top when N > 1.
+///
+/// # Arguments
+/// * `curve` - Brightness curve to sample
+/// * `top` - PWM counter top value
+///
+/// # Returns
+/// * `[u16; N]` - Compare values in rising order
+#[allow(dead_code)]
+pub fn preview_curve<const N: usize>(curve: Curve, top: u16) -> [u16; N] {
+    let last = N.saturating_sub(1).max(1) as u64;
+    core::array::from_fn(|i| {
+        let level = (i as u64 * LEVEL_MAX / last) as u8;
+        map_brightness(curve, level, top)
+    })
+}
+
+/// Fixed-point duty fraction in parts per thousand.
+///
+/// # Details
+/// Gives ten times the resolution of a percentage without floats.
+/// Values are always within 0..=1000.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+pub struct Permille(u16);
+
+impl Permille {
+    /// Full-scale value.
+    #[allow(dead_code)]
+    pub const MAX: Permille = Permille(1000);
+
+    /// Creates new permille value.
+    ///
+    /// # Details
+    /// Values above 1000 saturate to full scale.
+    ///
+    /// # Arguments
+    /// * `value` - Parts per thousand
+    ///
+    /// # Returns
+    /// * `Self` - New Permille instance
+    #[allow(dead_code)]
+    pub const fn new(value: u16) -> Self {
+        if value > 1000 {
+            Self(1000)
+        } else {
+            Self(value)
+        }
+    }
+
+    /// Creates permille value from a percentage.
+    ///
+    /// # Details
+    /// Percentages above 100 saturate to full scale.
+    ///
+    /// # Arguments
+    /// * `percent` - Duty in percent
+    ///
+    /// # Returns
+    /// * `Self` - Equivalent Permille value
+    #[allow(dead_code)]
+    pub const fn from_percent(percent: u8) -> Self {
+        Self::new(percent as u16 * 10)
+    }
+
+    /// Returns the raw parts-per-thousand value.
+    ///
+    /// # Returns
+    /// * `u16` - Value in 0..=1000
+    #[allow(dead_code)]
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    /// Converts to a PWM compare value.
+    ///
+    /// # Details
+    /// Uses u32 intermediate math so no precision is lost before
+    /// the final division and nothing can overflow.
+    ///
+    /// # Arguments
+    /// * `top` - PWM counter top value
+    ///
+    /// # Returns
+    /// * `u16` - Compare value in 0..=top
+    #[allow(dead_code)]
+    pub const fn to_compare(self, top: u16) -> u16 {
+        (top as u32 * self.0 as u32 / 1000) as u16
+    }
+}
+
+impl From<u8> for Permille {
+    /// Converts a percentage to permille.
+    ///
+    /// # Arguments
+    /// * `percent` - Duty in percent
+    ///
+    /// # Returns
+    /// * `Self` - Equivalent Permille value
+    fn from(percent: u8) -> Self {
+        Self::from_percent(percent)
+    }
+}
+
+/// Converts a duty percentage to a PWM compare value.
+///
+/// # Details
+/// Duty is capped at 100 percent. With inversion the result is
+/// subtracted from top for common-anode LEDs.
+///
+/// # Arguments
+/// * `duty_percent` - Duty cycle in percent
+/// * `top` - PWM counter top value
+/// * `duty_invert` - true for common-anode wiring
+///
+/// # Returns
+/// * `u16` - Compare value in 0..=top
+#[allow(dead_code)]
+pub fn duty_to_compare(duty_percent: u8, top: u16, duty_invert: bool) -> u16 {
+    let compare = Permille::from_percent(duty_percent).to_compare(top);
+    if duty_invert {
+        top - compare
+    } else {
+        compare
+    }
+}
+
+/// Returns whether a dither frame is lit.
+///
+/// # Details
+/// Bresenham-style temporal dither: over every window of 256 frames
+/// exactly `level` frames are lit, spread as evenly as possible.
+/// Smooths perceived brightness where PWM resolution is coarse.
+///
+/// # Arguments
+/// * `level` - Brightness level (0-255)
+/// * `frame` - Frame counter
+///
+/// # Returns
+/// * `bool` - true if the LED is lit in this frame
+#[allow(dead_code)]
+pub fn dither_step(level: u8, frame: u32) -> bool {
+    let f = frame % 256;
+    let level = u32::from(level);
+    ((f + 1) * level) >> 8 != (f * level) >> 8
+}
+
+/// Time-weighted average duty accumulator.
+///
+/// # Details
+/// Accumulates duty samples weighted by how long each was held.
+/// Reports the effective average brightness over the window.
+///
+/// # Fields
+/// * `weighted_sum` - Sum of duty times duration
+/// * `total_ms` - Sum of sample durations
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct AvgDuty {
+    weighted_sum: u64,
+    total_ms: u64,
+}
+
+impl AvgDuty {
+    /// Creates new empty accumulator.
+    ///
+    /// # Returns
+    /// * `Self` - New AvgDuty instance
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a duty sample held for a duration.
+    ///
+    /// # Details
+    /// Saturates rather than overflowing on very long windows.
+    ///
+    /// # Arguments
+    /// * `duty` - Duty value held during the interval
+    /// * `dt_ms` - Interval length in milliseconds
+    #[allow(dead_code)]
+    pub fn sample(&mut self, duty: u16, dt_ms: u64) {
+        let weighted = u64::from(duty).saturating_mul(dt_ms);
+        self.weighted_sum = self.weighted_sum.saturating_add(weighted);
+        self.total_ms = self.total_ms.saturating_add(dt_ms);
+    }
+
+    /// Returns the time-weighted average duty.
+    ///
+    /// # Returns
+    /// * `u16` - Average duty, or 0 if no time has been sampled
+    #[allow(dead_code)]
+    pub fn average(&self) -> u16 {
+        match self.total_ms {
+            0 => 0,
+            total => (self.weighted_sum / total) as u16,
+        }
+    }
+
+    /// Returns the average and resets the window.
+    ///
+    /// # Returns
+    /// * `u16` - Average duty of the window just closed
+    #[allow(dead_code)]
+    pub fn take_average(&mut self) -> u16 {
+        let average = self.average();
+        *self = Self::new();
+        average
+    }
+}
+
+/// Soft start ramp for inrush-sensitive LEDs.
+///
+/// # Details
+/// Intercepts the first enable and ramps duty linearly from 0 to the
+/// target over the ramp time, then passes the target through.
+/// Later enables are not ramped.
+///
+/// # Fields
+/// * `target` - Target duty in percent
+/// * `ramp_ms` - Ramp duration in milliseconds
+/// * `elapsed_ms` - Time since the first enable
+/// * `enabled` - true once enable() has been called
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct SoftStart {
+    target: u8,
+    ramp_ms: u64,
+    elapsed_ms: u64,
+    enabled: bool,
+}
+
+impl SoftStart {
+    /// Creates new soft start with the configured ramp time.
+    ///
+    /// # Arguments
+    /// * `target` - Target duty in percent
+    ///
+    /// # Returns
+    /// * `Self` - New SoftStart instance
+    #[allow(dead_code)]
+    pub fn new(target: u8) -> Self {
+        Self::with_ramp(target, SOFT_START_RAMP_MS)
+    }
+
+    /// Creates new soft start with an explicit ramp time.
+    ///
+    /// # Arguments
+    /// * `target` - Target duty in percent
+    /// * `ramp_ms` - Ramp duration in milliseconds
+    ///
+    /// # Returns
+    /// * `Self` - New SoftStart instance
+    #[allow(dead_code)]
+    pub fn with_ramp(target: u8, ramp_ms: u64) -> Self {
+        Self {
+            target,
+            ramp_ms,
+            elapsed_ms: 0,
+            enabled: false,
+        }
+    }
+
+    /// Enables the output, starting the ramp on first use.
+    #[allow(dead_code)]
+    pub fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    /// Advances ramp time.
+    ///
+    /// # Arguments
+    /// * `elapsed_ms` - Time since the previous advance
+    #[allow(dead_code)]
+    pub fn advance(&mut self, elapsed_ms: u64) {
+        if self.enabled {
+            self.elapsed_ms = self.elapsed_ms.saturating_add(elapsed_ms);
+        }
+    }
+
+    /// Sets the target duty.
+    ///
+    /// # Arguments
+    /// * `target` - Target duty in percent
+    #[allow(dead_code)]
+    pub fn set_target(&mut self, target: u8) {
+        self.target = target;
+    }
+
+    /// Returns the duty to apply now.
+    ///
+    /// # Returns
+    /// * `u8` - 0 before enable, ramping during the ramp, then target
+    #[allow(dead_code)]
+    pub fn current_duty(&self) -> u8 {
+        if !self.enabled {
+            0
+        } else if self.elapsed_ms >= self.ramp_ms {
+            self.target
+        } else {
+            (u128::from(self.target) * u128::from(self.elapsed_ms) / u128::from(self.ramp_ms)) as u8
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Brightness Curve Tests ====================
+
+    #[test]
+    fn test_curves_share_endpoints() {
+        for curve in [Curve::Linear, Curve::Gamma, Curve::Logarithmic] {
+            assert_eq!(map_brightness(curve, 0, 1000), 0);
+            assert_eq!(map_brightness(curve, 255, 1000), 1000);
+        }
+    }
+
+    #[test]
+    fn test_curves_at_midpoint() {
+        assert_eq!(map_brightness(Curve::Linear, 128, 65535), 32896);
+        assert_eq!(map_brightness(Curve::Gamma, 128, 65535), 16512);
+        assert_eq!(map_brightness(Curve::Logarithmic, 128, 65535), 8288);
+    }
+
+    #[test]
+    fn test_curves_ordered_at_same_level() {
+        for level in 1..255 {
+            let linear = map_brightness(Curve::Linear, level, 10000);
+            let gamma = map_brightness(Curve::Gamma, level, 10000);
+            let log = map_brightness(Curve::Logarithmic, level, 10000);
+            assert!(linear >= gamma);
+            assert!(gamma >= log);
+        }
+    }
+
+    #[test]
+    fn test_preview_endpoints_match_min_max() {
+        for curve in [Curve::Linear, Curve::Gamma, Curve::Logarithmic] {
+            let preview: [u16; 16] = preview_curve(curve, 4095);
+            assert_eq!(preview[0], 0);
+            assert_eq!(preview[15], 4095);
+        }
+    }
+
+    #[test]
+    fn test_preview_samples_curve() {
+        let preview: [u16; 3] = preview_curve(Curve::Linear, 65535);
+        assert_eq!(
+            preview,
+            [0, map_brightness(Curve::Linear, 127, 65535), 65535]
+        );
+        let single: [u16; 1] = preview_curve(Curve::Gamma, 1000);
+        assert_eq!(single, [0]);
+    }
+
+    #[test]
+    fn test_curves_monotonic() {
+        for curve in [Curve::Linear, Curve::Gamma, Curve::Logarithmic] {
+            let mut last = 0;
+            for level in 0..=255 {
+                let duty = map_brightness(curve, level, 4095);
+                assert!(duty >= last);
+                last = duty;
+            }
+        }
+    }
+
+    // ==================== Permille Tests ====================
+
+    #[test]
+    fn test_permille_known_compares() {
+        assert_eq!(Permille::new(250).to_compare(1000), 250);
+        assert_eq!(Permille::new(250).to_compare(u16::MAX), 16383);
+        assert_eq!(Permille::new(999).to_compare(1000), 999);
+        assert_eq!(Permille::new(999).to_compare(u16::MAX), 65469);
+    }
+
+    #[test]
+    fn test_permille_full_scale_no_overflow() {
+        assert_eq!(Permille::MAX.to_compare(u16::MAX), u16::MAX);
+        assert_eq!(Permille::new(0).to_compare(u16::MAX), 0);
+    }
+
+    #[test]
+    fn test_permille_saturates() {
+        assert_eq!(Permille::new(5000), Permille::MAX);
+        assert_eq!(Permille::from_percent(200), Permille::MAX);
+    }
+
+    #[test]
+    fn test_permille_from_percent() {
+        assert_eq!(Permille::from_percent(25).get(), 250);
+        assert_eq!(Permille::from(100u8), Permille::MAX);
+    }
+
+    // ==================== Duty Compare Tests ====================
+
+    #[test]
+    fn test_duty_to_compare_normal() {
+        assert_eq!(duty_to_compare(0, 1000, false), 0);
+        assert_eq!(duty_to_compare(50, 1000, false), 500);
+        assert_eq!(duty_to_compare(100, 1000, false), 1000);
+    }
+
+    #[test]
+    fn test_duty_to_compare_inverted() {
+        assert_eq!(duty_to_compare(0, 1000, true), 1000);
+        assert_eq!(duty_to_compare(25, 1000, true), 750);
+        assert_eq!(duty_to_compare(100, 1000, true), 0);
+    }
+
+    #[test]
+    fn test_duty_to_compare_caps_percent() {
+        assert_eq!(duty_to_compare(200, u16::MAX, false), u16::MAX);
+        assert_eq!(duty_to_compare(200, u16::MAX, true), 0);
+    }
+
+    // ==================== Dither Tests ====================
+
+    #[test]
+    fn test_dither_on_count_matches_level() {
+        for level in [0u8, 1, 3, 64, 100, 128, 255] {
+            let on = (0..256).filter(|&f| dither_step(level, f)).count();
+            assert_eq!(on, usize::from(level));
+        }
+    }
+
+    #[test]
+    fn test_dither_spreads_evenly() {
+        let lit: Vec<u32> = (0..256).filter(|&f| dither_step(64, f)).collect();
+        for pair in lit.windows(2) {
+            assert_eq!(pair[1] - pair[0], 4);
+        }
+    }
+
+    #[test]
+    fn test_dither_repeats_each_window() {
+        for f in 0..256 {
+            assert_eq!(dither_step(37, f), dither_step(37, f + 256));
+        }
+    }
+
+    // ==================== AvgDuty Tests ====================
+
+    #[test]
+    fn test_avg_duty_empty() {
+        assert_eq!(AvgDuty::new().average(), 0);
+    }
+
+    #[test]
+    fn test_avg_duty_constant() {
+        let mut avg = AvgDuty::new();
+        avg.sample(300, 10);
+        avg.sample(300, 90);
+        assert_eq!(avg.average(), 300);
+    }
+
+    #[test]
+    fn test_avg_duty_time_weighted() {
+        let mut avg = AvgDuty::new();
+        avg.sample(1000, 250);
+        avg.sample(0, 750);
+        assert_eq!(avg.average(), 250);
+        avg.sample(4000, 1000);
+        assert_eq!(avg.average(), 2125);
+    }
+
+    #[test]
+    fn test_avg_duty_zero_duration_ignored() {
+        let mut avg = AvgDuty::new();
+        avg.sample(500, 100);
+        avg.sample(u16::MAX, 0);
+        assert_eq!(avg.average(), 500);
+    }
+
+    #[test]
+    fn test_avg_duty_take_resets() {
+        let mut avg = AvgDuty::new();
+        avg.sample(800, 50);
+        assert_eq!(avg.take_average(), 800);
+        assert_eq!(avg.average(), 0);
+        avg.sample(100, 10);
+        assert_eq!(avg.average(), 100);
+    }
+
+    // ==================== SoftStart Tests ====================
+
+    #[test]
+    fn test_soft_start_off_before_enable() {
+        let mut soft = SoftStart::with_ramp(80, 100);
+        soft.advance(500);
+        assert_eq!(soft.current_duty(), 0);
+    }
+
+    #[test]
+    fn test_soft_start_ramps_stepwise_then_holds() {
+        let mut soft = SoftStart::with_ramp(80, 100);
+        soft.enable();
+        let mut duties = vec![soft.current_duty()];
+        for _ in 0..6 {
+            soft.advance(25);
+            duties.push(soft.current_duty());
+        }
+        assert_eq!(duties, vec![0, 20, 40, 60, 80, 80, 80]);
+    }
+
+    #[test]
+    fn test_soft_start_second_enable_passes_through() {
+        let mut soft = SoftStart::with_ramp(50, 100);
+        soft.enable();
+        soft.advance(100);
+        soft.enable();
+        assert_eq!(soft.current_duty(), 50);
+        soft.set_target(90);
+        assert_eq!(soft.current_duty(), 90);
+    }
+
+    #[test]
+    fn test_soft_start_long_ramp_does_not_overflow() {
+        let mut soft = SoftStart::with_ramp(200, u64::MAX);
+        soft.enable();
+        soft.advance(u64::MAX / 2);
+        assert_eq!(soft.current_duty(), 99);
+        soft.advance(u64::MAX);
+        assert_eq!(soft.current_duty(), 200);
+    }
+
+    #[test]
+    fn test_soft_start_zero_ramp_immediate() {
+        let mut soft = SoftStart::with_ramp(70, 0);
+        soft.enable();
+        assert_eq!(soft.current_duty(), 70);
+    }
+
+    #[test]
+    fn test_soft_start_default_ramp() {
+        assert_eq!(
+            SoftStart::new(10),
+            SoftStart::with_ramp(10, SOFT_START_RAMP_MS)
+        );
+    }
+}
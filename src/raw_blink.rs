@@ -0,0 +1,133 @@
+/*
+ * @file raw_blink.rs
+ * @brief Early boot bit-banged blink
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: raw_blink.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Early Boot Bit-Banged Blink.
+//!
+//! BRIEF:
+//! Flashes status codes with busy-wait delays before the executor starts.
+//! Cycle math is shared with the blink code encoder and host testable.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::blink_code::encode_blink_code;
+#[cfg(feature = "cortex-m")]
+use crate::led::led_state_to_level;
+
+/// Converts a duration into busy-wait cycles.
+///
+/// # Details
+/// Multiplies milliseconds by the core cycles per millisecond.
+/// Saturates instead of overflowing for long durations.
+///
+/// # Arguments
+/// * `ms` - Duration in milliseconds
+/// * `cycles_per_ms` - Core clock cycles per millisecond
+///
+/// # Returns
+/// * `u32` - Number of cycles to busy-wait
+#[allow(dead_code)]
+pub fn delay_cycles(ms: u64, cycles_per_ms: u32) -> u32 {
+    let cycles = ms.saturating_mul(u64::from(cycles_per_ms));
+    u32::try_from(cycles).unwrap_or(u32::MAX)
+}
+
+/// Returns total busy-wait cycles needed to flash a code.
+///
+/// # Details
+/// Sums the cycles of every step produced by encode_blink_code.
+///
+/// # Arguments
+/// * `code` - Number of flashes
+/// * `cycles_per_ms` - Core clock cycles per millisecond
+///
+/// # Returns
+/// * `u64` - Total cycles spent flashing the code
+#[allow(dead_code)]
+pub fn code_cycles(code: u8, cycles_per_ms: u32) -> u64 {
+    encode_blink_code(code)
+        .map(|(_, ms)| u64::from(delay_cycles(ms, cycles_per_ms)))
+        .sum()
+}
+
+/// Flashes a status code using busy-wait delays.
+///
+/// # Details
+/// Intended for very early boot before the Embassy executor runs.
+/// Drives the pin directly through the provided writer and spins
+/// with `cortex_m::asm::delay` between steps. Timing is approximate:
+/// the delay waits at least the requested cycles, so flash wait states
+/// and interrupts stretch each step. `cycles_per_ms` must match the
+/// core clock at the time of the call (e.g. 150_000 at 150 MHz).
+/// The pin is left low when the code completes.
+///
+/// # Arguments
+/// * `pin` - Writer setting the pin level (true = high)
+/// * `code` - Number of flashes to emit
+/// * `cycles_per_ms` - Core clock cycles per millisecond
+#[cfg(feature = "cortex-m")]
+#[allow(dead_code)]
+pub fn flash_code<F: FnMut(bool)>(pin: &mut F, code: u8, cycles_per_ms: u32) {
+    for (state, ms) in encode_blink_code(code) {
+        pin(led_state_to_level(state));
+        cortex_m::asm::delay(delay_cycles(ms, cycles_per_ms));
+    }
+    pin(false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{BLINK_CODE_OFF_MS, BLINK_CODE_ON_MS};
+
+    // ==================== Cycle Conversion Tests ====================
+
+    #[test]
+    fn test_delay_cycles_scales() {
+        assert_eq!(delay_cycles(0, 150_000), 0);
+        assert_eq!(delay_cycles(2, 150_000), 300_000);
+    }
+
+    #[test]
+    fn test_delay_cycles_saturates() {
+        assert_eq!(delay_cycles(u64::MAX, 150_000), u32::MAX);
+        assert_eq!(delay_cycles(1_000_000, 150_000), u32::MAX);
+    }
+
+    #[test]
+    fn test_code_cycles_matches_encoding() {
+        let per_flash = BLINK_CODE_ON_MS + BLINK_CODE_OFF_MS;
+        assert_eq!(code_cycles(3, 1000), 3 * per_flash * 1000);
+        assert_eq!(code_cycles(0, 1000), 0);
+    }
+}
@@ -0,0 +1,218 @@
+/*
+ * @file recorder.rs
+ * @brief Blink sequence recorder
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: recorder.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Blink Sequence Recorder.
+//!
+//! BRIEF:
+//! Captures live toggles with timestamps and replays them without alloc.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::{LedController, LedState};
+
+/// Recorder capturing live toggles for replay.
+///
+/// # Details
+/// Timestamps each toggle and stores it as a `(LedState, hold_ms)`
+/// step, apply then hold, like the pattern tables. The hold is the
+/// time until the next toggle, so the latest step holds 0 until then.
+/// Toggles past capacity still reach the controller but are not
+/// recorded.
+///
+/// # Fields
+/// * `steps` - Recorded steps
+/// * `len` - Number of recorded steps
+/// * `last_ms` - Timestamp of the previous toggle, if any
+/// * `open` - true while the latest recorded step awaits its hold
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Recorder<const N: usize> {
+    steps: [(LedState, u64); N],
+    len: usize,
+    last_ms: Option<u64>,
+    open: bool,
+}
+
+impl<const N: usize> Default for Recorder<N> {
+    /// Returns default Recorder instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New empty Recorder
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Recorder<N> {
+    /// Creates new empty recorder.
+    ///
+    /// # Returns
+    /// * `Self` - New Recorder instance
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            steps: [(LedState::Off, 0); N],
+            len: 0,
+            last_ms: None,
+            open: false,
+        }
+    }
+
+    /// Toggles the controller and records the step.
+    ///
+    /// # Details
+    /// Closes the previous step with the time since its toggle.
+    ///
+    /// # Arguments
+    /// * `controller` - Controller to toggle
+    /// * `now_ms` - Current time in milliseconds
+    ///
+    /// # Returns
+    /// * `LedState` - New LED state
+    #[allow(dead_code)]
+    pub fn toggle(&mut self, controller: &mut LedController, now_ms: u64) -> LedState {
+        let state = controller.toggle();
+        if let (true, Some(last_ms)) = (self.open, self.last_ms) {
+            self.steps[self.len - 1].1 = now_ms.saturating_sub(last_ms);
+        }
+        self.last_ms = Some(now_ms);
+        self.open = self.len < N;
+        if self.open {
+            self.steps[self.len] = (state, 0);
+            self.len += 1;
+        }
+        state
+    }
+
+    /// Returns the recorded steps.
+    ///
+    /// # Returns
+    /// * `&[(LedState, u64)]` - Steps in recording order
+    #[allow(dead_code)]
+    pub fn steps(&self) -> &[(LedState, u64)] {
+        &self.steps[..self.len]
+    }
+
+    /// Returns a replayer over the recorded steps.
+    ///
+    /// # Returns
+    /// * `Replayer<'_>` - Replayer starting at the first step
+    #[allow(dead_code)]
+    pub fn replayer(&self) -> Replayer<'_> {
+        Replayer::new(self.steps())
+    }
+}
+
+/// Replayer reproducing recorded steps.
+///
+/// # Details
+/// Yields each `(LedState, hold_ms)` step once: apply the state,
+/// then hold it, as play_steps does.
+///
+/// # Fields
+/// * `steps` - Steps to replay
+/// * `index` - Index of the next step
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Replayer<'a> {
+    steps: &'a [(LedState, u64)],
+    index: usize,
+}
+
+impl<'a> Replayer<'a> {
+    /// Creates new replayer over a step slice.
+    ///
+    /// # Arguments
+    /// * `steps` - Steps to replay
+    ///
+    /// # Returns
+    /// * `Self` - New Replayer instance
+    #[allow(dead_code)]
+    pub fn new(steps: &'a [(LedState, u64)]) -> Self {
+        Self { steps, index: 0 }
+    }
+}
+
+impl Iterator for Replayer<'_> {
+    type Item = (LedState, u64);
+
+    /// Returns the next recorded step.
+    ///
+    /// # Returns
+    /// * `Option<(LedState, u64)>` - State and hold time, or None at the end
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = *self.steps.get(self.index)?;
+        self.index += 1;
+        Some(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Recorder Tests ====================
+
+    #[test]
+    fn test_record_and_replay_three_toggles() {
+        let mut ctrl = LedController::new();
+        let mut recorder: Recorder<8> = Recorder::new();
+        recorder.toggle(&mut ctrl, 1000);
+        recorder.toggle(&mut ctrl, 1250);
+        recorder.toggle(&mut ctrl, 1900);
+        let expected = vec![(LedState::On, 250), (LedState::Off, 650), (LedState::On, 0)];
+        assert_eq!(recorder.replayer().collect::<Vec<_>>(), expected);
+        assert_eq!(ctrl.toggle_count(), 3);
+    }
+
+    #[test]
+    fn test_recorder_full_still_toggles() {
+        let mut ctrl = LedController::new();
+        let mut recorder: Recorder<1> = Recorder::new();
+        recorder.toggle(&mut ctrl, 0);
+        assert_eq!(recorder.toggle(&mut ctrl, 10), LedState::Off);
+        assert_eq!(recorder.toggle(&mut ctrl, 30), LedState::On);
+        assert_eq!(recorder.steps(), &[(LedState::On, 10)]);
+    }
+
+    #[test]
+    fn test_empty_replayer() {
+        let recorder: Recorder<4> = Recorder::new();
+        assert_eq!(recorder.replayer().next(), None);
+    }
+}
@@ -0,0 +1,210 @@
+/*
+ * @file rhythm.rs
+ * @brief Musical rhythm blink pattern
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: rhythm.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Musical Rhythm Blink Pattern.
+//!
+//! BRIEF:
+//! Converts note values at a tempo into blink durations and steps
+//! through a rhythm of notes.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::LedState;
+use crate::pattern::BlinkPattern;
+
+/// Musical note value enumeration.
+///
+/// # Variants
+/// * `Whole` - Four beats
+/// * `Half` - Two beats
+/// * `Quarter` - One beat
+/// * `Eighth` - Half a beat
+/// * `Sixteenth` - Quarter of a beat
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum NoteValue {
+    Whole,
+    Half,
+    Quarter,
+    Eighth,
+    Sixteenth,
+}
+
+/// Returns the duration of a note at a tempo.
+///
+/// # Details
+/// One beat is a quarter note. A zero tempo is treated as 1 BPM.
+///
+/// # Arguments
+/// * `note` - Note value
+/// * `bpm` - Tempo in quarter-note beats per minute
+///
+/// # Returns
+/// * `u64` - Note duration in milliseconds
+#[allow(dead_code)]
+pub fn note_duration_ms(note: NoteValue, bpm: u16) -> u64 {
+    let beat_ms = 60_000 / u64::from(bpm.max(1));
+    match note {
+        NoteValue::Whole => beat_ms * 4,
+        NoteValue::Half => beat_ms * 2,
+        NoteValue::Quarter => beat_ms,
+        NoteValue::Eighth => beat_ms / 2,
+        NoteValue::Sixteenth => beat_ms / 4,
+    }
+}
+
+/// Rhythm pattern stepping through note values.
+///
+/// # Details
+/// Each note lights the LED for three quarters of its duration and
+/// rests for the remainder so repeated notes stay distinct. Wraps to
+/// the first note after the last.
+///
+/// # Fields
+/// * `notes` - Notes to play in order
+/// * `bpm` - Tempo in beats per minute
+/// * `index` - Index of the current note
+/// * `rest` - true when the next step is the current note's rest
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Rhythm<const N: usize> {
+    notes: [NoteValue; N],
+    bpm: u16,
+    index: usize,
+    rest: bool,
+}
+
+impl<const N: usize> Rhythm<N> {
+    /// Creates new rhythm at the first note.
+    ///
+    /// # Arguments
+    /// * `notes` - Notes to play in order
+    /// * `bpm` - Tempo in beats per minute
+    ///
+    /// # Returns
+    /// * `Self` - New Rhythm instance
+    #[allow(dead_code)]
+    pub fn new(notes: [NoteValue; N], bpm: u16) -> Self {
+        Self {
+            notes,
+            bpm,
+            index: 0,
+            rest: false,
+        }
+    }
+}
+
+impl<const N: usize> BlinkPattern for Rhythm<N> {
+    /// Returns the next rhythm step.
+    ///
+    /// # Details
+    /// An empty rhythm rests for one beat per step.
+    ///
+    /// # Returns
+    /// * `(LedState, u64)` - Step state and hold time
+    #[allow(dead_code)]
+    fn next_step(&mut self) -> (LedState, u64) {
+        let Some(&note) = self.notes.get(self.index) else {
+            return (
+                LedState::Off,
+                note_duration_ms(NoteValue::Quarter, self.bpm),
+            );
+        };
+        let duration_ms = note_duration_ms(note, self.bpm);
+        let sound_ms = duration_ms * 3 / 4;
+        if self.rest {
+            self.rest = false;
+            self.index = (self.index + 1) % N;
+            (LedState::Off, duration_ms - sound_ms)
+        } else {
+            self.rest = true;
+            (LedState::On, sound_ms)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pattern::capture_steps;
+
+    // ==================== Note Duration Tests ====================
+
+    #[test]
+    fn test_quarter_at_120_bpm() {
+        assert_eq!(note_duration_ms(NoteValue::Quarter, 120), 500);
+    }
+
+    #[test]
+    fn test_eighth_at_120_bpm() {
+        assert_eq!(note_duration_ms(NoteValue::Eighth, 120), 250);
+    }
+
+    #[test]
+    fn test_note_values_scale_by_two() {
+        assert_eq!(note_duration_ms(NoteValue::Whole, 60), 4000);
+        assert_eq!(note_duration_ms(NoteValue::Half, 60), 2000);
+        assert_eq!(note_duration_ms(NoteValue::Sixteenth, 60), 250);
+    }
+
+    #[test]
+    fn test_zero_bpm_guarded() {
+        assert_eq!(note_duration_ms(NoteValue::Quarter, 0), 60_000);
+    }
+
+    // ==================== Rhythm Tests ====================
+
+    #[test]
+    fn test_rhythm_steps_through_notes_and_wraps() {
+        let mut rhythm = Rhythm::new([NoteValue::Quarter, NoteValue::Eighth], 120);
+        let steps: [(LedState, u64); 6] = capture_steps(&mut rhythm);
+        assert_eq!(
+            steps,
+            [
+                (LedState::On, 375),
+                (LedState::Off, 125),
+                (LedState::On, 187),
+                (LedState::Off, 63),
+                (LedState::On, 375),
+                (LedState::Off, 125),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_rhythm_rests() {
+        let mut rhythm = Rhythm::<0>::new([], 120);
+        assert_eq!(rhythm.next_step(), (LedState::Off, 500));
+    }
+}
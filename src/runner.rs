@@ -0,0 +1,403 @@
+/*
+ * @file runner.rs
+ * @brief Blink task runner
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: runner.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Blink Task Runner.
+//!
+//! BRIEF:
+//! Runs boot patterns and blink transitions through an
+//! embedded-hal-async DelayNs so timing can be mocked on host.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::guard::BlinkGuard;
+use crate::led::{LedController, LedState, Tick};
+use crate::pattern::Pattern;
+use crate::pin::LedPin;
+use crate::script::ScriptSeq;
+use embedded_hal_async::delay::DelayNs;
+
+/// Converts a millisecond delay to the DelayNs argument type.
+///
+/// # Details
+/// Saturates at u32::MAX (about 49 days).
+///
+/// # Arguments
+/// * `ms` - Delay in milliseconds
+///
+/// # Returns
+/// * `u32` - Saturated delay
+#[allow(dead_code)]
+pub fn delay_arg(ms: u64) -> u32 {
+    u32::try_from(ms).unwrap_or(u32::MAX)
+}
+
+/// Returns whether a one-shot sequence has finished.
+///
+/// # Arguments
+/// * `played` - Steps played so far
+/// * `total` - Steps in the sequence
+///
+/// # Returns
+/// * `bool` - true once every step has played
+#[allow(dead_code)]
+pub fn one_shot_complete(played: usize, total: usize) -> bool {
+    played >= total
+}
+
+/// Plays a one-shot step sequence.
+///
+/// # Details
+/// Applies each step then leaves the LED off. Used for bursts,
+/// alerts and Morse messages. Raises ONE_SHOT_DONE on completion so
+/// the application can wait_for_idle().
+///
+/// # Arguments
+/// * `led` - LED pin
+/// * `controller` - Controller providing polarity
+/// * `steps` - Steps to play
+/// * `delay` - Delay provider
+#[allow(dead_code)]
+pub async fn play_steps<P: LedPin, D: DelayNs>(
+    led: &mut P,
+    controller: &LedController,
+    steps: &[(LedState, u64)],
+    delay: &mut D,
+) {
+    let polarity = controller.polarity();
+    let mut played = 0;
+    while !one_shot_complete(played, steps.len()) {
+        let (state, ms) = steps[played];
+        led.set_level(polarity.level_for(state));
+        delay.delay_ms(delay_arg(ms)).await;
+        played += 1;
+    }
+    led.set_level(polarity.level_for(LedState::Off));
+    #[cfg(feature = "embassy-sync")]
+    crate::control::ONE_SHOT_DONE.signal(());
+}
+
+/// Plays a parsed blink script.
+///
+/// # Details
+/// Applies every step of each pass then leaves the LED off, raising
+/// ONE_SHOT_DONE like play_steps.
+///
+/// # Arguments
+/// * `led` - LED pin
+/// * `controller` - Controller providing polarity
+/// * `script` - Script to play
+/// * `delay` - Delay provider
+#[allow(dead_code)]
+pub async fn play_script<P: LedPin, D: DelayNs>(
+    led: &mut P,
+    controller: &LedController,
+    script: &ScriptSeq,
+    delay: &mut D,
+) {
+    let polarity = controller.polarity();
+    for (state, ms) in script.player() {
+        led.set_level(polarity.level_for(state));
+        delay.delay_ms(delay_arg(ms)).await;
+    }
+    led.set_level(polarity.level_for(LedState::Off));
+    #[cfg(feature = "embassy-sync")]
+    crate::control::ONE_SHOT_DONE.signal(());
+}
+
+/// Plays one pass of a pattern.
+///
+/// # Details
+/// Applies each step of the pattern table then leaves the LED off.
+///
+/// # Arguments
+/// * `led` - LED pin
+/// * `controller` - Controller providing polarity
+/// * `pattern` - Pattern to play
+/// * `delay` - Delay provider
+#[allow(dead_code)]
+pub async fn play_once<P: LedPin, D: DelayNs>(
+    led: &mut P,
+    controller: &LedController,
+    pattern: Pattern,
+    delay: &mut D,
+) {
+    play_steps(led, controller, pattern.steps(), delay).await;
+}
+
+/// Runs one full blink cycle.
+///
+/// # Details
+/// Calls set(true), waits, set(false), waits, in that order. Body of
+/// the fixed-rate heartbeat loop; the on/wait/off/wait invariant is
+/// testable with a recording closure and a mock delay.
+///
+/// # Arguments
+/// * `set` - Pin writer (true = on)
+/// * `delay` - Delay provider
+/// * `delay_ms` - Delay for each phase in milliseconds
+#[allow(dead_code)]
+pub async fn blink_once<F: FnMut(bool), D: DelayNs>(set: &mut F, delay: &mut D, delay_ms: u64) {
+    set(true);
+    delay.delay_ms(delay_arg(delay_ms)).await;
+    set(false);
+    delay.delay_ms(delay_arg(delay_ms)).await;
+}
+
+/// Runs one blink transition.
+///
+/// # Details
+/// Ticks the controller, drives the LED, waits the settle time when
+/// configured, reports the toggle count, then waits the blink delay.
+///
+/// # Arguments
+/// * `led` - Guarded LED pin
+/// * `controller` - Controller providing state and timing
+/// * `delay` - Delay provider
+/// * `publish` - Called with the toggle count after each toggle
+///
+/// # Returns
+/// * `Tick` - Transition that was applied
+#[allow(dead_code)]
+pub async fn blink_step<P: LedPin, D: DelayNs, F: FnMut(u64)>(
+    led: &mut BlinkGuard<P>,
+    controller: &mut LedController,
+    delay: &mut D,
+    publish: &mut F,
+) -> Tick {
+    let tick = controller.tick();
+    led.set_state(tick.state);
+    if tick.settle_us > 0 {
+        delay.delay_us(delay_arg(tick.settle_us)).await;
+    }
+    publish(controller.toggle_count());
+    delay.delay_ms(delay_arg(tick.delay_ms)).await;
+    tick
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polarity::Polarity;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// Recorded wait.
+    #[derive(Debug, PartialEq, Eq)]
+    enum Wait {
+        Us(u32),
+        Ms(u32),
+    }
+
+    /// Mock delay recording every wait.
+    #[derive(Default)]
+    struct MockDelay {
+        waits: Vec<Wait>,
+    }
+
+    impl DelayNs for MockDelay {
+        async fn delay_ns(&mut self, _ns: u32) {}
+
+        async fn delay_us(&mut self, us: u32) {
+            self.waits.push(Wait::Us(us));
+        }
+
+        async fn delay_ms(&mut self, ms: u32) {
+            self.waits.push(Wait::Ms(ms));
+        }
+    }
+
+    /// Mock pin recording every level written.
+    #[derive(Default)]
+    struct MockPin {
+        levels: Vec<bool>,
+    }
+
+    impl LedPin for MockPin {
+        fn set_level(&mut self, high: bool) {
+            self.levels.push(high);
+        }
+    }
+
+    impl LedPin for &mut MockPin {
+        fn set_level(&mut self, high: bool) {
+            self.levels.push(high);
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    // ==================== Delay Conversion Tests ====================
+
+    #[test]
+    fn test_delay_arg_saturates() {
+        assert_eq!(delay_arg(500), 500);
+        assert_eq!(delay_arg(u64::MAX), u32::MAX);
+    }
+
+    // ==================== One-Shot Completion Tests ====================
+
+    #[test]
+    fn test_one_shot_incomplete_at_partial_progress() {
+        assert!(!one_shot_complete(0, 4));
+        assert!(!one_shot_complete(3, 4));
+    }
+
+    #[test]
+    fn test_one_shot_complete_at_full_progress() {
+        assert!(one_shot_complete(4, 4));
+        assert!(one_shot_complete(5, 4));
+        assert!(one_shot_complete(0, 0));
+    }
+
+    // ==================== Runner Tests ====================
+
+    #[test]
+    fn test_blink_once_call_order() {
+        let mut levels = Vec::new();
+        let mut delay = MockDelay::default();
+        block_on(blink_once(&mut |on| levels.push(on), &mut delay, 250));
+        assert_eq!(levels, vec![true, false]);
+        assert_eq!(delay.waits, vec![Wait::Ms(250), Wait::Ms(250)]);
+    }
+
+    #[test]
+    fn test_blink_once_drives_guarded_pin() {
+        let mut pin = MockPin::default();
+        let mut delay = MockDelay::default();
+        {
+            let mut led = BlinkGuard::new(&mut pin, Polarity::ActiveLow);
+            for _ in 0..2 {
+                block_on(blink_once(
+                    &mut |on| led.set_state(if on { LedState::On } else { LedState::Off }),
+                    &mut delay,
+                    100,
+                ));
+            }
+        }
+        assert_eq!(pin.levels, vec![false, true, false, true, true]);
+        assert_eq!(delay.waits.len(), 4);
+    }
+
+    #[test]
+    fn test_blink_step_wait_sequence() {
+        let mut pin = MockPin::default();
+        let mut delay = MockDelay::default();
+        let mut ctrl = LedController::new();
+        let mut counts = Vec::new();
+        {
+            let mut led = BlinkGuard::new(&mut pin, Polarity::ActiveHigh);
+            for _ in 0..3 {
+                block_on(blink_step(&mut led, &mut ctrl, &mut delay, &mut |c| {
+                    counts.push(c)
+                }));
+            }
+        }
+        assert_eq!(
+            delay.waits,
+            vec![Wait::Ms(500), Wait::Ms(500), Wait::Ms(500)]
+        );
+        assert_eq!(counts, vec![1, 2, 3]);
+        assert_eq!(pin.levels, vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn test_blink_step_waits_settle_time() {
+        let mut pin = MockPin::default();
+        let mut delay = MockDelay::default();
+        let mut ctrl = LedController::new();
+        ctrl.set_settle_us(40);
+        let mut led = BlinkGuard::new(&mut pin, Polarity::ActiveHigh);
+        let tick = block_on(blink_step(&mut led, &mut ctrl, &mut delay, &mut |_| {}));
+        assert_eq!(tick.settle_us, 40);
+        assert_eq!(delay.waits, vec![Wait::Us(40), Wait::Ms(500)]);
+    }
+
+    #[test]
+    fn test_blink_step_uses_effective_delay() {
+        let mut pin = MockPin::default();
+        let mut delay = MockDelay::default();
+        let mut ctrl = LedController::new();
+        ctrl.set_speed_factor(50);
+        let mut led = BlinkGuard::new(&mut pin, Polarity::ActiveHigh);
+        let tick = block_on(blink_step(&mut led, &mut ctrl, &mut delay, &mut |_| {}));
+        assert_eq!(tick.delay_ms, 250);
+        assert_eq!(delay.waits, vec![Wait::Ms(250)]);
+    }
+
+    #[test]
+    fn test_play_once_wait_sequence() {
+        let mut pin = MockPin::default();
+        let mut delay = MockDelay::default();
+        let ctrl = LedController::new();
+        block_on(play_once(&mut pin, &ctrl, Pattern::Heartbeat, &mut delay));
+        assert_eq!(
+            delay.waits,
+            vec![Wait::Ms(100), Wait::Ms(100), Wait::Ms(100), Wait::Ms(700)]
+        );
+        assert_eq!(pin.levels, vec![true, false, true, false, false]);
+    }
+
+    #[test]
+    fn test_play_steps_empty_leaves_led_off() {
+        let mut pin = MockPin::default();
+        let mut delay = MockDelay::default();
+        let ctrl = LedController::new();
+        block_on(play_steps(&mut pin, &ctrl, &[], &mut delay));
+        assert!(delay.waits.is_empty());
+        assert_eq!(pin.levels, vec![false]);
+    }
+
+    #[test]
+    fn test_play_script_wait_sequence() {
+        let mut pin = MockPin::default();
+        let mut delay = MockDelay::default();
+        let ctrl = LedController::new();
+        let script = crate::script::parse_script("on:100,off:400,rep:2").unwrap();
+        block_on(play_script(&mut pin, &ctrl, &script, &mut delay));
+        assert_eq!(
+            delay.waits,
+            vec![Wait::Ms(100), Wait::Ms(400), Wait::Ms(100), Wait::Ms(400)]
+        );
+        assert_eq!(pin.levels, vec![true, false, true, false, false]);
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * @file rx_blink.rs
+ * @brief UART RX activity flash
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: rx_blink.rs
+//!
+//! DESCRIPTION:
+//! RP2350 UART RX Activity Flash.
+//!
+//! BRIEF:
+//! Flashes the LED briefly for received UART bytes, gating flashes
+//! so bursts stay visible as separate pulses.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{RX_FLASH_INTERVAL_MS, RX_FLASH_MS};
+use crate::control::RateLimiter;
+use crate::guard::BlinkGuard;
+use crate::led::LedState;
+use crate::pin::LedPin;
+use crate::runner::delay_arg;
+use embedded_hal_async::delay::DelayNs;
+
+/// Creates the gate deciding which received bytes flash.
+///
+/// # Details
+/// Allows one flash per RX_FLASH_INTERVAL_MS; bytes inside the window
+/// coalesce into the flash already shown.
+///
+/// # Returns
+/// * `RateLimiter` - Gate over byte arrival timestamps
+#[allow(dead_code)]
+pub fn rx_flash_gate() -> RateLimiter {
+    RateLimiter::new(RX_FLASH_INTERVAL_MS)
+}
+
+/// Emits one short activity flash.
+///
+/// # Arguments
+/// * `led` - Guarded LED pin
+/// * `delay` - Delay provider
+#[allow(dead_code)]
+pub async fn flash<P: LedPin, D: DelayNs>(led: &mut BlinkGuard<P>, delay: &mut D) {
+    led.set_state(LedState::On);
+    delay.delay_ms(delay_arg(RX_FLASH_MS)).await;
+    led.set_state(LedState::Off);
+}
+
+/// Flashes the LED for received UART bytes forever.
+///
+/// # Details
+/// Reads one byte at a time and flashes when the gate allows.
+/// Receive errors such as overruns during a flash still count as
+/// traffic but are otherwise ignored.
+///
+/// # Arguments
+/// * `rx` - UART receiver
+/// * `led` - Guarded LED pin
+/// * `delay` - Delay provider
+#[cfg(all(feature = "embassy-rp", feature = "embassy-time"))]
+#[allow(dead_code)]
+pub async fn rx_blink_task<P: LedPin, D: DelayNs>(
+    rx: &mut embassy_rp::uart::UartRx<'_, embassy_rp::uart::Async>,
+    led: &mut BlinkGuard<P>,
+    delay: &mut D,
+) -> ! {
+    let mut gate = rx_flash_gate();
+    let mut byte = [0u8; 1];
+    loop {
+        let _ = rx.read(&mut byte).await;
+        if gate.allow(embassy_time::Instant::now().as_millis()) {
+            flash(led, delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flashes(arrivals: &[u64]) -> usize {
+        let mut gate = rx_flash_gate();
+        arrivals.iter().filter(|&&now| gate.allow(now)).count()
+    }
+
+    // ==================== RX Flash Gate Tests ====================
+
+    #[test]
+    fn test_single_byte_flashes() {
+        assert_eq!(flashes(&[1000]), 1);
+    }
+
+    #[test]
+    fn test_burst_within_window_coalesces() {
+        let burst: Vec<u64> = (0..RX_FLASH_INTERVAL_MS).collect();
+        assert_eq!(flashes(&burst), 1);
+    }
+
+    #[test]
+    fn test_continuous_stream_flashes_once_per_window() {
+        let stream: Vec<u64> = (0..RX_FLASH_INTERVAL_MS * 5).collect();
+        assert_eq!(flashes(&stream), 5);
+    }
+
+    #[test]
+    fn test_spaced_bytes_each_flash() {
+        let spaced = [0, RX_FLASH_INTERVAL_MS, 3 * RX_FLASH_INTERVAL_MS];
+        assert_eq!(flashes(&spaced), 3);
+    }
+}
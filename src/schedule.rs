@@ -0,0 +1,139 @@
+/*
+ * @file schedule.rs
+ * @brief Daily active blink window
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: schedule.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Daily Active Blink Window.
+//!
+//! BRIEF:
+//! Decides whether blinking is enabled at a minute of the day,
+//! including windows that wrap past midnight.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+/// Minutes in a day.
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// Daily window during which blinking is enabled.
+///
+/// # Details
+/// Covers minutes from `start_min` up to but not including `end_min`.
+/// A start after the end wraps past midnight, so 22:00 to 06:00 is
+/// written as start 1320, end 360. Equal start and end is an empty
+/// window. Minutes are taken modulo one day.
+///
+/// # Fields
+/// * `start_min` - First active minute of the day
+/// * `end_min` - First inactive minute after the window
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ActiveWindow {
+    start_min: u16,
+    end_min: u16,
+}
+
+impl ActiveWindow {
+    /// Creates new active window.
+    ///
+    /// # Arguments
+    /// * `start_min` - First active minute of the day
+    /// * `end_min` - First inactive minute after the window
+    ///
+    /// # Returns
+    /// * `Self` - New ActiveWindow instance
+    #[allow(dead_code)]
+    pub fn new(start_min: u16, end_min: u16) -> Self {
+        Self {
+            start_min: start_min % MINUTES_PER_DAY,
+            end_min: end_min % MINUTES_PER_DAY,
+        }
+    }
+
+    /// Returns whether blinking is enabled at a minute of the day.
+    ///
+    /// # Arguments
+    /// * `minute` - Minutes since midnight
+    ///
+    /// # Returns
+    /// * `bool` - true inside the window
+    #[allow(dead_code)]
+    pub fn is_active(&self, minute: u16) -> bool {
+        let minute = minute % MINUTES_PER_DAY;
+        if self.start_min <= self.end_min {
+            (self.start_min..self.end_min).contains(&minute)
+        } else {
+            minute >= self.start_min || minute < self.end_min
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Active Window Tests ====================
+
+    #[test]
+    fn test_normal_window_boundaries() {
+        let window = ActiveWindow::new(8 * 60, 17 * 60);
+        assert!(!window.is_active(8 * 60 - 1));
+        assert!(window.is_active(8 * 60));
+        assert!(window.is_active(17 * 60 - 1));
+        assert!(!window.is_active(17 * 60));
+    }
+
+    #[test]
+    fn test_midnight_wrap_boundaries() {
+        let window = ActiveWindow::new(22 * 60, 6 * 60);
+        assert!(!window.is_active(22 * 60 - 1));
+        assert!(window.is_active(22 * 60));
+        assert!(window.is_active(MINUTES_PER_DAY - 1));
+        assert!(window.is_active(0));
+        assert!(window.is_active(6 * 60 - 1));
+        assert!(!window.is_active(6 * 60));
+        assert!(!window.is_active(12 * 60));
+    }
+
+    #[test]
+    fn test_equal_start_end_is_empty() {
+        let window = ActiveWindow::new(600, 600);
+        assert!(!window.is_active(600));
+        assert!(!window.is_active(0));
+    }
+
+    #[test]
+    fn test_minutes_wrap_modulo_day() {
+        let window = ActiveWindow::new(22 * 60, 6 * 60);
+        assert!(window.is_active(MINUTES_PER_DAY));
+        assert!(!window.is_active(MINUTES_PER_DAY + 12 * 60));
+    }
+}
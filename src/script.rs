@@ -0,0 +1,264 @@
+/*
+ * @file script.rs
+ * @brief Blink script parsing
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: script.rs
+//!
+//! DESCRIPTION:
+//! Blink Script Parsing.
+//!
+//! BRIEF:
+//! Parses short comma-separated blink scripts into a fixed-capacity
+//! sequence and plays them back.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::command::parse_number;
+use crate::config::SCRIPT_CAPACITY;
+use crate::led::LedState;
+
+/// Script parse error enumeration.
+///
+/// # Variants
+/// * `Empty` - Script contains no steps
+/// * `TooLong` - Script has more steps than SCRIPT_CAPACITY
+/// * `BadToken` - Token is not `on:<ms>`, `off:<ms>` or a final `rep:<n>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum ScriptError {
+    Empty,
+    TooLong,
+    BadToken,
+}
+
+/// Parsed blink script.
+///
+/// # Details
+/// Holds up to SCRIPT_CAPACITY steps and the number of times the
+/// whole sequence plays.
+///
+/// # Fields
+/// * `steps` - Step storage
+/// * `len` - Number of steps in use
+/// * `repeat` - Number of passes, at least 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ScriptSeq {
+    steps: [(LedState, u64); SCRIPT_CAPACITY],
+    len: usize,
+    repeat: u16,
+}
+
+impl ScriptSeq {
+    /// Returns the script steps.
+    ///
+    /// # Returns
+    /// * `&[(LedState, u64)]` - Steps in script order
+    #[allow(dead_code)]
+    pub fn steps(&self) -> &[(LedState, u64)] {
+        &self.steps[..self.len]
+    }
+
+    /// Returns the number of passes.
+    ///
+    /// # Returns
+    /// * `u16` - Repeat count, 1 when the script has no `rep` token
+    #[allow(dead_code)]
+    pub fn repeat(&self) -> u16 {
+        self.repeat
+    }
+
+    /// Returns a player over every pass of the script.
+    ///
+    /// # Returns
+    /// * `ScriptPlayer<'_>` - Player starting at the first step
+    #[allow(dead_code)]
+    pub fn player(&self) -> ScriptPlayer<'_> {
+        ScriptPlayer {
+            script: self,
+            index: 0,
+            pass: 0,
+        }
+    }
+}
+
+/// Parses a blink script.
+///
+/// # Details
+/// Comma-separated `on:<ms>` and `off:<ms>` tokens, optionally
+/// ended by `rep:<n>` to play the sequence n times, for example
+/// `on:100,off:400,rep:3`. Whitespace around tokens is ignored and
+/// numbers accept the console formats. A zero repeat is rejected.
+///
+/// # Arguments
+/// * `text` - Script text
+///
+/// # Returns
+/// * `Result<ScriptSeq, ScriptError>` - Parsed script or error
+#[allow(dead_code)]
+pub fn parse_script(text: &str) -> Result<ScriptSeq, ScriptError> {
+    let mut script = ScriptSeq {
+        steps: [(LedState::Off, 0); SCRIPT_CAPACITY],
+        len: 0,
+        repeat: 1,
+    };
+    if text.trim().is_empty() {
+        return Err(ScriptError::Empty);
+    }
+    let mut tokens = text.split(',').map(str::trim).peekable();
+    while let Some(token) = tokens.next() {
+        let (key, value) = token.split_once(':').ok_or(ScriptError::BadToken)?;
+        let value = parse_number(value).map_err(|_| ScriptError::BadToken)?;
+        let state = match key {
+            "on" => LedState::On,
+            "off" => LedState::Off,
+            "rep" if tokens.peek().is_none() => {
+                script.repeat = u16::try_from(value)
+                    .ok()
+                    .filter(|&repeat| repeat > 0)
+                    .ok_or(ScriptError::BadToken)?;
+                break;
+            }
+            _ => return Err(ScriptError::BadToken),
+        };
+        if script.len == SCRIPT_CAPACITY {
+            return Err(ScriptError::TooLong);
+        }
+        script.steps[script.len] = (state, value);
+        script.len += 1;
+    }
+    if script.len == 0 {
+        return Err(ScriptError::Empty);
+    }
+    Ok(script)
+}
+
+/// Player executing a parsed script.
+///
+/// # Details
+/// Yields each step of every pass in order, then ends.
+///
+/// # Fields
+/// * `script` - Script to play
+/// * `index` - Index of the next step in the pass
+/// * `pass` - Number of completed passes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct ScriptPlayer<'a> {
+    script: &'a ScriptSeq,
+    index: usize,
+    pass: u16,
+}
+
+impl Iterator for ScriptPlayer<'_> {
+    type Item = (LedState, u64);
+
+    /// Returns the next script step.
+    ///
+    /// # Returns
+    /// * `Option<(LedState, u64)>` - Next step or None after the last pass
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pass >= self.script.repeat {
+            return None;
+        }
+        let step = self.script.steps()[self.index];
+        self.index += 1;
+        if self.index == self.script.len {
+            self.index = 0;
+            self.pass += 1;
+        }
+        Some(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Parse Tests ====================
+
+    #[test]
+    fn test_parse_valid_script() {
+        let script = parse_script("on:100,off:400,rep:3").unwrap();
+        assert_eq!(script.steps(), &[(LedState::On, 100), (LedState::Off, 400)]);
+        assert_eq!(script.repeat(), 3);
+    }
+
+    #[test]
+    fn test_parse_without_repeat_plays_once() {
+        let script = parse_script(" on:0x20 , off:50 ").unwrap();
+        assert_eq!(script.steps(), &[(LedState::On, 32), (LedState::Off, 50)]);
+        assert_eq!(script.repeat(), 1);
+    }
+
+    #[test]
+    fn test_parse_over_capacity() {
+        let mut text = String::new();
+        for _ in 0..=SCRIPT_CAPACITY {
+            text.push_str("on:10,");
+        }
+        text.pop();
+        assert_eq!(parse_script(&text), Err(ScriptError::TooLong));
+        text.truncate(text.len() - ",on:10".len());
+        assert!(parse_script(&text).is_ok());
+    }
+
+    #[test]
+    fn test_parse_malformed_tokens() {
+        assert_eq!(parse_script("on:100,blink:5"), Err(ScriptError::BadToken));
+        assert_eq!(parse_script("on100"), Err(ScriptError::BadToken));
+        assert_eq!(parse_script("on:abc"), Err(ScriptError::BadToken));
+        assert_eq!(parse_script("rep:2,on:100"), Err(ScriptError::BadToken));
+        assert_eq!(parse_script("on:100,rep:0"), Err(ScriptError::BadToken));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(parse_script("rep:2"), Err(ScriptError::Empty));
+        assert_eq!(parse_script(" "), Err(ScriptError::Empty));
+    }
+
+    // ==================== Player Tests ====================
+
+    #[test]
+    fn test_player_repeats_passes() {
+        let script = parse_script("on:100,off:400,rep:2").unwrap();
+        let steps: Vec<_> = script.player().collect();
+        assert_eq!(
+            steps,
+            vec![
+                (LedState::On, 100),
+                (LedState::Off, 400),
+                (LedState::On, 100),
+                (LedState::Off, 400),
+            ]
+        );
+    }
+}
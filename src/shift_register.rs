@@ -0,0 +1,196 @@
+/*
+ * @file shift_register.rs
+ * @brief Shift register LED output
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: shift_register.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Shift Register LED Output.
+//!
+//! BRIEF:
+//! Drives eight LEDs from three pins through a 74HC595 and packs
+//! controller states into the output byte.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::LedController;
+use crate::pin::LedPin;
+
+/// Packs controller states into a shift register byte.
+///
+/// # Details
+/// Bit i is set while controller i is on. Controllers past the
+/// eighth are ignored.
+///
+/// # Arguments
+/// * `controllers` - Controllers, one per output bit
+///
+/// # Returns
+/// * `u8` - Packed LED states
+#[allow(dead_code)]
+pub fn pack_states(controllers: &[LedController]) -> u8 {
+    controllers
+        .iter()
+        .take(8)
+        .enumerate()
+        .filter(|(_, ctrl)| ctrl.in_on_phase())
+        .fold(0, |byte, (i, _)| byte | 1 << i)
+}
+
+/// 74HC595 shift register LED output.
+///
+/// # Details
+/// Drives eight LEDs from three pins. Bits are shifted out MSB
+/// first, so bit 7 lands on output QH and bit 0 on QA.
+///
+/// # Fields
+/// * `data` - Serial data pin (SER)
+/// * `clock` - Shift clock pin (SRCLK)
+/// * `latch` - Storage latch pin (RCLK)
+#[allow(dead_code)]
+pub struct ShiftRegisterOutput<D: LedPin, C: LedPin, L: LedPin> {
+    data: D,
+    clock: C,
+    latch: L,
+}
+
+impl<D: LedPin, C: LedPin, L: LedPin> ShiftRegisterOutput<D, C, L> {
+    /// Creates new shift register output.
+    ///
+    /// # Details
+    /// Leaves clock and latch low.
+    ///
+    /// # Arguments
+    /// * `data` - Serial data pin
+    /// * `clock` - Shift clock pin
+    /// * `latch` - Storage latch pin
+    ///
+    /// # Returns
+    /// * `Self` - New ShiftRegisterOutput instance
+    #[allow(dead_code)]
+    pub fn new(data: D, mut clock: C, mut latch: L) -> Self {
+        clock.set_level(false);
+        latch.set_level(false);
+        Self { data, clock, latch }
+    }
+
+    /// Shifts out a byte and latches it onto the outputs.
+    ///
+    /// # Arguments
+    /// * `byte` - LED states, bit i for output i
+    #[allow(dead_code)]
+    pub fn write(&mut self, byte: u8) {
+        for bit in (0..8).rev() {
+            self.data.set_level(byte & (1 << bit) != 0);
+            self.clock.set_level(true);
+            self.clock.set_level(false);
+        }
+        self.latch.set_level(true);
+        self.latch.set_level(false);
+    }
+
+    /// Packs and writes controller states.
+    ///
+    /// # Arguments
+    /// * `controllers` - Controllers, one per output bit
+    #[allow(dead_code)]
+    pub fn write_states(&mut self, controllers: &[LedController]) {
+        self.write(pack_states(controllers));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Mock pin appending its name and level to a shared log.
+    struct MockPin {
+        name: char,
+        log: Rc<RefCell<Vec<(char, bool)>>>,
+    }
+
+    impl LedPin for MockPin {
+        fn set_level(&mut self, high: bool) {
+            self.log.borrow_mut().push((self.name, high));
+        }
+    }
+
+    fn controllers(on: [bool; 8]) -> [LedController; 8] {
+        on.map(|lit| {
+            let mut ctrl = LedController::new();
+            if lit {
+                ctrl.toggle();
+            }
+            ctrl
+        })
+    }
+
+    // ==================== Packing Tests ====================
+
+    #[test]
+    fn test_pack_eight_states() {
+        let ctrls = controllers([true, false, true, true, false, false, false, true]);
+        assert_eq!(pack_states(&ctrls), 0b1000_1101);
+    }
+
+    #[test]
+    fn test_pack_all_off_and_all_on() {
+        assert_eq!(pack_states(&controllers([false; 8])), 0);
+        assert_eq!(pack_states(&controllers([true; 8])), 0xFF);
+    }
+
+    #[test]
+    fn test_pack_ignores_extra_controllers() {
+        let mut ctrls = [LedController::new(); 9];
+        ctrls[8].toggle();
+        assert_eq!(pack_states(&ctrls), 0);
+    }
+
+    // ==================== Output Tests ====================
+
+    #[test]
+    fn test_write_shifts_msb_first_then_latches() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let pin = |name| MockPin {
+            name,
+            log: log.clone(),
+        };
+        let mut output = ShiftRegisterOutput::new(pin('d'), pin('c'), pin('l'));
+        log.borrow_mut().clear();
+        output.write(0b1000_0001);
+        let log = log.borrow();
+        let data: Vec<bool> = log.iter().filter(|e| e.0 == 'd').map(|e| e.1).collect();
+        assert_eq!(data, [true, false, false, false, false, false, false, true]);
+        assert_eq!(log.iter().filter(|e| *e == &('c', true)).count(), 8);
+        assert_eq!(&log[log.len() - 2..], &[('l', true), ('l', false)]);
+    }
+}
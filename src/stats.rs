@@ -0,0 +1,237 @@
+/*
+ * @file stats.rs
+ * @brief Blink statistics
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: stats.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Blink Statistics.
+//!
+//! BRIEF:
+//! Accumulates on/off time and formats a console summary without alloc.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::LedState;
+use core::fmt::Write;
+
+/// Accumulated blink statistics.
+///
+/// # Details
+/// Tracks total time spent lit and dark for diagnostics.
+///
+/// # Fields
+/// * `on_ms` - Total ON time in milliseconds
+/// * `off_ms` - Total OFF time in milliseconds
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct BlinkStats {
+    on_ms: u64,
+    off_ms: u64,
+}
+
+impl BlinkStats {
+    /// Creates new empty statistics.
+    ///
+    /// # Returns
+    /// * `Self` - New BlinkStats instance
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records time spent in a state.
+    ///
+    /// # Details
+    /// Saturates rather than overflowing.
+    ///
+    /// # Arguments
+    /// * `state` - LED state held
+    /// * `ms` - Duration in milliseconds
+    #[allow(dead_code)]
+    pub fn record(&mut self, state: LedState, ms: u64) {
+        match state {
+            LedState::On => self.on_ms = self.on_ms.saturating_add(ms),
+            LedState::Off => self.off_ms = self.off_ms.saturating_add(ms),
+        }
+    }
+
+    /// Returns total ON time.
+    ///
+    /// # Returns
+    /// * `u64` - ON time in milliseconds
+    #[allow(dead_code)]
+    pub fn on_ms(&self) -> u64 {
+        self.on_ms
+    }
+
+    /// Returns total OFF time.
+    ///
+    /// # Returns
+    /// * `u64` - OFF time in milliseconds
+    #[allow(dead_code)]
+    pub fn off_ms(&self) -> u64 {
+        self.off_ms
+    }
+
+    /// Returns the ON time ratio in parts per thousand.
+    ///
+    /// # Returns
+    /// * `u64` - Duty ratio (0-1000), or 0 if nothing was recorded
+    #[allow(dead_code)]
+    pub fn duty_permille(&self) -> u64 {
+        match self.on_ms.saturating_add(self.off_ms) {
+            0 => 0,
+            total => (u128::from(self.on_ms) * 1000 / u128::from(total)) as u64,
+        }
+    }
+}
+
+/// Truncating formatter over a caller buffer.
+///
+/// # Fields
+/// * `buf` - Destination buffer
+/// * `len` - Bytes written so far
+pub(crate) struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> BufWriter<'a> {
+    /// Creates new writer at the start of a buffer.
+    ///
+    /// # Arguments
+    /// * `buf` - Destination buffer
+    ///
+    /// # Returns
+    /// * `Self` - New empty BufWriter
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Returns the number of bytes written.
+    ///
+    /// # Returns
+    /// * `usize` - Bytes written so far
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Write for BufWriter<'_> {
+    /// Appends text, dropping whatever does not fit.
+    fn write_str(&mut self, text: &str) -> core::fmt::Result {
+        let n = text.len().min(self.buf.len() - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&text.as_bytes()[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+/// Formats statistics as an ASCII console summary.
+///
+/// # Details
+/// Writes `on=<ms>ms off=<ms>ms duty=<pct>.<tenth>%` followed by
+/// CRLF. Output that does not fit the buffer is truncated.
+///
+/// # Arguments
+/// * `stats` - Statistics to format
+/// * `buf` - Destination buffer
+///
+/// # Returns
+/// * `usize` - Number of bytes written
+#[allow(dead_code)]
+pub fn format_stats(stats: &BlinkStats, buf: &mut [u8]) -> usize {
+    let duty = stats.duty_permille();
+    let mut writer = BufWriter::new(buf);
+    let _ = write!(
+        writer,
+        "on={}ms off={}ms duty={}.{}%\r\n",
+        stats.on_ms,
+        stats.off_ms,
+        duty / 10,
+        duty % 10
+    );
+    writer.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== BlinkStats Tests ====================
+
+    #[test]
+    fn test_record_accumulates() {
+        let mut stats = BlinkStats::new();
+        stats.record(LedState::On, 300);
+        stats.record(LedState::Off, 100);
+        stats.record(LedState::On, 200);
+        assert_eq!(stats.on_ms(), 500);
+        assert_eq!(stats.off_ms(), 100);
+    }
+
+    #[test]
+    fn test_duty_permille() {
+        let mut stats = BlinkStats::new();
+        assert_eq!(stats.duty_permille(), 0);
+        stats.record(LedState::On, 1);
+        stats.record(LedState::Off, 2);
+        assert_eq!(stats.duty_permille(), 333);
+    }
+
+    // ==================== Formatter Tests ====================
+
+    #[test]
+    fn test_format_known_stats() {
+        let mut stats = BlinkStats::new();
+        stats.record(LedState::On, 1500);
+        stats.record(LedState::Off, 500);
+        let mut buf = [0u8; 64];
+        let len = format_stats(&stats, &mut buf);
+        assert_eq!(&buf[..len], b"on=1500ms off=500ms duty=75.0%\r\n");
+    }
+
+    #[test]
+    fn test_format_empty_stats() {
+        let mut buf = [0u8; 64];
+        let len = format_stats(&BlinkStats::new(), &mut buf);
+        assert_eq!(&buf[..len], b"on=0ms off=0ms duty=0.0%\r\n");
+    }
+
+    #[test]
+    fn test_format_truncates_to_buffer() {
+        let mut stats = BlinkStats::new();
+        stats.record(LedState::On, 1500);
+        let mut buf = [0u8; 8];
+        assert_eq!(format_stats(&stats, &mut buf), 8);
+        assert_eq!(&buf, b"on=1500m");
+    }
+}
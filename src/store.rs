@@ -0,0 +1,491 @@
+/*
+ * @file store.rs
+ * @brief Blink configuration persistence
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: store.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Blink Configuration Persistence.
+//!
+//! BRIEF:
+//! Defines the ConfigStore trait with flash and in-memory backends.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{BLINK_DELAY_MS, DEFAULT_DUTY_PERCENT, DEFAULT_INTENSITY};
+#[cfg(feature = "embassy-rp")]
+use crate::config::{CONFIG_FLASH_OFFSET, FLASH_SIZE_BYTES};
+use crate::pattern::Pattern;
+use crate::profile::BlinkProfile;
+
+/// Serialized configuration size in bytes.
+#[allow(dead_code)]
+pub const CONFIG_BYTES: usize = 16;
+
+/// Magic marker identifying a stored configuration.
+const CONFIG_MAGIC: [u8; 2] = [0xB1, 0x27];
+
+/// Erased flash byte value.
+const ERASED: u8 = 0xFF;
+
+/// FNV-1a 32-bit offset basis.
+const FNV_OFFSET: u32 = 0x811C_9DC5;
+
+/// FNV-1a 32-bit prime.
+const FNV_PRIME: u32 = 0x0100_0193;
+
+/// Hashes bytes with 32-bit FNV-1a.
+///
+/// # Arguments
+/// * `bytes` - Bytes to hash
+///
+/// # Returns
+/// * `u32` - FNV-1a hash
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(FNV_OFFSET, |hash, &byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Persistent blink configuration.
+///
+/// # Details
+/// Settings saved across reboots through a ConfigStore.
+///
+/// # Fields
+/// * `delay_ms` - Blink delay in milliseconds
+/// * `duty` - PWM duty cycle in percent while lit
+/// * `intensity` - Logical brightness level (0-255)
+/// * `active_low` - true if the LED is lit by a low pin
+/// * `pattern` - Last-used blink pattern
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct BlinkConfig {
+    pub delay_ms: u64,
+    pub duty: u8,
+    pub intensity: u8,
+    pub active_low: bool,
+    pub pattern: Pattern,
+}
+
+impl Default for BlinkConfig {
+    /// Returns default BlinkConfig.
+    ///
+    /// # Details
+    /// Built from the compile-time configuration constants.
+    ///
+    /// # Returns
+    /// * `Self` - Default configuration
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self {
+            delay_ms: BLINK_DELAY_MS,
+            duty: DEFAULT_DUTY_PERCENT,
+            intensity: DEFAULT_INTENSITY,
+            active_low: false,
+            pattern: Pattern::Steady,
+        }
+    }
+}
+
+impl BlinkConfig {
+    /// Serializes configuration into a fixed byte layout.
+    ///
+    /// # Details
+    /// Layout: magic (2), delay_ms LE (8), duty, intensity, flags,
+    /// pattern, then reserved bytes.
+    ///
+    /// # Returns
+    /// * `[u8; CONFIG_BYTES]` - Serialized configuration
+    #[allow(dead_code)]
+    pub fn to_bytes(&self) -> [u8; CONFIG_BYTES] {
+        let mut bytes = [0u8; CONFIG_BYTES];
+        bytes[..2].copy_from_slice(&CONFIG_MAGIC);
+        bytes[2..10].copy_from_slice(&self.delay_ms.to_le_bytes());
+        bytes[10] = self.duty;
+        bytes[11] = self.intensity;
+        bytes[12] = u8::from(self.active_low);
+        bytes[13] = self.pattern.to_byte();
+        bytes
+    }
+
+    /// Deserializes configuration from bytes.
+    ///
+    /// # Details
+    /// Erased (all 0xFF) storage yields the default configuration.
+    /// An unknown pattern byte falls back to Steady.
+    ///
+    /// # Arguments
+    /// * `bytes` - Serialized configuration
+    ///
+    /// # Returns
+    /// * `Result<Self, ConfigError>` - Configuration or Corrupt
+    #[allow(dead_code)]
+    pub fn from_bytes(bytes: &[u8; CONFIG_BYTES]) -> Result<Self, ConfigError> {
+        if bytes.iter().all(|&b| b == ERASED) {
+            return Ok(Self::default());
+        }
+        if bytes[..2] != CONFIG_MAGIC || bytes[12] > 1 {
+            return Err(ConfigError::Corrupt);
+        }
+        let mut delay = [0u8; 8];
+        delay.copy_from_slice(&bytes[2..10]);
+        Ok(Self {
+            delay_ms: u64::from_le_bytes(delay),
+            duty: bytes[10],
+            intensity: bytes[11],
+            active_low: bytes[12] == 1,
+            pattern: Pattern::from_byte(bytes[13]).unwrap_or(Pattern::Steady),
+        })
+    }
+
+    /// Returns a stable hash of the configuration.
+    ///
+    /// # Details
+    /// FNV-1a over the serialized bytes, so devices with the same
+    /// settings report the same hash regardless of build.
+    ///
+    /// # Returns
+    /// * `u32` - Configuration hash
+    #[allow(dead_code)]
+    pub fn config_hash(&self) -> u32 {
+        fnv1a(&self.to_bytes())
+    }
+
+    /// Returns the configuration as a blink profile.
+    ///
+    /// # Details
+    /// Lets a loaded configuration be applied with apply_profile.
+    ///
+    /// # Returns
+    /// * `BlinkProfile` - Equivalent profile
+    #[allow(dead_code)]
+    pub fn to_profile(&self) -> BlinkProfile {
+        BlinkProfile {
+            pattern: self.pattern,
+            delay_ms: self.delay_ms,
+            duty: self.duty,
+            intensity: self.intensity,
+            active_low: self.active_low,
+        }
+    }
+}
+
+/// Configuration store error enumeration.
+///
+/// # Variants
+/// * `Corrupt` - Stored bytes are not a valid configuration
+/// * `Flash` - Underlying flash operation failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum ConfigError {
+    Corrupt,
+    Flash,
+}
+
+/// Persistence backend for BlinkConfig.
+///
+/// # Details
+/// Lets flash backends be swapped without touching callers.
+#[allow(dead_code)]
+pub trait ConfigStore {
+    /// Loads the stored configuration.
+    ///
+    /// # Returns
+    /// * `Result<BlinkConfig, ConfigError>` - Stored or default configuration
+    fn load(&mut self) -> Result<BlinkConfig, ConfigError>;
+
+    /// Saves a configuration.
+    ///
+    /// # Arguments
+    /// * `config` - Configuration to persist
+    ///
+    /// # Returns
+    /// * `Result<(), ConfigError>` - Ok on success
+    fn save(&mut self, config: &BlinkConfig) -> Result<(), ConfigError>;
+}
+
+/// In-memory configuration store.
+///
+/// # Details
+/// RAM-backed store for tests and boards without spare flash.
+/// Starts erased, like fresh flash.
+///
+/// # Fields
+/// * `bytes` - Stored configuration bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct MemoryStore {
+    bytes: [u8; CONFIG_BYTES],
+}
+
+impl Default for MemoryStore {
+    /// Returns default MemoryStore instance.
+    ///
+    /// # Details
+    /// Delegates to new() for initialization.
+    ///
+    /// # Returns
+    /// * `Self` - New erased MemoryStore
+    #[allow(dead_code)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryStore {
+    /// Creates new erased in-memory store.
+    ///
+    /// # Returns
+    /// * `Self` - New MemoryStore instance
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self {
+            bytes: [ERASED; CONFIG_BYTES],
+        }
+    }
+}
+
+impl ConfigStore for MemoryStore {
+    /// Loads configuration from memory.
+    ///
+    /// # Returns
+    /// * `Result<BlinkConfig, ConfigError>` - Stored or default configuration
+    fn load(&mut self) -> Result<BlinkConfig, ConfigError> {
+        BlinkConfig::from_bytes(&self.bytes)
+    }
+
+    /// Saves configuration to memory.
+    ///
+    /// # Arguments
+    /// * `config` - Configuration to persist
+    ///
+    /// # Returns
+    /// * `Result<(), ConfigError>` - Always Ok
+    fn save(&mut self, config: &BlinkConfig) -> Result<(), ConfigError> {
+        self.bytes = config.to_bytes();
+        Ok(())
+    }
+}
+
+/// RP2350 flash configuration store.
+///
+/// # Details
+/// Persists the configuration in the last flash erase sector.
+/// Saving erases the whole sector before writing.
+///
+/// # Fields
+/// * `flash` - Blocking flash driver
+#[cfg(feature = "embassy-rp")]
+#[allow(dead_code)]
+pub struct FlashStore<'d> {
+    flash: embassy_rp::flash::Flash<
+        'd,
+        embassy_rp::peripherals::FLASH,
+        embassy_rp::flash::Blocking,
+        FLASH_SIZE_BYTES,
+    >,
+}
+
+#[cfg(feature = "embassy-rp")]
+impl<'d> FlashStore<'d> {
+    /// Creates new flash store from a blocking flash driver.
+    ///
+    /// # Arguments
+    /// * `flash` - Blocking flash driver
+    ///
+    /// # Returns
+    /// * `Self` - New FlashStore instance
+    #[allow(dead_code)]
+    pub fn new(
+        flash: embassy_rp::flash::Flash<
+            'd,
+            embassy_rp::peripherals::FLASH,
+            embassy_rp::flash::Blocking,
+            FLASH_SIZE_BYTES,
+        >,
+    ) -> Self {
+        Self { flash }
+    }
+}
+
+#[cfg(feature = "embassy-rp")]
+impl ConfigStore for FlashStore<'_> {
+    /// Loads configuration from flash.
+    ///
+    /// # Returns
+    /// * `Result<BlinkConfig, ConfigError>` - Stored or default configuration
+    fn load(&mut self) -> Result<BlinkConfig, ConfigError> {
+        let mut bytes = [0u8; CONFIG_BYTES];
+        self.flash
+            .blocking_read(CONFIG_FLASH_OFFSET, &mut bytes)
+            .map_err(|_| ConfigError::Flash)?;
+        BlinkConfig::from_bytes(&bytes)
+    }
+
+    /// Saves configuration to flash.
+    ///
+    /// # Arguments
+    /// * `config` - Configuration to persist
+    ///
+    /// # Returns
+    /// * `Result<(), ConfigError>` - Ok on success
+    fn save(&mut self, config: &BlinkConfig) -> Result<(), ConfigError> {
+        let end = CONFIG_FLASH_OFFSET + embassy_rp::flash::ERASE_SIZE as u32;
+        self.flash
+            .blocking_erase(CONFIG_FLASH_OFFSET, end)
+            .map_err(|_| ConfigError::Flash)?;
+        self.flash
+            .blocking_write(CONFIG_FLASH_OFFSET, &config.to_bytes())
+            .map_err(|_| ConfigError::Flash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Serialization Tests ====================
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let config = BlinkConfig {
+            delay_ms: 1234,
+            duty: 42,
+            intensity: 7,
+            active_low: true,
+            pattern: Pattern::Sos,
+        };
+        assert_eq!(BlinkConfig::from_bytes(&config.to_bytes()), Ok(config));
+    }
+
+    #[test]
+    fn test_erased_bytes_are_default() {
+        let bytes = [ERASED; CONFIG_BYTES];
+        assert_eq!(BlinkConfig::from_bytes(&bytes), Ok(BlinkConfig::default()));
+    }
+
+    #[test]
+    fn test_bad_magic_is_corrupt() {
+        let mut bytes = BlinkConfig::default().to_bytes();
+        bytes[0] = 0;
+        assert_eq!(BlinkConfig::from_bytes(&bytes), Err(ConfigError::Corrupt));
+    }
+
+    #[test]
+    fn test_unknown_pattern_byte_falls_back_to_steady() {
+        let mut bytes = BlinkConfig {
+            pattern: Pattern::Heartbeat,
+            ..BlinkConfig::default()
+        }
+        .to_bytes();
+        assert_eq!(bytes[13], 1);
+        bytes[13] = 0x7F;
+        let config = BlinkConfig::from_bytes(&bytes).unwrap();
+        assert_eq!(config.pattern, Pattern::Steady);
+    }
+
+    #[test]
+    fn test_to_profile_carries_pattern() {
+        let config = BlinkConfig {
+            pattern: Pattern::Sos,
+            ..BlinkConfig::default()
+        };
+        let mut ctrl = crate::led::LedController::new();
+        ctrl.apply_profile(&config.to_profile());
+        assert_eq!(ctrl.pattern(), Pattern::Sos);
+    }
+
+    // ==================== Hash Tests ====================
+
+    #[test]
+    fn test_fnv1a_known_vectors() {
+        assert_eq!(fnv1a(b""), 0x811C_9DC5);
+        assert_eq!(fnv1a(b"a"), 0xE40C_292C);
+    }
+
+    #[test]
+    fn test_identical_configs_hash_equal() {
+        let a = BlinkConfig {
+            delay_ms: 250,
+            ..BlinkConfig::default()
+        };
+        let b = BlinkConfig {
+            delay_ms: 250,
+            ..BlinkConfig::default()
+        };
+        assert_eq!(a.config_hash(), b.config_hash());
+    }
+
+    #[test]
+    fn test_single_field_change_alters_hash() {
+        let base = BlinkConfig::default();
+        let changes = [
+            BlinkConfig {
+                delay_ms: base.delay_ms + 1,
+                ..base
+            },
+            BlinkConfig {
+                duty: base.duty.wrapping_add(1),
+                ..base
+            },
+            BlinkConfig {
+                active_low: !base.active_low,
+                ..base
+            },
+            BlinkConfig {
+                pattern: Pattern::Sos,
+                ..base
+            },
+        ];
+        for changed in changes {
+            assert_ne!(changed.config_hash(), base.config_hash());
+        }
+    }
+
+    // ==================== MemoryStore Tests ====================
+
+    #[test]
+    fn test_memory_store_round_trip() {
+        let mut store = MemoryStore::new();
+        let config = BlinkConfig {
+            delay_ms: 250,
+            ..BlinkConfig::default()
+        };
+        assert_eq!(store.save(&config), Ok(()));
+        assert_eq!(store.load(), Ok(config));
+    }
+
+    #[test]
+    fn test_memory_store_empty_loads_default() {
+        let mut store = MemoryStore::default();
+        assert_eq!(store.load(), Ok(BlinkConfig::default()));
+    }
+}
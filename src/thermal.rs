@@ -0,0 +1,133 @@
+/*
+ * @file thermal.rs
+ * @brief Thermal throttle warning
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: thermal.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Thermal Throttle Warning.
+//!
+//! BRIEF:
+//! Maps die temperature to a warning blink pattern.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::config::{ADC_MAX, THERMAL_WARN_C};
+use crate::led::LedController;
+use crate::pattern::Pattern;
+
+/// Pattern shown while the chip is too hot.
+#[allow(dead_code)]
+pub const THERMAL_WARNING_PATTERN: Pattern = Pattern::Sos;
+
+/// Converts a raw temperature sensor ADC reading to Celsius.
+///
+/// # Details
+/// Uses the RP2350 datasheet formula with a 3.3 V reference:
+/// T = 27 - (V - 0.706) / 0.001721.
+///
+/// # Arguments
+/// * `raw` - 12-bit ADC reading of the temperature sensor channel
+///
+/// # Returns
+/// * `f32` - Die temperature in degrees Celsius
+#[allow(dead_code)]
+pub fn adc_to_celsius(raw: u16) -> f32 {
+    let volts = f32::from(raw) * 3.3 / (f32::from(ADC_MAX) + 1.0);
+    27.0 - (volts - 0.706) / 0.001721
+}
+
+/// Returns the warning pattern for a die temperature.
+///
+/// # Arguments
+/// * `temp_c` - Die temperature in degrees Celsius
+///
+/// # Returns
+/// * `Option<Pattern>` - Warning pattern above the threshold, else None
+#[allow(dead_code)]
+pub fn thermal_mode(temp_c: f32) -> Option<Pattern> {
+    (temp_c > THERMAL_WARN_C).then_some(THERMAL_WARNING_PATTERN)
+}
+
+/// Switches the controller pattern for a die temperature.
+///
+/// # Details
+/// Selects the warning pattern while hot and the normal pattern
+/// otherwise.
+///
+/// # Arguments
+/// * `controller` - Controller to update
+/// * `temp_c` - Die temperature in degrees Celsius
+/// * `normal` - Pattern to use below the threshold
+///
+/// # Returns
+/// * `bool` - true while the warning is active
+#[allow(dead_code)]
+pub fn apply_thermal(controller: &mut LedController, temp_c: f32, normal: Pattern) -> bool {
+    let warning = thermal_mode(temp_c);
+    controller.set_pattern(warning.unwrap_or(normal));
+    warning.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Threshold Tests ====================
+
+    #[test]
+    fn test_below_threshold_is_none() {
+        assert_eq!(thermal_mode(25.0), None);
+        assert_eq!(thermal_mode(THERMAL_WARN_C), None);
+    }
+
+    #[test]
+    fn test_above_threshold_warns() {
+        assert_eq!(thermal_mode(THERMAL_WARN_C + 0.5), Some(Pattern::Sos));
+        assert_eq!(thermal_mode(105.0), Some(THERMAL_WARNING_PATTERN));
+    }
+
+    #[test]
+    fn test_apply_thermal_switches_and_restores() {
+        let mut ctrl = LedController::new();
+        assert!(apply_thermal(&mut ctrl, 90.0, Pattern::Heartbeat));
+        assert_eq!(ctrl.pattern(), THERMAL_WARNING_PATTERN);
+        assert!(!apply_thermal(&mut ctrl, 40.0, Pattern::Heartbeat));
+        assert_eq!(ctrl.pattern(), Pattern::Heartbeat);
+    }
+
+    // ==================== Conversion Tests ====================
+
+    #[test]
+    fn test_adc_to_celsius_reference_point() {
+        let raw = (0.706 / 3.3 * 4096.0) as u16;
+        assert!((adc_to_celsius(raw) - 27.0).abs() < 1.0);
+    }
+}
@@ -0,0 +1,188 @@
+/*
+ * @file timer_isr.rs
+ * @brief Hardware timer interrupt blink
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: timer_isr.rs
+//!
+//! DESCRIPTION:
+//! RP2350 Hardware Timer Interrupt Blink.
+//!
+//! BRIEF:
+//! Drives LED toggling from a periodic TIMER1 alarm interrupt instead of
+//! the async executor when the timer-isr feature is enabled.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+use crate::led::{led_state_to_level, LedController};
+
+/// Performs one blink step from the timer interrupt.
+///
+/// # Details
+/// Toggles the controller and returns the new pin level.
+/// Shared by the ISR so the step logic can be tested on host.
+///
+/// # Arguments
+/// * `controller` - Controller owned by the interrupt
+///
+/// # Returns
+/// * `bool` - true to drive the pin high, false for low
+#[allow(dead_code)]
+pub fn isr_tick(controller: &mut LedController) -> bool {
+    led_state_to_level(controller.toggle())
+}
+
+/// Converts a blink delay into timer alarm microseconds.
+///
+/// # Details
+/// Saturates at the 32-bit alarm range (about 71 minutes).
+///
+/// # Arguments
+/// * `delay_ms` - Blink delay in milliseconds
+///
+/// # Returns
+/// * `u32` - Alarm offset in microseconds
+#[allow(dead_code)]
+pub fn alarm_delay_us(delay_ms: u64) -> u32 {
+    u32::try_from(delay_ms.saturating_mul(1000)).unwrap_or(u32::MAX)
+}
+
+#[cfg(feature = "timer-isr")]
+pub use hw::start;
+
+/// Hardware timer interrupt glue.
+///
+/// # Details
+/// Uses alarm 0 of TIMER1, leaving TIMER0 to the Embassy time driver.
+/// The `#[interrupt]` attribute comes from `embassy_rp::pac`, so the
+/// handler name is checked against the RP2350 vector table; the IRQ is
+/// unmasked through `InterruptExt` on `embassy_rp::interrupt`.
+/// The pin and controller live in a critical-section mutex shared with
+/// the ISR; they are only touched inside `cortex_m::interrupt::free`,
+/// and `start` installs them before the interrupt is unmasked, so the
+/// ISR never observes a partially initialized state.
+#[cfg(feature = "timer-isr")]
+mod hw {
+    use super::{alarm_delay_us, isr_tick};
+    use crate::control::TOGGLE_COUNT;
+    use crate::led::LedController;
+    use core::cell::RefCell;
+    use cortex_m::interrupt::Mutex;
+    use embassy_rp::gpio::{Level, Output};
+    use embassy_rp::interrupt::InterruptExt;
+    use embassy_rp::pac::{self, interrupt};
+
+    /// LED pin and controller shared with the ISR.
+    static SHARED: Mutex<RefCell<Option<(Output<'static>, LedController)>>> =
+        Mutex::new(RefCell::new(None));
+
+    /// Hands the LED to the timer interrupt and starts blinking.
+    ///
+    /// # Details
+    /// After this call the pin is owned by the ISR; the caller must not
+    /// drive it from task context.
+    ///
+    /// # Arguments
+    /// * `led` - LED output pin
+    /// * `controller` - Controller providing state and timing
+    #[allow(dead_code)]
+    pub fn start(led: Output<'static>, controller: LedController) {
+        let delay_us = alarm_delay_us(controller.delay_ms());
+        cortex_m::interrupt::free(|cs| {
+            SHARED.borrow(cs).replace(Some((led, controller)));
+        });
+        pac::TIMER1.inte().modify(|w| w.set_alarm(0, true));
+        arm(delay_us);
+        embassy_rp::interrupt::TIMER1_IRQ_0.unpend();
+        unsafe { embassy_rp::interrupt::TIMER1_IRQ_0.enable() };
+    }
+
+    /// Schedules the next alarm relative to now.
+    ///
+    /// # Arguments
+    /// * `delay_us` - Alarm offset in microseconds
+    fn arm(delay_us: u32) {
+        let now = pac::TIMER1.timerawl().read();
+        pac::TIMER1.alarm(0).write_value(now.wrapping_add(delay_us));
+    }
+
+    /// TIMER1 alarm 0 interrupt handler.
+    ///
+    /// # Details
+    /// Clears the alarm, applies one isr_tick and re-arms the alarm.
+    #[interrupt]
+    fn TIMER1_IRQ_0() {
+        pac::TIMER1.intr().write(|w| w.set_alarm(0, true));
+        let next = cortex_m::interrupt::free(|cs| {
+            let mut shared = SHARED.borrow(cs).borrow_mut();
+            shared.as_mut().map(|(led, controller)| {
+                let level = if isr_tick(controller) {
+                    Level::High
+                } else {
+                    Level::Low
+                };
+                led.set_level(level);
+                TOGGLE_COUNT.signal(controller.toggle_count());
+                alarm_delay_us(controller.delay_ms())
+            })
+        });
+        if let Some(delay_us) = next {
+            arm(delay_us);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== ISR Tick Tests ====================
+
+    #[test]
+    fn test_isr_tick_alternates_levels() {
+        let mut ctrl = LedController::new();
+        assert!(isr_tick(&mut ctrl));
+        assert!(!isr_tick(&mut ctrl));
+        assert!(isr_tick(&mut ctrl));
+    }
+
+    #[test]
+    fn test_isr_tick_counts_toggles() {
+        let mut ctrl = LedController::new();
+        isr_tick(&mut ctrl);
+        isr_tick(&mut ctrl);
+        assert_eq!(ctrl.toggle_count(), 2);
+    }
+
+    #[test]
+    fn test_alarm_delay_us() {
+        assert_eq!(alarm_delay_us(500), 500_000);
+        assert_eq!(alarm_delay_us(u64::MAX), u32::MAX);
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * @file wave.rs
+ * @brief Travelling LED wave
+ * @author Kevin Thomas
+ * @date 2026
+ *
+ * MIT License
+ *
+ * Copyright (c) 2026 Kevin Thomas
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! FILE: wave.rs
+//!
+//! DESCRIPTION:
+//! Travelling LED Wave.
+//!
+//! BRIEF:
+//! Computes per-LED levels for a wave that travels along a row,
+//! offsetting each LED's phase by a fixed skew.
+//!
+//! AUTHOR: Kevin Thomas
+//! CREATION DATE: October 14, 2026
+//! UPDATE DATE: October 14, 2026
+
+/// Travelling wave across a row of LEDs.
+///
+/// # Details
+/// Every LED sees the same repeating phase, delayed by `skew` per
+/// index, and is lit for the first `width` units of each period.
+/// With a skew equal to the width a single lit band walks down the
+/// row. A zero period leaves every LED off.
+///
+/// # Fields
+/// * `period` - Length of one wave cycle
+/// * `width` - Lit portion of each cycle
+/// * `skew` - Phase delay between neighbouring LEDs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub struct Wave<const N: usize> {
+    period: u32,
+    width: u32,
+    skew: u32,
+}
+
+impl<const N: usize> Wave<N> {
+    /// Creates new wave.
+    ///
+    /// # Arguments
+    /// * `period` - Length of one wave cycle
+    /// * `width` - Lit portion of each cycle
+    /// * `skew` - Phase delay between neighbouring LEDs
+    ///
+    /// # Returns
+    /// * `Self` - New Wave instance
+    #[allow(dead_code)]
+    pub fn new(period: u32, width: u32, skew: u32) -> Self {
+        Self {
+            period,
+            width,
+            skew,
+        }
+    }
+
+    /// Returns the level of every LED at a shared phase.
+    ///
+    /// # Arguments
+    /// * `phase` - Shared wave phase, taken modulo the period
+    ///
+    /// # Returns
+    /// * `[bool; N]` - true for each lit LED
+    #[allow(dead_code)]
+    pub fn levels(&self, phase: u32) -> [bool; N] {
+        let mut levels = [false; N];
+        if self.period == 0 {
+            return levels;
+        }
+        let phase = u64::from(phase % self.period);
+        let period = u64::from(self.period);
+        for (index, level) in levels.iter_mut().enumerate() {
+            let offset = (index as u64 * u64::from(self.skew)) % period;
+            let local = (phase + period - offset) % period;
+            *level = local < u64::from(self.width);
+        }
+        levels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Wave Tests ====================
+
+    #[test]
+    fn test_four_led_wave_band_walks() {
+        let wave: Wave<4> = Wave::new(400, 100, 100);
+        assert_eq!(wave.levels(0), [true, false, false, false]);
+        assert_eq!(wave.levels(150), [false, true, false, false]);
+        assert_eq!(wave.levels(399), [false, false, false, true]);
+    }
+
+    #[test]
+    fn test_four_led_wave_wraps_phase() {
+        let wave: Wave<4> = Wave::new(400, 100, 100);
+        assert_eq!(wave.levels(600), wave.levels(200));
+        assert_eq!(wave.levels(200), [false, false, true, false]);
+    }
+
+    #[test]
+    fn test_wide_band_overlaps_neighbours() {
+        let wave: Wave<4> = Wave::new(400, 200, 100);
+        assert_eq!(wave.levels(150), [true, true, false, false]);
+    }
+
+    #[test]
+    fn test_zero_period_is_dark() {
+        let wave: Wave<4> = Wave::new(0, 100, 100);
+        assert_eq!(wave.levels(50), [false; 4]);
+    }
+}